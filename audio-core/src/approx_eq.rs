@@ -0,0 +1,60 @@
+use crate::Sample;
+
+/// Trait for samples which can be compared with a tolerance.
+///
+/// Floating point samples are considered equal if they differ by no more than
+/// `epsilon`. Integer samples are compared exactly, and `epsilon` is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::ApproxEq as _;
+///
+/// assert!(0.5f32.approx_eq(0.5001, 0.001));
+/// assert!(!0.5f32.approx_eq(0.502, 0.001));
+///
+/// assert!(42i16.approx_eq(42, 0));
+/// assert!(!42i16.approx_eq(43, 10));
+/// ```
+pub trait ApproxEq: Sample {
+    /// Test if the sample is equal to `other` within `epsilon`.
+    fn approx_eq(self, other: Self, epsilon: Self) -> bool;
+}
+
+macro_rules! float {
+    ($ty:ty) => {
+        impl ApproxEq for $ty {
+            #[inline]
+            fn approx_eq(self, other: Self, epsilon: Self) -> bool {
+                (self - other).abs() <= epsilon
+            }
+        }
+    };
+}
+
+macro_rules! int {
+    ($ty:ty) => {
+        impl ApproxEq for $ty {
+            #[inline]
+            fn approx_eq(self, other: Self, _: Self) -> bool {
+                self == other
+            }
+        }
+    };
+}
+
+float!(f32);
+float!(f64);
+
+int!(u8);
+int!(u16);
+int!(u32);
+int!(u64);
+int!(u128);
+int!(i8);
+int!(i16);
+int!(i32);
+int!(i64);
+int!(i128);
+int!(usize);
+int!(isize);
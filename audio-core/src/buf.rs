@@ -1,6 +1,7 @@
 //! Trait for dealing with abstract channel buffers.
 
 use crate::channel::{Channel, ChannelMut};
+use crate::channel_mask::ChannelMask;
 
 mod skip;
 pub use self::skip::Skip;
@@ -8,12 +9,30 @@ pub use self::skip::Skip;
 mod limit;
 pub use self::limit::Limit;
 
+mod range;
+pub use self::range::Range;
+
 mod chunk;
 pub use self::chunk::Chunk;
 
 mod tail;
 pub use self::tail::Tail;
 
+mod take_channels;
+pub use self::take_channels::TakeChannels;
+
+mod channels_iter;
+pub use self::channels_iter::{ChannelsIter, ChannelsIterMut};
+
+mod cycle;
+pub use self::cycle::Cycle;
+
+mod masked;
+pub use self::masked::Masked;
+
+mod gained;
+pub use self::gained::Gained;
+
 mod exact_size_buf;
 pub use self::exact_size_buf::ExactSizeBuf;
 
@@ -202,6 +221,33 @@ pub trait Buf {
         Limit::new(self, limit)
     }
 
+    /// Construct a buffer covering the frames `start..end` of this buffer.
+    ///
+    /// This is the same as `skip(start).limit(end - start)`, except that `end`
+    /// is clamped to the number of available frames and a `start` which is
+    /// past `end` results in an empty buffer rather than an underflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ExactSizeBuf as _};
+    /// use audio::buf;
+    ///
+    /// let from = audio::interleaved![[1, 2, 3, 4, 5, 6, 7, 8]; 2];
+    /// let mut to = audio::Interleaved::with_topology(2, 4);
+    ///
+    /// assert_eq!((&from).range(2, 6).frames(), 4);
+    /// buf::copy(from.range(2, 6), &mut to);
+    ///
+    /// assert_eq!(to.as_slice(), &[3, 3, 4, 4, 5, 5, 6, 6]);
+    /// ```
+    fn range(self, start: usize, end: usize) -> Range<Self>
+    where
+        Self: Sized,
+    {
+        Range::new(self, start, end)
+    }
+
     /// Construct a range of frames corresponds to the chunk with `len` and
     /// position `n`.
     ///
@@ -226,6 +272,111 @@ pub trait Buf {
     {
         Chunk::new(self, n, len)
     }
+
+    /// Construct a buffer which repeats the frames of this buffer until it
+    /// reaches `frames` frames.
+    ///
+    /// This is useful for looping a short sample to fill a longer block. See
+    /// [Cycle] for how to access the frames of the cycled buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ExactSizeBuf as _};
+    ///
+    /// let buf = audio::interleaved![[1, 2, 3, 4]; 2];
+    /// let cycle = (&buf).cycle(10);
+    ///
+    /// assert_eq!(cycle.frames(), 10);
+    /// assert_eq!(cycle.frame(0, 9), Some(2));
+    /// ```
+    fn cycle(self, frames: usize) -> Cycle<Self>
+    where
+        Self: Sized,
+    {
+        Cycle::new(self, frames)
+    }
+
+    /// Construct a buffer where the channels which aren't audible according to
+    /// `mask` are presented as silence.
+    ///
+    /// This allows for muting and soloing channels without modifying the
+    /// underlying buffer. See [Masked] for how to access the frames of the
+    /// masked buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ChannelMask};
+    ///
+    /// let buf = audio::interleaved![[1, 2, 3, 4]; 2];
+    ///
+    /// let mut mask = ChannelMask::new();
+    /// mask.mute(1);
+    ///
+    /// let masked = (&buf).apply_mask(&mask);
+    ///
+    /// assert_eq!(masked.frame(0, 3), Some(4));
+    /// assert_eq!(masked.frame(1, 3), Some(0));
+    /// ```
+    fn apply_mask<T>(self, mask: &ChannelMask) -> Masked<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        Masked::new(self, mask)
+    }
+
+    /// Construct a buffer whose samples are multiplied by `gain` as they're
+    /// read.
+    ///
+    /// This allows for applying a gain without modifying or copying the
+    /// underlying buffer, until the scaled samples are needed. See [Gained]
+    /// for how to access the samples of the scaled buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::interleaved![[1.0f32, 2.0, 3.0, 4.0]; 2];
+    /// let gained = (&buf).with_gain(0.5);
+    ///
+    /// assert_eq!(gained.frame(0, 3), Some(2.0));
+    /// assert_eq!(buf.frame(0, 3), Some(4.0));
+    /// ```
+    fn with_gain(self, gain: f32) -> Gained<Self>
+    where
+        Self: Sized,
+    {
+        Gained::new(self, gain)
+    }
+
+    /// Construct a buffer which only presents the first `n` channels of this
+    /// buffer.
+    ///
+    /// The remaining channels are inaccessible through the returned buffer.
+    /// If this buffer has fewer than `n` channels, all of them are presented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, Channels as _};
+    /// use audio::buf;
+    ///
+    /// let from = audio::interleaved![[1; 4], [2; 4], [3; 4], [4; 4]];
+    /// let mut to = audio::interleaved![[0; 4]; 2];
+    ///
+    /// buf::copy((&from).take_channels(2), &mut to);
+    ///
+    /// assert_eq!(to, audio::interleaved![[1; 4], [2; 4]]);
+    /// assert_eq!(from.take_channels(2).channels(), 2);
+    /// ```
+    fn take_channels(self, n: usize) -> TakeChannels<Self>
+    where
+        Self: Sized,
+    {
+        TakeChannels::new(self, n)
+    }
 }
 
 /// A trait describing something that has channels.
@@ -244,6 +395,65 @@ pub trait Channels<T>: Buf {
     /// Panics if the specified channel is out of bound as reported by
     /// [Buf::channels].
     fn channel(&self, channel: usize) -> Channel<'_, T>;
+
+    /// Construct an iterator over every channel in the buffer.
+    ///
+    /// This is the same as calling [Channels::channel] for every channel in
+    /// `0..channels()`.
+    ///
+    /// The mutable counterpart to this is [ChannelsMut::channels_iter_mut].
+    ///
+    /// The `Self: Sized` bound is what keeps [Channels] usable as a trait
+    /// object, since the iterator is generic over the buffer it borrows. To
+    /// iterate over the channels of a `&dyn Channels<T>`, call this on the
+    /// reference itself instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Channels;
+    ///
+    /// fn peaks(buf: &impl Channels<f32>) -> Vec<f32> {
+    ///     buf.channels_iter()
+    ///         .map(|channel| channel.iter().fold(0.0, |peak, s| f32::max(peak, s.abs())))
+    ///         .collect()
+    /// }
+    ///
+    /// assert_eq!(peaks(&audio::interleaved![[0.5, -1.0], [0.25, 0.125]]), vec![1.0, 0.25]);
+    /// assert_eq!(peaks(&audio::sequential![[0.5, -1.0], [0.25, 0.125]]), vec![1.0, 0.25]);
+    ///
+    /// let buf: &dyn Channels<f32> = &audio::dynamic![[0.5, -1.0]; 3];
+    /// assert_eq!((&buf).channels_iter().len(), 3);
+    /// ```
+    fn channels_iter(&self) -> ChannelsIter<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        ChannelsIter::new(self)
+    }
+
+    /// The alignment in bytes which the start of the storage of every channel
+    /// is guaranteed to have.
+    ///
+    /// SIMD code can use this to decide whether aligned loads can be used.
+    /// This defaults to the alignment of the sample type, but buffers which
+    /// are backed by over-aligned allocations report a larger alignment. See
+    /// for example `Dynamic::with_alignment`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Channels;
+    ///
+    /// let buf = audio::interleaved![[0.0f32; 4]; 2];
+    /// assert_eq!(buf.alignment(), std::mem::align_of::<f32>());
+    ///
+    /// let buf = audio::Dynamic::<f32>::with_alignment(2, 4, 32);
+    /// assert_eq!((&buf).alignment(), 32);
+    /// ```
+    fn alignment(&self) -> usize {
+        std::mem::align_of::<T>()
+    }
 }
 
 /// A trait describing a mutable audio buffer.
@@ -279,6 +489,46 @@ pub trait ChannelsMut<T>: Channels<T> {
     fn copy_channels(&mut self, from: usize, to: usize)
     where
         T: Copy;
+
+    /// Construct a mutable iterator over every channel in the buffer.
+    ///
+    /// Since channels in some layouts, like interleaved buffers, share the
+    /// same storage, handing out all of them at once would alias. So this is
+    /// not an [Iterator], but is instead advanced with
+    /// [ChannelsIterMut::next] and only one channel can be borrowed from it
+    /// at a time.
+    ///
+    /// The `Self: Sized` bound is needed for the same reason as for
+    /// [Channels::channels_iter].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Channels, ChannelsMut};
+    ///
+    /// fn fill(mut buf: impl ChannelsMut<i32>) {
+    ///     let mut channels = buf.channels_iter_mut();
+    ///     let mut n = 0;
+    ///
+    ///     while let Some(channel) = channels.next() {
+    ///         n += 1;
+    ///
+    ///         for s in channel.iter_mut() {
+    ///             *s = n;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut buf = audio::interleaved![[0; 2]; 3];
+    /// fill(&mut buf);
+    /// assert_eq!(buf.as_slice(), &[1, 2, 3, 1, 2, 3]);
+    /// ```
+    fn channels_iter_mut(&mut self) -> ChannelsIterMut<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        ChannelsIterMut::new(self)
+    }
 }
 
 impl<B> Buf for &B
@@ -298,12 +548,17 @@ where
 
 impl<B, T> Channels<T> for &B
 where
-    B: Channels<T>,
+    B: ?Sized + Channels<T>,
 {
     #[inline]
     fn channel(&self, channel: usize) -> Channel<'_, T> {
         (**self).channel(channel)
     }
+
+    #[inline]
+    fn alignment(&self) -> usize {
+        (**self).alignment()
+    }
 }
 
 impl<B> Buf for &mut B
@@ -329,6 +584,11 @@ where
     fn channel(&self, channel: usize) -> Channel<'_, T> {
         (**self).channel(channel)
     }
+
+    #[inline]
+    fn alignment(&self) -> usize {
+        (**self).alignment()
+    }
 }
 
 impl<B, T> ChannelsMut<T> for &mut B
@@ -0,0 +1,109 @@
+use crate::buf::{Channels, ChannelsMut};
+use crate::channel::{Channel, ChannelMut};
+use std::marker;
+
+/// An iterator over the channels of a buffer.
+///
+/// See [Channels::channels_iter].
+pub struct ChannelsIter<'a, B: ?Sized, T> {
+    buf: &'a B,
+    channel: usize,
+    channels: usize,
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, B: ?Sized, T> ChannelsIter<'a, B, T>
+where
+    B: Channels<T>,
+{
+    /// Construct a new iterator over the channels of `buf`.
+    pub(crate) fn new(buf: &'a B) -> Self {
+        Self {
+            buf,
+            channel: 0,
+            channels: buf.channels(),
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, B: ?Sized, T> Iterator for ChannelsIter<'a, B, T>
+where
+    B: Channels<T>,
+    T: 'a,
+{
+    type Item = Channel<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.channel < self.channels {
+            let channel = self.channel;
+            self.channel += 1;
+            Some(self.buf.channel(channel))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.channels - self.channel;
+        (len, Some(len))
+    }
+}
+
+impl<'a, B: ?Sized, T> ExactSizeIterator for ChannelsIter<'a, B, T>
+where
+    B: Channels<T>,
+    T: 'a,
+{
+}
+
+/// A mutable iterator over the channels of a buffer.
+///
+/// See [ChannelsMut::channels_iter_mut].
+pub struct ChannelsIterMut<'a, B: ?Sized, T> {
+    buf: &'a mut B,
+    channel: usize,
+    channels: usize,
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, B: ?Sized, T> ChannelsIterMut<'a, B, T>
+where
+    B: ChannelsMut<T>,
+{
+    /// Construct a new mutable iterator over the channels of `buf`.
+    pub(crate) fn new(buf: &'a mut B) -> Self {
+        let channels = buf.channels();
+
+        Self {
+            buf,
+            channel: 0,
+            channels,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Get the next channel, or `None` if every channel has been visited.
+    ///
+    /// The channel borrows the iterator, so it has to be released before the
+    /// next one can be accessed.
+    pub fn next(&mut self) -> Option<ChannelMut<'_, T>> {
+        if self.channel < self.channels {
+            let channel = self.channel;
+            self.channel += 1;
+            Some(self.buf.channel_mut(channel))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of channels which are left to visit.
+    pub fn len(&self) -> usize {
+        self.channels - self.channel
+    }
+
+    /// Test if every channel has been visited.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
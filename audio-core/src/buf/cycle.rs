@@ -0,0 +1,148 @@
+use crate::buf::{Buf, Channels, ChannelsMut, ExactSizeBuf};
+use crate::sample::Sample;
+
+/// A buffer whose frames repeat the frames of an underlying buffer until a
+/// target number of frames is reached.
+///
+/// Frame `n` of the cycled buffer corresponds to frame `n % frames` of the
+/// underlying buffer. If the underlying buffer doesn't have any frames, the
+/// cycled buffer is silent.
+///
+/// Since the frames of a cycled buffer aren't contiguous in memory it doesn't
+/// implement [Channels]. Samples are instead accessed with [Cycle::frame], or
+/// copied into another buffer with [Cycle::copy_into].
+///
+/// See [Buf::cycle].
+pub struct Cycle<B> {
+    buf: B,
+    frames: usize,
+}
+
+impl<B> Cycle<B> {
+    /// Construct a new cycled buffer.
+    pub(crate) fn new(buf: B, frames: usize) -> Self {
+        Self { buf, frames }
+    }
+
+    /// Get the sample of the given frame in the given channel.
+    ///
+    /// Returns [None] if either the channel or the frame is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::interleaved![[1, 2, 3]; 2];
+    /// let cycle = (&buf).cycle(7);
+    ///
+    /// assert_eq!(cycle.frame(1, 0), Some(1));
+    /// assert_eq!(cycle.frame(1, 4), Some(2));
+    /// assert_eq!(cycle.frame(1, 6), Some(1));
+    /// assert_eq!(cycle.frame(1, 7), None);
+    /// assert_eq!(cycle.frame(2, 0), None);
+    /// ```
+    pub fn frame<T>(&self, channel: usize, frame: usize) -> Option<T>
+    where
+        B: Channels<T>,
+        T: Sample,
+    {
+        if channel >= self.buf.channels() || frame >= self.frames {
+            return None;
+        }
+
+        let channel = self.buf.channel(channel);
+
+        match channel.frames() {
+            0 => Some(T::ZERO),
+            n => Some(channel[frame % n]),
+        }
+    }
+
+    /// Copy the cycled buffer into `to`.
+    ///
+    /// Only the common count of channels and frames will be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::sequential![[1, 2, 3], [4, 5, 6]];
+    /// let mut to = audio::interleaved![[0; 5]; 2];
+    ///
+    /// (&buf).cycle(8).copy_into(&mut to);
+    ///
+    /// assert_eq!(to, audio::interleaved![[1, 2, 3, 1, 2], [4, 5, 6, 4, 5]]);
+    /// ```
+    pub fn copy_into<O, T>(&self, mut to: O)
+    where
+        B: Channels<T>,
+        O: ChannelsMut<T>,
+        T: Sample,
+    {
+        let end = usize::min(self.buf.channels(), to.channels());
+
+        for chan in 0..end {
+            let from = self.buf.channel(chan);
+            let n = from.frames();
+
+            for (frame, s) in to
+                .channel_mut(chan)
+                .iter_mut()
+                .take(self.frames)
+                .enumerate()
+            {
+                *s = match n {
+                    0 => T::ZERO,
+                    n => from[frame % n],
+                };
+            }
+        }
+    }
+}
+
+/// [Cycle] adjusts the implementation of [Buf] to report the target number of
+/// frames.
+///
+/// ```rust
+/// use audio::Buf;
+///
+/// let buf = audio::interleaved![[0; 4]; 2];
+///
+/// assert_eq!((&buf).cycle(10).channels(), 2);
+/// assert_eq!((&buf).cycle(10).frames_hint(), Some(10));
+/// assert_eq!((&buf).cycle(2).frames_hint(), Some(2));
+/// ```
+impl<B> Buf for Cycle<B>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.frames)
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+/// [Cycle] adjusts the implementation of [ExactSizeBuf] to report the target
+/// number of frames.
+///
+/// ```rust
+/// use audio::{Buf, ExactSizeBuf};
+///
+/// let buf = audio::interleaved![[0; 4]; 2];
+///
+/// assert_eq!((&buf).cycle(10).frames(), 10);
+/// assert_eq!((&buf).cycle(0).frames(), 0);
+/// ```
+impl<B> ExactSizeBuf for Cycle<B>
+where
+    B: Buf,
+{
+    fn frames(&self) -> usize {
+        self.frames
+    }
+}
@@ -0,0 +1,145 @@
+use crate::buf::{Buf, Channels, ChannelsMut, ExactSizeBuf};
+
+/// A buffer whose samples are multiplied by a gain as they're read, without
+/// modifying or copying the underlying buffer.
+///
+/// Since the scaled samples don't exist in the underlying buffer this doesn't
+/// implement [Channels]. Samples are instead accessed with [Gained::frame] or
+/// [Gained::iter], or copied into another buffer with [Gained::copy_into].
+///
+/// See [Buf::with_gain].
+pub struct Gained<B> {
+    buf: B,
+    gain: f32,
+}
+
+impl<B> Gained<B> {
+    /// Construct a new gained buffer.
+    pub(crate) fn new(buf: B, gain: f32) -> Self {
+        Self { buf, gain }
+    }
+
+    /// Get the gain applied to the buffer.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Get the scaled sample of the given frame in the given channel.
+    ///
+    /// Returns [None] if either the channel or the frame is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::interleaved![[1.0f32, 2.0], [3.0, 4.0]];
+    /// let gained = (&buf).with_gain(0.5);
+    ///
+    /// assert_eq!(gained.frame(1, 1), Some(2.0));
+    /// assert_eq!(gained.frame(1, 2), None);
+    /// assert_eq!(gained.frame(2, 0), None);
+    /// ```
+    pub fn frame(&self, channel: usize, frame: usize) -> Option<f32>
+    where
+        B: Channels<f32>,
+    {
+        if channel >= self.buf.channels() {
+            return None;
+        }
+
+        let channel = self.buf.channel(channel);
+
+        if frame >= channel.frames() {
+            return None;
+        }
+
+        Some(channel[frame] * self.gain)
+    }
+
+    /// Iterate over the scaled samples of the given channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::sequential![[1.0f32, 2.0], [3.0, 4.0]];
+    /// let gained = (&buf).with_gain(2.0);
+    ///
+    /// assert!(gained.iter(1).eq([6.0, 8.0]));
+    /// ```
+    pub fn iter(&self, channel: usize) -> impl Iterator<Item = f32> + '_
+    where
+        B: Channels<f32>,
+    {
+        let gain = self.gain;
+        self.buf.channel(channel).iter().map(move |s| s * gain)
+    }
+
+    /// Copy the scaled buffer into `to`.
+    ///
+    /// Only the common count of channels and frames will be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Buf as _;
+    ///
+    /// let buf = audio::sequential![[1.0f32, 2.0], [3.0, 4.0]];
+    /// let mut to = audio::interleaved![[0.0f32; 2]; 2];
+    ///
+    /// (&buf).with_gain(0.5).copy_into(&mut to);
+    ///
+    /// assert_eq!(to, audio::interleaved![[0.5, 1.0], [1.5, 2.0]]);
+    /// ```
+    pub fn copy_into<O>(&self, mut to: O)
+    where
+        B: Channels<f32>,
+        O: ChannelsMut<f32>,
+    {
+        let end = usize::min(self.buf.channels(), to.channels());
+
+        for chan in 0..end {
+            for (o, s) in to.channel_mut(chan).iter_mut().zip(self.iter(chan)) {
+                *o = s;
+            }
+        }
+    }
+}
+
+/// [Gained] doesn't adjust the implementation of [Buf].
+///
+/// ```rust
+/// use audio::Buf;
+///
+/// let buf = audio::interleaved![[0.0f32; 4]; 2];
+///
+/// assert_eq!((&buf).with_gain(0.5).channels(), 2);
+/// assert_eq!((&buf).with_gain(0.5).frames_hint(), Some(4));
+/// ```
+impl<B> Buf for Gained<B>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        self.buf.frames_hint()
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+impl<B> ExactSizeBuf for Gained<B>
+where
+    B: ExactSizeBuf,
+{
+    fn frames(&self) -> usize {
+        self.buf.frames()
+    }
+}
@@ -0,0 +1,196 @@
+use crate::buf::{Buf, Channels, ChannelsMut, ExactSizeBuf};
+use crate::channel::Channel;
+use crate::channel_mask::ChannelMask;
+use crate::sample::Sample;
+use std::sync::OnceLock;
+
+/// A buffer where the channels which aren't audible according to a
+/// [ChannelMask] are presented as silence.
+///
+/// The underlying buffer isn't modified. Audible channels accessed through
+/// [Channels] are passed through as-is. Since silent channels don't exist in
+/// the underlying buffer, a buffer of silence is allocated the first time one
+/// is accessed through [Channels]. [Masked::frame] and [Masked::copy_into]
+/// never allocate.
+///
+/// `T` is the type of the samples in the buffer, which is usually inferred
+/// from how the masked buffer is used.
+///
+/// See [Buf::apply_mask].
+pub struct Masked<'a, B, T> {
+    buf: B,
+    mask: &'a ChannelMask,
+    silence: OnceLock<Vec<T>>,
+}
+
+impl<'a, B, T> Masked<'a, B, T> {
+    /// Construct a new masked buffer.
+    pub(crate) fn new(buf: B, mask: &'a ChannelMask) -> Self {
+        Self {
+            buf,
+            mask,
+            silence: OnceLock::new(),
+        }
+    }
+
+    /// Get the sample of the given frame in the given channel, which is
+    /// silence if the channel isn't audible.
+    ///
+    /// Returns [None] if either the channel or the frame is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ChannelMask};
+    ///
+    /// let buf = audio::interleaved![[1, 2], [3, 4]];
+    ///
+    /// let mut mask = ChannelMask::new();
+    /// mask.mute(0);
+    ///
+    /// let masked = (&buf).apply_mask(&mask);
+    ///
+    /// assert_eq!(masked.frame(0, 1), Some(0));
+    /// assert_eq!(masked.frame(1, 1), Some(4));
+    /// assert_eq!(masked.frame(1, 2), None);
+    /// assert_eq!(masked.frame(2, 0), None);
+    /// ```
+    pub fn frame(&self, channel: usize, frame: usize) -> Option<T>
+    where
+        B: Channels<T>,
+        T: Sample,
+    {
+        if channel >= self.buf.channels() {
+            return None;
+        }
+
+        let channel_buf = self.buf.channel(channel);
+
+        if frame >= channel_buf.frames() {
+            return None;
+        }
+
+        if self.mask.is_audible(channel) {
+            Some(channel_buf[frame])
+        } else {
+            Some(T::ZERO)
+        }
+    }
+
+    /// Copy the masked buffer into `to`.
+    ///
+    /// Only the common count of channels and frames will be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ChannelMask};
+    ///
+    /// let buf = audio::sequential![[1, 2], [3, 4], [5, 6]];
+    /// let mut to = audio::interleaved![[9; 2]; 3];
+    ///
+    /// let mut mask = ChannelMask::new();
+    /// mask.mute(1);
+    ///
+    /// (&buf).apply_mask(&mask).copy_into(&mut to);
+    ///
+    /// assert_eq!(to, audio::interleaved![[1, 2], [0, 0], [5, 6]]);
+    /// ```
+    pub fn copy_into<O>(&self, mut to: O)
+    where
+        B: Channels<T>,
+        O: ChannelsMut<T>,
+        T: Sample,
+    {
+        let end = usize::min(self.buf.channels(), to.channels());
+
+        for chan in 0..end {
+            let from = self.buf.channel(chan);
+            let mut to = to.channel_mut(chan);
+
+            if self.mask.is_audible(chan) {
+                to.copy_from(from);
+            } else {
+                for s in to.iter_mut().take(from.frames()) {
+                    *s = T::ZERO;
+                }
+            }
+        }
+    }
+}
+
+/// [Masked] doesn't adjust the implementation of [Buf].
+///
+/// ```rust
+/// use audio::{Buf, ChannelMask};
+///
+/// let buf = audio::interleaved![[0; 4]; 2];
+/// let mask = ChannelMask::new();
+///
+/// let masked = (&buf).apply_mask::<i32>(&mask);
+///
+/// assert_eq!(masked.channels(), 2);
+/// assert_eq!(masked.frames_hint(), Some(4));
+/// ```
+impl<B, T> Buf for Masked<'_, B, T>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        self.buf.frames_hint()
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+impl<B, T> ExactSizeBuf for Masked<'_, B, T>
+where
+    B: ExactSizeBuf,
+{
+    fn frames(&self) -> usize {
+        self.buf.frames()
+    }
+}
+
+/// [Masked] presents the channels which aren't audible as silence.
+///
+/// ```rust
+/// use audio::{Buf as _, ChannelMask, Channels};
+///
+/// let buf = audio::sequential![[1, 2], [3, 4], [5, 6]];
+///
+/// let mut mask = ChannelMask::new();
+/// mask.mute(1);
+///
+/// let masked = (&buf).apply_mask(&mask);
+///
+/// assert_eq!(masked.channel(0), buf.channel(0));
+/// assert!(masked.channel(1).iter().eq([0, 0]));
+/// assert_eq!(audio::buf::to_owned(&masked), audio::dynamic![[1, 2], [0, 0], [5, 6]]);
+/// ```
+impl<B, T> Channels<T> for Masked<'_, B, T>
+where
+    B: Channels<T>,
+    T: Sample,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        let from = self.buf.channel(channel);
+
+        if self.mask.is_audible(channel) {
+            return from;
+        }
+
+        let silence = self.silence.get_or_init(|| {
+            let frames = (0..self.buf.channels())
+                .map(|chan| self.buf.channel(chan).frames())
+                .max()
+                .unwrap_or_default();
+
+            vec![T::ZERO; frames]
+        });
+
+        Channel::linear(&silence[..from.frames()])
+    }
+}
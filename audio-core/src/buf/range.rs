@@ -0,0 +1,97 @@
+use crate::buf::{Buf, Channel, ChannelMut, Channels, ChannelsMut, ExactSizeBuf};
+
+/// A range of frames from another buffer.
+///
+/// See [Buf::range].
+pub struct Range<B> {
+    buf: B,
+    start: usize,
+    end: usize,
+}
+
+impl<B> Range<B> {
+    /// Construct a new ranged buffer.
+    pub(crate) fn new(buf: B, start: usize, end: usize) -> Self {
+        Self { buf, start, end }
+    }
+
+    /// The number of frames covered by the range, without clamping it to
+    /// the underlying buffer.
+    fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+}
+
+/// ```rust
+/// use audio::Buf;
+///
+/// let buf = audio::interleaved![[0; 4]; 2];
+///
+/// assert_eq!((&buf).range(1, 3).channels(), 2);
+/// assert_eq!((&buf).range(1, 3).frames_hint(), Some(2));
+///
+/// assert_eq!((&buf).range(2, 8).channels(), 2);
+/// assert_eq!((&buf).range(2, 8).frames_hint(), Some(2));
+///
+/// assert_eq!((&buf).range(3, 1).channels(), 2);
+/// assert_eq!((&buf).range(3, 1).frames_hint(), Some(0));
+/// ```
+impl<B> Buf for Range<B>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        let frames = self.buf.frames_hint()?;
+        Some(usize::min(frames, self.end).saturating_sub(self.start))
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+/// ```rust
+/// use audio::{Buf, ExactSizeBuf};
+///
+/// let buf = audio::interleaved![[0; 4]; 2];
+///
+/// assert_eq!((&buf).range(1, 3).frames(), 2);
+/// assert_eq!((&buf).range(2, 8).frames(), 2);
+/// assert_eq!((&buf).range(3, 1).frames(), 0);
+/// assert_eq!((&buf).range(6, 8).frames(), 0);
+/// ```
+impl<B> ExactSizeBuf for Range<B>
+where
+    B: ExactSizeBuf,
+{
+    fn frames(&self) -> usize {
+        usize::min(self.buf.frames(), self.end).saturating_sub(self.start)
+    }
+}
+
+impl<B, T> Channels<T> for Range<B>
+where
+    B: Channels<T>,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        let len = self.len();
+        self.buf.channel(channel).skip(self.start).limit(len)
+    }
+}
+
+impl<B, T> ChannelsMut<T> for Range<B>
+where
+    B: ChannelsMut<T>,
+{
+    fn channel_mut(&mut self, channel: usize) -> ChannelMut<'_, T> {
+        let len = self.len();
+        self.buf.channel_mut(channel).skip(self.start).limit(len)
+    }
+
+    fn copy_channels(&mut self, from: usize, to: usize)
+    where
+        T: Copy,
+    {
+        self.buf.copy_channels(from, to);
+    }
+}
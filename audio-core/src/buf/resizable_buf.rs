@@ -5,6 +5,9 @@ use crate::sample::Sample;
 pub trait ResizableBuf: Buf {
     /// Resize the number of per-channel frames in the buffer.
     ///
+    /// If the buffer grows, the new frames in every channel are zeroed, even if
+    /// they were previously sized out.
+    ///
     /// # Examples
     ///
     /// ```rust
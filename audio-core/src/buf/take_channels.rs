@@ -0,0 +1,112 @@
+use crate::buf::{Buf, Channels, ChannelsMut, ExactSizeBuf};
+use crate::channel::{Channel, ChannelMut};
+use crate::io::ReadBuf;
+
+/// A buffer which only presents the first few channels of another buffer.
+///
+/// See [Buf::take_channels].
+pub struct TakeChannels<B> {
+    buf: B,
+    n: usize,
+}
+
+impl<B> TakeChannels<B> {
+    /// Construct a new buffer with a reduced number of channels.
+    pub(crate) fn new(buf: B, n: usize) -> Self {
+        Self { buf, n }
+    }
+}
+
+/// [TakeChannels] adjusts the number of channels reported by [Buf].
+///
+/// ```rust
+/// use audio::Buf;
+///
+/// let buf = audio::interleaved![[0; 4]; 4];
+///
+/// assert_eq!((&buf).take_channels(0).channels(), 0);
+/// assert_eq!((&buf).take_channels(2).channels(), 2);
+/// assert_eq!((&buf).take_channels(8).channels(), 4);
+/// assert_eq!((&buf).take_channels(2).frames_hint(), Some(4));
+/// ```
+impl<B> Buf for TakeChannels<B>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        self.buf.frames_hint()
+    }
+
+    fn channels(&self) -> usize {
+        usize::min(self.buf.channels(), self.n)
+    }
+}
+
+impl<B> ExactSizeBuf for TakeChannels<B>
+where
+    B: ExactSizeBuf,
+{
+    fn frames(&self) -> usize {
+        self.buf.frames()
+    }
+}
+
+impl<B, T> Channels<T> for TakeChannels<B>
+where
+    B: Channels<T>,
+{
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        assert! {
+            channel < self.channels(),
+            "channel {} is out of bounds for a buffer with {} channels",
+            channel,
+            self.channels()
+        };
+
+        self.buf.channel(channel)
+    }
+}
+
+impl<B, T> ChannelsMut<T> for TakeChannels<B>
+where
+    B: ChannelsMut<T>,
+{
+    fn channel_mut(&mut self, channel: usize) -> ChannelMut<'_, T> {
+        assert! {
+            channel < self.channels(),
+            "channel {} is out of bounds for a buffer with {} channels",
+            channel,
+            self.channels()
+        };
+
+        self.buf.channel_mut(channel)
+    }
+
+    fn copy_channels(&mut self, from: usize, to: usize)
+    where
+        T: Copy,
+    {
+        assert! {
+            from < self.channels() && to < self.channels(),
+            "channels {} and {} are out of bounds for a buffer with {} channels",
+            from,
+            to,
+            self.channels()
+        };
+
+        self.buf.copy_channels(from, to);
+    }
+}
+
+impl<B> ReadBuf for TakeChannels<B>
+where
+    B: ReadBuf,
+{
+    fn remaining(&self) -> usize {
+        self.buf.remaining()
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.buf.advance(n);
+    }
+}
@@ -81,6 +81,8 @@ impl<'a, T> Channel<'a, T> {
     /// assert_eq!(channel[2], 6);
     /// ```
     pub fn interleaved(buf: &'a [T], channels: usize, channel: usize) -> Self {
+        crate::checks::interleaved_stride(buf.len(), channels, channel);
+
         Self {
             buf,
             kind: Kind::Interleaved { channels, channel },
@@ -113,6 +115,49 @@ impl<'a, T> Channel<'a, T> {
         }
     }
 
+    /// Get the range of memory which backs the channel.
+    ///
+    /// For interleaved channels this covers the samples of the other channels
+    /// which are interleaved with it as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Channels;
+    ///
+    /// let buf = audio::interleaved![[0; 4]; 2];
+    ///
+    /// let range = buf.channel(1).as_ptr_range();
+    /// assert_eq!(range, buf.as_slice().as_ptr_range());
+    /// ```
+    pub fn as_ptr_range(&self) -> ops::Range<*const T> {
+        self.buf.as_ptr_range()
+    }
+
+    /// Access the complete interleaved buffer backing the channel, if it is
+    /// interleaved.
+    ///
+    /// This returns the buffer which includes *all* other channels, the total
+    /// number of channels interleaved in it, and which channel this is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Channels;
+    ///
+    /// let buf = audio::interleaved![[1, 2], [3, 4]];
+    /// assert_eq!(buf.channel(1).as_interleaved(), Some((&[1, 3, 2, 4][..], 2, 1)));
+    ///
+    /// let buf = audio::sequential![[1, 2], [3, 4]];
+    /// assert_eq!(buf.channel(1).as_interleaved(), None);
+    /// ```
+    pub fn as_interleaved(&self) -> Option<(&'a [T], usize, usize)> {
+        match self.kind {
+            Kind::Linear => None,
+            Kind::Interleaved { channels, channel } => Some((self.buf, channels, channel)),
+        }
+    }
+
     /// Construct an iterator over the channel.
     ///
     /// # Examples
@@ -263,11 +308,11 @@ impl<'a, T> Channel<'a, T> {
 
         match kind {
             Kind::Linear => Channel {
-                buf: buf.get(..limit).unwrap_or_default(),
+                buf: &buf[..usize::min(limit, buf.len())],
                 kind,
             },
             Kind::Interleaved { channels, .. } => Channel {
-                buf: buf.get(..limit * channels).unwrap_or_default(),
+                buf: &buf[..usize::min(limit * channels, buf.len())],
                 kind,
             },
         }
@@ -582,6 +627,8 @@ impl<'a, T> ChannelMut<'a, T> {
     /// assert_eq!(buf, &[1, 2, 3, 16, 5, 6, 7, 8]);
     /// ```
     pub fn interleaved(buf: &'a mut [T], channels: usize, channel: usize) -> Self {
+        crate::checks::interleaved_stride(buf.len(), channels, channel);
+
         Self {
             buf,
             kind: Kind::Interleaved { channels, channel },
@@ -648,6 +695,33 @@ impl<'a, T> ChannelMut<'a, T> {
         }
     }
 
+    /// Convert the channel into the complete interleaved buffer backing it, if
+    /// it is interleaved.
+    ///
+    /// This returns the buffer which includes *all* other channels, the total
+    /// number of channels interleaved in it, and which channel this is. See
+    /// [Channel::as_interleaved].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::ChannelsMut;
+    ///
+    /// let mut buf = audio::interleaved![[0; 2]; 2];
+    ///
+    /// if let Some((samples, channels, channel)) = buf.channel_mut(1).into_interleaved() {
+    ///     samples[channels + channel] = 1;
+    /// }
+    ///
+    /// assert_eq!(buf.as_slice(), &[0, 0, 0, 1]);
+    /// ```
+    pub fn into_interleaved(self) -> Option<(&'a mut [T], usize, usize)> {
+        match self.kind {
+            Kind::Linear => None,
+            Kind::Interleaved { channels, channel } => Some((self.buf, channels, channel)),
+        }
+    }
+
     /// Construct a new [Channel] reference with a lifetime associated with the
     /// current channel instance instead of the underlying buffer.
     ///
@@ -930,14 +1004,22 @@ impl<'a, T> ChannelMut<'a, T> {
         let Self { buf, kind } = self;
 
         match kind {
-            Kind::Linear => Self {
-                buf: buf.get_mut(..limit).unwrap_or_default(),
-                kind,
-            },
-            Kind::Interleaved { channels, .. } => Self {
-                buf: buf.get_mut(..limit * channels).unwrap_or_default(),
-                kind,
-            },
+            Kind::Linear => {
+                let end = usize::min(limit, buf.len());
+
+                Self {
+                    buf: &mut buf[..end],
+                    kind,
+                }
+            }
+            Kind::Interleaved { channels, .. } => {
+                let end = usize::min(limit * channels, buf.len());
+
+                Self {
+                    buf: &mut buf[..end],
+                    kind,
+                }
+            }
         }
     }
 
@@ -0,0 +1,173 @@
+//! A mute and solo state for the channels of a buffer.
+
+/// The number of channels tracked by each word in a [ChannelMask].
+const BITS: usize = 64;
+
+/// Per-channel mute and solo state, which can be applied to a buffer with
+/// [Buf::apply_mask][crate::Buf::apply_mask].
+///
+/// Solo overrides mute: if any channel is soloed, only the soloed channels are
+/// audible regardless of whether they're muted. Otherwise every channel which
+/// isn't muted is audible.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::ChannelMask;
+///
+/// let mut mask = ChannelMask::new();
+/// mask.mute(1);
+///
+/// assert!(mask.is_audible(0));
+/// assert!(!mask.is_audible(1));
+///
+/// mask.solo(1);
+///
+/// assert!(!mask.is_audible(0));
+/// assert!(mask.is_audible(1));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChannelMask {
+    muted: Vec<u64>,
+    soloed: Vec<u64>,
+}
+
+impl ChannelMask {
+    /// Construct a new mask where no channel is muted or soloed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mask = audio::ChannelMask::new();
+    ///
+    /// assert!(mask.is_audible(0));
+    /// assert!(mask.is_audible(1000));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mute the given channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut mask = audio::ChannelMask::new();
+    /// mask.mute(3);
+    ///
+    /// assert!(mask.is_muted(3));
+    /// assert!(!mask.is_audible(3));
+    /// ```
+    pub fn mute(&mut self, channel: usize) {
+        set(&mut self.muted, channel);
+    }
+
+    /// Unmute the given channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut mask = audio::ChannelMask::new();
+    /// mask.mute(3);
+    /// mask.unmute(3);
+    ///
+    /// assert!(!mask.is_muted(3));
+    /// assert!(mask.is_audible(3));
+    /// ```
+    pub fn unmute(&mut self, channel: usize) {
+        clear(&mut self.muted, channel);
+    }
+
+    /// Test if the given channel is muted.
+    ///
+    /// Note that a muted channel is still audible if it's soloed. See
+    /// [ChannelMask::is_audible].
+    pub fn is_muted(&self, channel: usize) -> bool {
+        test(&self.muted, channel)
+    }
+
+    /// Solo the given channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut mask = audio::ChannelMask::new();
+    /// mask.solo(2);
+    ///
+    /// assert!(mask.is_soloed(2));
+    /// assert!(mask.is_audible(2));
+    /// assert!(!mask.is_audible(0));
+    /// ```
+    pub fn solo(&mut self, channel: usize) {
+        set(&mut self.soloed, channel);
+    }
+
+    /// Unsolo the given channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut mask = audio::ChannelMask::new();
+    /// mask.solo(2);
+    /// mask.unsolo(2);
+    ///
+    /// assert!(!mask.is_soloed(2));
+    /// assert!(mask.is_audible(0));
+    /// ```
+    pub fn unsolo(&mut self, channel: usize) {
+        clear(&mut self.soloed, channel);
+    }
+
+    /// Test if the given channel is soloed.
+    pub fn is_soloed(&self, channel: usize) -> bool {
+        test(&self.soloed, channel)
+    }
+
+    /// Test if the given channel is audible.
+    ///
+    /// If any channel is soloed, this is only true for soloed channels.
+    /// Otherwise it's true for every channel which isn't muted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut mask = audio::ChannelMask::new();
+    /// mask.mute(0);
+    /// mask.mute(1);
+    /// mask.solo(1);
+    ///
+    /// assert!(!mask.is_audible(0));
+    /// assert!(mask.is_audible(1));
+    /// assert!(!mask.is_audible(2));
+    /// ```
+    pub fn is_audible(&self, channel: usize) -> bool {
+        if self.soloed.iter().any(|w| *w != 0) {
+            self.is_soloed(channel)
+        } else {
+            !self.is_muted(channel)
+        }
+    }
+}
+
+fn test(words: &[u64], index: usize) -> bool {
+    match words.get(index / BITS) {
+        Some(word) => word & (1 << (index % BITS)) != 0,
+        None => false,
+    }
+}
+
+fn set(words: &mut Vec<u64>, index: usize) {
+    let word = index / BITS;
+
+    if word >= words.len() {
+        words.resize(word + 1, 0);
+    }
+
+    words[word] |= 1 << (index % BITS);
+}
+
+fn clear(words: &mut [u64], index: usize) {
+    if let Some(word) = words.get_mut(index / BITS) {
+        *word &= !(1 << (index % BITS));
+    }
+}
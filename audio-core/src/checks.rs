@@ -0,0 +1,108 @@
+//! Debug checks of the invariants which audio buffers must uphold.
+//!
+//! These are only performed if the `debug-checks` feature is enabled, and
+//! even then only in builds with debug assertions. Otherwise they compile to
+//! nothing. They're called from central places like the constructors of
+//! [Channel][crate::Channel] and [ChannelMut][crate::ChannelMut], so buffers
+//! and adapters built on top of them are checked without having to opt in.
+
+use crate::{Channels, ExactSizeBuf};
+
+/// Check that every channel of `buf` has exactly as many frames as it
+/// reports through [ExactSizeBuf::frames].
+///
+/// # Panics
+///
+/// Panics if the `debug-checks` feature is enabled, debug assertions are
+/// enabled, and the check fails.
+#[inline]
+pub fn exact_size<B, T>(buf: &B)
+where
+    B: ?Sized + Channels<T> + ExactSizeBuf,
+{
+    #[cfg(feature = "debug-checks")]
+    {
+        let frames = buf.frames();
+
+        for chan in 0..buf.channels() {
+            let actual = buf.channel(chan).frames();
+
+            debug_assert! {
+                actual == frames,
+                "channel {} has {} frames, but the buffer reports {}",
+                chan,
+                actual,
+                frames
+            };
+        }
+    }
+
+    #[cfg(not(feature = "debug-checks"))]
+    let _ = buf;
+}
+
+/// Check that no channel of `buf` has more frames than it reports through
+/// [Buf::frames_hint][crate::Buf::frames_hint], if it reports any.
+///
+/// Unlike [exact_size] this doesn't require [ExactSizeBuf], so it's used
+/// where buffers are copied or translated. Channels are allowed to be shorter
+/// than the hint, like those of an io adapter which only exposes part of the
+/// underlying buffer.
+///
+/// # Panics
+///
+/// Panics if the `debug-checks` feature is enabled, debug assertions are
+/// enabled, and the check fails.
+#[inline]
+pub fn frames_hint<B, T>(buf: &B)
+where
+    B: ?Sized + Channels<T>,
+{
+    #[cfg(feature = "debug-checks")]
+    if let Some(frames) = buf.frames_hint() {
+        for chan in 0..buf.channels() {
+            let actual = buf.channel(chan).frames();
+
+            debug_assert! {
+                actual <= frames,
+                "channel {} has {} frames, but the buffer reports at most {}",
+                chan,
+                actual,
+                frames
+            };
+        }
+    }
+
+    #[cfg(not(feature = "debug-checks"))]
+    let _ = buf;
+}
+
+/// Check that the interleaved storage of `len` samples for a buffer with
+/// `channels` channels can be strided over for the given `channel`.
+///
+/// # Panics
+///
+/// Panics if the `debug-checks` feature is enabled, debug assertions are
+/// enabled, and the check fails.
+#[inline]
+pub fn interleaved_stride(len: usize, channels: usize, channel: usize) {
+    #[cfg(feature = "debug-checks")]
+    {
+        debug_assert! {
+            channel < channels,
+            "channel {} is out of bounds for an interleaved buffer with {} channels",
+            channel,
+            channels
+        };
+
+        debug_assert! {
+            len % channels == 0,
+            "interleaved storage of {} samples isn't a whole number of frames with {} channels",
+            len,
+            channels
+        };
+    }
+
+    #[cfg(not(feature = "debug-checks"))]
+    let _ = (len, channels, channel);
+}
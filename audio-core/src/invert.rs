@@ -0,0 +1,76 @@
+use crate::Sample;
+
+/// Trait for samples whose polarity can be inverted.
+///
+/// For signed and floating point samples this is negation, and for unsigned
+/// samples the value is reflected around the midpoint of its range. Signed
+/// integers saturate, so that the most negative value inverts to the most
+/// positive one.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::Invert as _;
+///
+/// assert_eq!(0.5f32.invert(), -0.5);
+/// assert_eq!(i16::MIN.invert(), i16::MAX);
+/// assert_eq!(128u8.invert(), 128);
+/// assert_eq!(0u8.invert(), 255);
+/// assert_eq!(192u8.invert(), 64);
+/// ```
+pub trait Invert: Sample {
+    /// Invert the polarity of the sample.
+    fn invert(self) -> Self;
+}
+
+macro_rules! float {
+    ($ty:ty) => {
+        impl Invert for $ty {
+            #[inline]
+            fn invert(self) -> Self {
+                -self
+            }
+        }
+    };
+}
+
+macro_rules! signed {
+    ($ty:ty) => {
+        impl Invert for $ty {
+            #[inline]
+            fn invert(self) -> Self {
+                self.saturating_neg()
+            }
+        }
+    };
+}
+
+macro_rules! unsigned {
+    ($unsigned:ty, $signed:ty) => {
+        impl Invert for $unsigned {
+            #[inline]
+            fn invert(self) -> Self {
+                let mid = (<$unsigned>::MAX >> 1) + 1;
+                let value = self.wrapping_sub(mid) as $signed;
+                (value.saturating_neg() as $unsigned).wrapping_add(mid)
+            }
+        }
+    };
+}
+
+float!(f32);
+float!(f64);
+
+signed!(i8);
+signed!(i16);
+signed!(i32);
+signed!(i64);
+signed!(i128);
+signed!(isize);
+
+unsigned!(u8, i8);
+unsigned!(u16, i16);
+unsigned!(u32, i32);
+unsigned!(u64, i64);
+unsigned!(u128, i128);
+unsigned!(usize, isize);
@@ -1,3 +1,8 @@
+//! Traits for buffered I/O.
+
+mod exact;
+pub use self::exact::Exact;
+
 /// Trait used to govern sequential reading of an audio buffer.
 ///
 /// This is the "in" part of "buffered I/O". It allows for buffers to govern
@@ -84,6 +89,36 @@ pub trait ReadBuf {
     /// assert_eq!(buffer.remaining(), 2);
     /// ```
     fn advance(&mut self, n: usize);
+
+    /// Construct a reader which reports exactly `frames` remaining.
+    ///
+    /// If this reader has more frames remaining they are truncated. If it has
+    /// fewer, the reader is padded with silence up to `frames`, which is
+    /// useful when an audio callback demands an exact block size regardless
+    /// of how much data is available.
+    ///
+    /// The padded frames are written by [Exact::copy_remaining].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::ReadBuf as _;
+    /// use audio::{io, wrap};
+    ///
+    /// let from = io::Read::new(audio::interleaved![[1, 2, 3]; 2]);
+    /// let mut exact = from.exact(4);
+    /// assert_eq!(exact.remaining(), 4);
+    ///
+    /// let mut out = [9; 8];
+    /// exact.copy_remaining(wrap::interleaved(&mut out[..], 2));
+    /// assert_eq!(out, [1, 1, 2, 2, 3, 3, 0, 0]);
+    /// ```
+    fn exact(self, frames: usize) -> Exact<Self>
+    where
+        Self: Sized,
+    {
+        Exact::new(self, frames)
+    }
 }
 
 impl<B> ReadBuf for &'_ mut B
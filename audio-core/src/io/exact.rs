@@ -0,0 +1,174 @@
+use crate::buf::{Buf, Channels, ChannelsMut, ExactSizeBuf};
+use crate::io::{ReadBuf, WriteBuf};
+use crate::sample::Sample;
+
+/// A reader which reports exactly a given number of frames remaining,
+/// truncating the underlying reader if it has more frames and padding it with
+/// silence if it has fewer.
+///
+/// Since the padded frames don't exist in the underlying buffer this doesn't
+/// implement [Channels]. Frames are instead read with
+/// [Exact::copy_remaining], which writes silence for any frames beyond the
+/// end of the underlying reader.
+///
+/// See [ReadBuf::exact].
+pub struct Exact<B> {
+    buf: B,
+    remaining: usize,
+}
+
+impl<B> Exact<B> {
+    /// Construct a new exact reader.
+    pub(crate) fn new(buf: B, frames: usize) -> Self {
+        Self {
+            buf,
+            remaining: frames,
+        }
+    }
+
+    /// Convert into the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::ReadBuf as _;
+    /// use audio::io;
+    ///
+    /// let from = io::Read::new(audio::interleaved![[1, 2, 3, 4]; 2]);
+    /// let mut exact = from.exact(2);
+    ///
+    /// exact.copy_remaining(audio::wrap::interleaved(&mut [0; 4][..], 2));
+    ///
+    /// let from = exact.into_inner();
+    /// assert_eq!(from.remaining(), 2);
+    /// ```
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Copy the remaining frames into `to`, advancing both this reader and
+    /// `to` by the number of frames copied.
+    ///
+    /// This copies the minimum number of frames between [ReadBuf::remaining]
+    /// and [WriteBuf::remaining_mut]. Frames which are read past the end of the
+    /// underlying reader are written as silence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{ReadBuf as _, WriteBuf as _};
+    /// use audio::{io, wrap};
+    ///
+    /// let from = wrap::interleaved(&[1, 2, 3, 4][..], 2);
+    /// let mut exact = from.exact(4);
+    ///
+    /// let mut out = [9; 8];
+    /// let mut to = wrap::interleaved(&mut out[..], 2);
+    ///
+    /// exact.copy_remaining(&mut to);
+    ///
+    /// assert!(!exact.has_remaining());
+    /// assert!(!to.has_remaining_mut());
+    /// assert_eq!(out, [1, 2, 3, 4, 0, 0, 0, 0]);
+    /// ```
+    pub fn copy_remaining<O, T>(&mut self, mut to: O)
+    where
+        B: ReadBuf + Channels<T>,
+        O: WriteBuf + ChannelsMut<T>,
+        T: Sample,
+    {
+        let len = usize::min(self.remaining, to.remaining_mut());
+        let end = usize::min(self.buf.channels(), to.channels());
+
+        for chan in 0..end {
+            let from = self.buf.channel(chan);
+            let mut from = from.iter();
+
+            for s in to.channel_mut(chan).iter_mut().take(len) {
+                *s = from.next().unwrap_or(T::ZERO);
+            }
+        }
+
+        self.advance(len);
+        to.advance_mut(len);
+    }
+}
+
+/// [Exact] adjusts the implementation of [ReadBuf] to report exactly the
+/// requested number of frames.
+///
+/// ```rust
+/// use audio::ReadBuf as _;
+/// use audio::wrap;
+///
+/// let from = wrap::interleaved(&[1, 2, 3, 4, 5, 6][..], 2);
+/// assert_eq!(from.remaining(), 3);
+///
+/// let mut exact = from.exact(5);
+/// assert_eq!(exact.remaining(), 5);
+///
+/// exact.advance(4);
+/// assert_eq!(exact.remaining(), 1);
+/// assert_eq!(exact.into_inner().remaining(), 0);
+/// ```
+impl<B> ReadBuf for Exact<B>
+where
+    B: ReadBuf,
+{
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn advance(&mut self, n: usize) {
+        let n = usize::min(n, self.remaining);
+        self.buf.advance(n);
+        self.remaining -= n;
+    }
+}
+
+/// [Exact] adjusts the implementation of [Buf] to report the remaining number
+/// of frames.
+///
+/// ```rust
+/// use audio::{Buf as _, ReadBuf as _};
+/// use audio::wrap;
+///
+/// let from = wrap::interleaved(&[1, 2, 3, 4][..], 2);
+/// let exact = from.exact(8);
+///
+/// assert_eq!(exact.channels(), 2);
+/// assert_eq!(exact.frames_hint(), Some(8));
+/// ```
+impl<B> Buf for Exact<B>
+where
+    B: Buf,
+{
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+/// [Exact] adjusts the implementation of [ExactSizeBuf] to report the
+/// remaining number of frames.
+///
+/// ```rust
+/// use audio::{ExactSizeBuf as _, ReadBuf as _};
+/// use audio::wrap;
+///
+/// let from = [1, 2, 3, 4];
+///
+/// assert_eq!(wrap::interleaved(&from[..], 2).exact(1).frames(), 1);
+/// assert_eq!(wrap::interleaved(&from[..], 2).exact(8).frames(), 8);
+/// ```
+impl<B> ExactSizeBuf for Exact<B>
+where
+    B: Buf,
+{
+    fn frames(&self) -> usize {
+        self.remaining
+    }
+}
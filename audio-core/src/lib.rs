@@ -11,18 +11,30 @@
 
 mod buf;
 pub use self::buf::{
-    AsInterleaved, AsInterleavedMut, Buf, Channels, ChannelsMut, ExactSizeBuf, InterleavedBuf,
-    ResizableBuf,
+    AsInterleaved, AsInterleavedMut, Buf, Channels, ChannelsIter, ChannelsIterMut, ChannelsMut,
+    Chunk, Cycle, ExactSizeBuf, Gained, InterleavedBuf, Limit, Masked, Range, ResizableBuf, Skip,
+    Tail, TakeChannels,
 };
 
 mod channel;
 pub use self::channel::{Channel, ChannelMut};
 
+pub mod checks;
+
+mod channel_mask;
+pub use self::channel_mask::ChannelMask;
+
 mod translate;
 pub use self::translate::Translate;
 
 mod sample;
 pub use self::sample::Sample;
 
+mod invert;
+pub use self::invert::Invert;
+
+mod approx_eq;
+pub use self::approx_eq::ApproxEq;
+
 mod io;
-pub use self::io::{ReadBuf, WriteBuf};
+pub use self::io::{Exact, ReadBuf, WriteBuf};
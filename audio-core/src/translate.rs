@@ -102,8 +102,12 @@ int_to_float!(i16, u16, f64);
 int_to_float!(i8, u8, f32);
 int_to_float!(i8, u8, f64);
 
+int_to_float!(i32, u32, f32);
+int_to_float!(i32, u32, f64);
+
 signed_to_unsigned!(i16, u16);
 signed_to_unsigned!(i8, u8);
+signed_to_unsigned!(i32, u32);
 
 impl Translate<f32> for f64 {
     #[inline]
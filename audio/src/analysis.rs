@@ -0,0 +1,9 @@
+//! Analysis of audio buffers, producing measurements or control signals
+//! rather than processed audio.
+
+mod envelope;
+pub(crate) use self::envelope::coefficient;
+pub use self::envelope::envelope;
+
+mod loudness;
+pub use self::loudness::integrated_loudness;
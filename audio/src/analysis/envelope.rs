@@ -0,0 +1,57 @@
+use crate::dynamic::Dynamic;
+use audio_core::{Channels, ExactSizeBuf};
+
+/// Compute the amplitude envelope of every channel in the buffer.
+///
+/// The envelope is the rectified signal smoothed by a one-pole follower,
+/// which rises towards louder samples with the `attack` time constant and
+/// falls towards quieter samples with the `release` time constant. Both are
+/// specified in seconds, and after one time constant the envelope has
+/// covered about 63% of the distance to a new steady level. A time constant
+/// of zero follows the signal immediately.
+///
+/// The envelope starts at zero and the returned buffer has the same topology
+/// as `buf`.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::analysis;
+///
+/// let buffer = audio::sequential![[-1.0f32; 4]; 2];
+/// let env = analysis::envelope(&buffer, 0.0, 0.1, 44100.0);
+///
+/// assert_eq!(env, audio::dynamic![[1.0f32; 4]; 2]);
+/// ```
+pub fn envelope<B>(buf: B, attack: f32, release: f32, sample_rate: f32) -> Dynamic<f32>
+where
+    B: Channels<f32> + ExactSizeBuf,
+{
+    let attack = coefficient(attack, sample_rate);
+    let release = coefficient(release, sample_rate);
+
+    let mut out = Dynamic::with_topology(buf.channels(), buf.frames());
+
+    for (chan, to) in out.iter_mut().enumerate() {
+        let mut env = 0.0f32;
+
+        for (o, s) in to.iter_mut().zip(buf.channel(chan).iter()) {
+            let s = s.abs();
+            let c = if s > env { attack } else { release };
+            env = s + c * (env - s);
+            *o = env;
+        }
+    }
+
+    out
+}
+
+/// Calculate the per-sample smoothing coefficient for a time constant in
+/// seconds.
+pub(crate) fn coefficient(time: f32, sample_rate: f32) -> f32 {
+    if time <= 0.0 {
+        return 0.0;
+    }
+
+    (-1.0 / (time * sample_rate)).exp()
+}
@@ -0,0 +1,91 @@
+use crate::buf;
+use crate::filter::Biquad;
+use audio_core::Channels;
+use std::f32::consts::PI;
+
+/// Measure the integrated loudness of the buffer in LUFS, which is how loud it
+/// is perceived to be on average.
+///
+/// This is a simplified approximation of the measurement described in
+/// [ITU-R BS.1770]. Every channel is passed through the K-weighting prefilter
+/// and the mean square of the filtered signal is summed across channels.
+/// Unlike the full specification, no gating is applied, so silent passages
+/// pull the measurement down, and every channel is weighted equally.
+///
+/// Silent or empty buffers have a loudness of negative infinity.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::analysis;
+/// use std::f32::consts::PI;
+///
+/// // A full scale 997 Hz tone in one channel reads as about -3 LUFS.
+/// let buffer = audio::generate!(|_, f| (2.0 * PI * 997.0 * f as f32 / 48000.0).sin(); [48000]; 1);
+/// let loudness = analysis::integrated_loudness(&buffer, 48000.0);
+///
+/// assert!((loudness - -3.01).abs() < 0.1);
+/// ```
+///
+/// [ITU-R BS.1770]: https://www.itu.int/rec/R-REC-BS.1770
+pub fn integrated_loudness<B>(buf: B, sample_rate: f32) -> f32
+where
+    B: Channels<f32>,
+{
+    let mut sum = 0.0f32;
+
+    for chan in 0..buf.channels() {
+        let mut channel = buf::channel_to_owned(&buf, chan);
+
+        if channel.frames() == 0 {
+            continue;
+        }
+
+        shelf(sample_rate).process(&mut channel);
+        highpass(sample_rate).process(&mut channel);
+
+        let square = channel[0].iter().map(|s| s * s).sum::<f32>();
+        sum += square / channel.frames() as f32;
+    }
+
+    -0.691 + 10.0 * sum.log10()
+}
+
+/// The first stage of the K-weighting prefilter, which is a high shelf that
+/// models the acoustic effect of the head.
+fn shelf(sample_rate: f32) -> Biquad {
+    const GAIN_DB: f32 = 3.999_843_8;
+    const Q: f32 = 0.707_175_24;
+    const CENTER: f32 = 1_681.974_5;
+
+    let k = (PI * CENTER / sample_rate).tan();
+    let vh = 10f32.powf(GAIN_DB / 20.0);
+    let vb = vh.powf(0.499_666_77);
+
+    Biquad::new(
+        vh + vb * k / Q + k * k,
+        2.0 * (k * k - vh),
+        vh - vb * k / Q + k * k,
+        1.0 + k / Q + k * k,
+        2.0 * (k * k - 1.0),
+        1.0 - k / Q + k * k,
+    )
+}
+
+/// The second stage of the K-weighting prefilter, which is a highpass filter
+/// that models the reduced sensitivity of hearing for low frequencies.
+fn highpass(sample_rate: f32) -> Biquad {
+    const Q: f32 = 0.500_327_04;
+    const CUTOFF: f32 = 38.135_47;
+
+    let k = (PI * CUTOFF / sample_rate).tan();
+
+    Biquad::new(
+        1.0,
+        -2.0,
+        1.0,
+        1.0 + k / Q + k * k,
+        2.0 * (k * k - 1.0),
+        1.0 - k / Q + k * k,
+    )
+}
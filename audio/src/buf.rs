@@ -1,4 +1,66 @@
 //! Utilities for working with buffers.
 
 mod utils;
-pub use self::utils::{copy, translate};
+pub use self::utils::{
+    channel_to_owned, copy, map_in_place, map_samples, next_power_of_two_frames, pad_to, to_owned,
+    translate,
+};
+
+mod approx_eq;
+pub use self::approx_eq::approx_eq;
+
+mod automation;
+pub use self::automation::apply_gain_envelope;
+
+mod calibrate;
+pub use self::calibrate::calibrate;
+
+mod clamp;
+pub use self::clamp::clamp;
+
+mod correlation;
+pub use self::correlation::correlation;
+
+mod fade;
+pub use self::fade::{fade_in, fade_out, fade_to_gain};
+
+mod finite;
+pub use self::finite::{find_non_finite, replace_non_finite};
+
+mod frame;
+pub use self::frame::for_each_frame;
+
+mod invert;
+pub use self::invert::invert_channels;
+
+mod silence;
+pub use self::silence::{active_bounds, is_silent, is_silent_below, silence_range, trim_silence};
+
+mod level;
+pub use self::level::{crest_factor, dbfs_peak, headroom_db, normalize_true_peak};
+
+mod overlap;
+pub use self::overlap::overlap_add;
+
+mod render;
+pub use self::render::{interleave_sources, render_to_interleaved, Downmix};
+
+#[cfg(feature = "fft")]
+mod spectrum;
+#[cfg(feature = "fft")]
+pub use self::spectrum::spectrum;
+
+mod smooth;
+pub use self::smooth::smooth_one_pole;
+
+mod stats;
+pub use self::stats::{stats, BufStats};
+
+mod stereo;
+pub use self::stereo::{split_stereo, stereo_from_mono};
+
+mod storage;
+pub use self::storage::shares_storage;
+
+mod waveshape;
+pub use self::waveshape::waveshape;
@@ -0,0 +1,38 @@
+//! Utilities for comparing audio buffers with a tolerance.
+
+use audio_core::{ApproxEq, Channels};
+
+/// Test if the buffers `a` and `b` have the same topology and all of their
+/// samples are equal within `epsilon`.
+///
+/// See [ApproxEq] for how samples are compared. This is useful for validating
+/// the output of floating point processing, which is rarely bit-exact.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let a = audio::interleaved![[0.5f32, 0.25], [1.0, -1.0]];
+/// let b = audio::sequential![[0.5001f32, 0.25], [1.0, -0.9999]];
+///
+/// assert!(buf::approx_eq(&a, &b, 0.001));
+/// assert!(!buf::approx_eq(&a, &b, 0.00001));
+/// ```
+pub fn approx_eq<A, B, T>(a: A, b: B, epsilon: T) -> bool
+where
+    A: Channels<T>,
+    B: Channels<T>,
+    T: ApproxEq,
+{
+    if a.channels() != b.channels() {
+        return false;
+    }
+
+    (0..a.channels()).all(|chan| {
+        let a = a.channel(chan);
+        let b = b.channel(chan);
+
+        a.frames() == b.frames() && a.iter().zip(b.iter()).all(|(a, b)| a.approx_eq(b, epsilon))
+    })
+}
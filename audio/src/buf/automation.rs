@@ -0,0 +1,54 @@
+//! Utilities for automating the gain of audio buffers.
+
+use audio_core::{Channels, ChannelsMut};
+
+/// Multiply every frame in the buffer by the corresponding value in the
+/// control buffer `env`, which applies an arbitrary volume automation curve.
+///
+/// If `env` has a single channel, it's applied to every channel in the buffer.
+/// Otherwise every channel is scaled by the matching channel in `env`.
+///
+/// Only the frames which are covered by `env` are scaled. Any frames past its
+/// end are left untouched.
+///
+/// # Panics
+///
+/// Panics if `env` has more than one channel and the number of channels
+/// doesn't match the buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1.0f32; 4]; 2];
+/// let env = audio::dynamic![[0.0f32, 0.25, 0.5, 0.75]];
+///
+/// buf::apply_gain_envelope(&mut buffer, &env);
+///
+/// assert_eq!(buffer, audio::interleaved![[0.0f32, 0.25, 0.5, 0.75]; 2]);
+/// ```
+pub fn apply_gain_envelope<B, E>(mut buf: B, env: E)
+where
+    B: ChannelsMut<f32>,
+    E: Channels<f32>,
+{
+    assert! {
+        env.channels() == 1 || env.channels() == buf.channels(),
+        "expected an envelope with 1 or {} channels, but got {}",
+        buf.channels(),
+        env.channels()
+    };
+
+    for chan in 0..buf.channels() {
+        let gains = if env.channels() == 1 {
+            env.channel(0)
+        } else {
+            env.channel(chan)
+        };
+
+        for (s, gain) in buf.channel_mut(chan).iter_mut().zip(gains.iter()) {
+            *s *= gain;
+        }
+    }
+}
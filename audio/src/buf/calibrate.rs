@@ -0,0 +1,44 @@
+//! Utilities for calibrating audio buffers.
+
+use audio_core::ChannelsMut;
+
+/// Calibrate every channel in the buffer by first subtracting the offset and
+/// then multiplying by the linear gain associated with it.
+///
+/// This is the usual correction applied to inputs from miscalibrated
+/// converters, where `offsets[n]` is the DC offset and `gains[n]` the gain
+/// correction of channel `n`.
+///
+/// # Panics
+///
+/// Panics unless there is exactly one offset and one gain for every channel
+/// in the buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[0.5f32, 1.5], [-0.25, 0.25]];
+/// buf::calibrate(&mut buffer, &[0.5, 0.0], &[1.0, 2.0]);
+///
+/// assert_eq!(buffer.as_slice(), &[0.0, -0.5, 1.0, 0.5]);
+/// ```
+pub fn calibrate<B>(mut buf: B, offsets: &[f32], gains: &[f32])
+where
+    B: ChannelsMut<f32>,
+{
+    assert! {
+        offsets.len() == buf.channels() && gains.len() == buf.channels(),
+        "expected {} offsets and gains, but got {} offsets and {} gains",
+        buf.channels(),
+        offsets.len(),
+        gains.len()
+    };
+
+    for (chan, (offset, gain)) in offsets.iter().zip(gains).enumerate() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            *s = (*s - offset) * gain;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Utility for hard limiting samples to a range.
+
+use audio_core::{ChannelsMut, Sample};
+
+/// Clamp every sample in the buffer to the inclusive range `[min, max]`.
+///
+/// This is a simple hard limiter, which is typically used to guard against
+/// out of range samples before converting to a different format. For floating
+/// point buffers the common range is `[-1.0, 1.0]`.
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[-1.5f32, -0.5, 0.5, 1.5]; 2];
+/// buf::clamp(&mut buffer, -1.0, 1.0);
+///
+/// assert_eq!(buffer.as_slice(), &[-1.0, -1.0, -0.5, -0.5, 0.5, 0.5, 1.0, 1.0]);
+/// ```
+pub fn clamp<B, T>(mut buf: B, min: T, max: T)
+where
+    B: ChannelsMut<T>,
+    T: Sample + PartialOrd,
+{
+    assert! {
+        min <= max,
+        "minimum must not be greater than maximum"
+    };
+
+    for chan in 0..buf.channels() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            if *s < min {
+                *s = min;
+            } else if *s > max {
+                *s = max;
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Utilities for measuring the correlation between channels.
+
+use audio_core::Channels;
+
+/// Compute the normalized cross-correlation coefficient between the channels
+/// `a` and `b` over their overlapping frames.
+///
+/// The result lies in the range `-1.0..=1.0`, where `1.0` means that the
+/// channels are perfectly in phase and `-1.0` that they are perfectly out of
+/// phase, which warns of phase cancellation if they're summed to mono. If
+/// either channel has no variance, like when it's silent, `0.0` is returned.
+///
+/// # Panics
+///
+/// Panics if either channel is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::dynamic![[1.0, -0.5, 0.25, -1.0], [-1.0, 0.5, -0.25, 1.0]];
+///
+/// assert!((buf::correlation(&buffer, 0, 0) - 1.0).abs() < 1e-6);
+/// assert!((buf::correlation(&buffer, 0, 1) + 1.0).abs() < 1e-6);
+/// ```
+pub fn correlation<B>(buf: B, a: usize, b: usize) -> f32
+where
+    B: Channels<f32>,
+{
+    for &chan in &[a, b] {
+        assert! {
+            chan < buf.channels(),
+            "channel {} is out of bounds 0-{}",
+            chan,
+            buf.channels()
+        };
+    }
+
+    let a = buf.channel(a);
+    let b = buf.channel(b);
+
+    let mut n = 0usize;
+    let mut sum_a = 0.0f64;
+    let mut sum_b = 0.0f64;
+
+    for (a, b) in a.iter().zip(b.iter()) {
+        n += 1;
+        sum_a += a as f64;
+        sum_b += b as f64;
+    }
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    let mean_a = sum_a / n as f64;
+    let mean_b = sum_b / n as f64;
+
+    let mut cov = 0.0f64;
+    let mut var_a = 0.0f64;
+    let mut var_b = 0.0f64;
+
+    for (a, b) in a.iter().zip(b.iter()) {
+        let a = a as f64 - mean_a;
+        let b = b as f64 - mean_b;
+        cov += a * b;
+        var_a += a * a;
+        var_b += b * b;
+    }
+
+    let denom = (var_a * var_b).sqrt();
+
+    if denom <= f64::EPSILON {
+        return 0.0;
+    }
+
+    (cov / denom) as f32
+}
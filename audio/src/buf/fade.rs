@@ -0,0 +1,125 @@
+//! Utilities for fading audio buffers in and out.
+
+use crate::level::Level;
+use audio_core::ChannelsMut;
+use std::f32::consts::FRAC_PI_2;
+
+/// The level in decibels below which [fade_to_gain] treats the gain as
+/// silence.
+const SILENCE_DB: f32 = -120.0;
+
+/// Fade in the first `frames` frames of every channel in the buffer from
+/// silence, leaving the rest of the buffer untouched.
+///
+/// The fade uses an equal-power curve, so the first frame is silent and the
+/// gain rises along a quarter sine towards unity. `frames` is clamped to the
+/// number of frames in each channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1.0f32; 8]; 2];
+/// buf::fade_in(&mut buffer, 4);
+///
+/// assert_eq!(buffer.as_slice()[0], 0.0);
+/// assert!(buffer.as_slice()[8..].iter().all(|s| *s == 1.0));
+/// ```
+pub fn fade_in<B>(mut buf: B, frames: usize)
+where
+    B: ChannelsMut<f32>,
+{
+    for chan in 0..buf.channels() {
+        let channel = buf.channel_mut(chan);
+        let frames = usize::min(frames, channel.frames());
+
+        for (n, s) in channel.limit(frames).iter_mut().enumerate() {
+            *s *= gain(n, frames);
+        }
+    }
+}
+
+/// Fade out the last `frames` frames of every channel in the buffer to
+/// silence, leaving the rest of the buffer untouched.
+///
+/// This is the mirror image of [fade_in], so the last frame is silent.
+/// `frames` is clamped to the number of frames in each channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::sequential![[1.0f32; 8]; 2];
+/// buf::fade_out(&mut buffer, 4);
+///
+/// assert!(buffer[0][..4].iter().all(|s| *s == 1.0));
+/// assert_eq!(buffer[0][7], 0.0);
+/// ```
+pub fn fade_out<B>(mut buf: B, frames: usize)
+where
+    B: ChannelsMut<f32>,
+{
+    for chan in 0..buf.channels() {
+        let channel = buf.channel_mut(chan);
+        let len = channel.frames();
+        let frames = usize::min(frames, len);
+
+        for (n, s) in channel.skip(len - frames).iter_mut().enumerate() {
+            *s *= gain(frames - 1 - n, frames);
+        }
+    }
+}
+
+/// Apply a gain which ramps from `from_db` to `target_db` decibels across the
+/// buffer, as a fader moving between two settings does.
+///
+/// The gain is interpolated linearly in decibels, which is exponential in
+/// linear gain and sounds smooth to the ear. The first frame has a gain of
+/// `from_db`, and the ramp is spread so that the frame just past the end of
+/// the buffer would have a gain of `target_db`. So a ramp can continue into
+/// the next buffer without discontinuities.
+///
+/// Either endpoint can be [f32::NEG_INFINITY] for silence. Levels are clamped
+/// to -120 dB for the ramp, and any frame at or below it is silenced.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 4]; 2];
+/// buf::fade_to_gain(&mut buffer, 0.0, -12.0);
+///
+/// for (s, db) in buffer[0].iter().zip([-12.0f32, -9.0, -6.0, -3.0].iter()) {
+///     assert!((s - 10f32.powf(db / 20.0)).abs() < 1e-6);
+/// }
+/// ```
+pub fn fade_to_gain<B>(mut buf: B, target_db: f32, from_db: f32)
+where
+    B: ChannelsMut<f32>,
+{
+    let from_db = f32::max(from_db, SILENCE_DB);
+    let target_db = f32::max(target_db, SILENCE_DB);
+
+    for chan in 0..buf.channels() {
+        let channel = buf.channel_mut(chan);
+        let frames = channel.frames();
+
+        for (n, s) in channel.iter_mut().enumerate() {
+            let db = from_db + (target_db - from_db) * (n as f32 / frames as f32);
+
+            if db <= SILENCE_DB {
+                *s = 0.0;
+            } else {
+                *s *= Level::from_db(db).as_linear();
+            }
+        }
+    }
+}
+
+/// The equal-power gain of frame `n` in a fade of `frames` frames.
+fn gain(n: usize, frames: usize) -> f32 {
+    (n as f32 / frames as f32 * FRAC_PI_2).sin()
+}
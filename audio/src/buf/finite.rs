@@ -0,0 +1,63 @@
+//! Utilities for detecting and scrubbing non-finite samples.
+
+use audio_core::{Channels, ChannelsMut};
+
+/// Find the first sample in the buffer which is either NaN or infinite,
+/// returning its `(channel, frame)` position.
+///
+/// Channels are searched in order, so a non-finite sample in an earlier
+/// channel is reported before one in a later channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::sequential![[0.0f32; 4]; 2];
+/// assert_eq!(buf::find_non_finite(&buffer), None);
+///
+/// buffer[1][2] = f32::NAN;
+/// assert_eq!(buf::find_non_finite(&buffer), Some((1, 2)));
+///
+/// buffer[0][3] = f32::INFINITY;
+/// assert_eq!(buf::find_non_finite(&buffer), Some((0, 3)));
+/// ```
+pub fn find_non_finite<B>(buf: B) -> Option<(usize, usize)>
+where
+    B: Channels<f32>,
+{
+    for chan in 0..buf.channels() {
+        if let Some(frame) = buf.channel(chan).iter().position(|s| !s.is_finite()) {
+            return Some((chan, frame));
+        }
+    }
+
+    None
+}
+
+/// Replace every sample in the buffer which is either NaN or infinite with
+/// `with`.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1.0f32, f32::NAN, f32::NEG_INFINITY, 2.0]; 2];
+/// buf::replace_non_finite(&mut buffer, 0.0);
+///
+/// assert_eq!(buf::find_non_finite(&buffer), None);
+/// assert_eq!(buffer.as_slice(), &[1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0, 2.0]);
+/// ```
+pub fn replace_non_finite<B>(mut buf: B, with: f32)
+where
+    B: ChannelsMut<f32>,
+{
+    for chan in 0..buf.channels() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            if !s.is_finite() {
+                *s = with;
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Utilities for operating on audio buffers one frame at a time.
+
+use audio_core::{ChannelsMut, ExactSizeBuf, Sample};
+
+/// The number of channels which a frame can have before [for_each_frame]
+/// has to allocate its scratch space on the heap.
+const STACK_CHANNELS: usize = 32;
+
+/// Call `f` with a mutable slice containing the sample of every channel for
+/// one frame at a time, writing back any modifications made to it.
+///
+/// This is useful for operations which need all channels of a frame at the
+/// same time, like applying a rotation or crosstalk matrix. Each frame is
+/// gathered into a scratch buffer which lives on the stack for buffers with
+/// up to 32 channels and is allocated once on the heap otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1, 2, 3], [4, 5, 6]];
+/// buf::for_each_frame(&mut buffer, |frame| frame.swap(0, 1));
+///
+/// assert_eq!(buffer, audio::interleaved![[4, 5, 6], [1, 2, 3]]);
+/// ```
+pub fn for_each_frame<B, T, F>(buf: B, f: F)
+where
+    B: ChannelsMut<T> + ExactSizeBuf,
+    T: Sample,
+    F: FnMut(&mut [T]),
+{
+    let channels = buf.channels();
+
+    if channels <= STACK_CHANNELS {
+        let mut scratch = [T::ZERO; STACK_CHANNELS];
+        apply(buf, &mut scratch[..channels], f);
+    } else {
+        let mut scratch = vec![T::ZERO; channels];
+        apply(buf, &mut scratch, f);
+    }
+}
+
+fn apply<B, T, F>(mut buf: B, scratch: &mut [T], mut f: F)
+where
+    B: ChannelsMut<T> + ExactSizeBuf,
+    T: Copy,
+    F: FnMut(&mut [T]),
+{
+    for n in 0..buf.frames() {
+        for (chan, s) in scratch.iter_mut().enumerate() {
+            *s = buf.channel(chan)[n];
+        }
+
+        f(scratch);
+
+        for (chan, s) in scratch.iter().enumerate() {
+            buf.channel_mut(chan)[n] = *s;
+        }
+    }
+}
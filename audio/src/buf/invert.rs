@@ -0,0 +1,43 @@
+//! Utilities for inverting the polarity of audio buffers.
+
+use audio_core::{ChannelsMut, Invert};
+
+/// Invert the polarity of every sample in the given `channels`, leaving other
+/// channels untouched.
+///
+/// See [Invert] for how each type of sample is inverted.
+///
+/// # Panics
+///
+/// Panics if any of the channels is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[0.5f32, -0.25], [0.5, -0.25]];
+/// buf::invert_channels(&mut buffer, &[1]);
+///
+/// assert_eq!(buffer.as_slice(), &[0.5, -0.5, -0.25, 0.25]);
+/// ```
+pub fn invert_channels<B, T>(mut buf: B, channels: &[usize])
+where
+    B: ChannelsMut<T>,
+    T: Invert,
+{
+    for &chan in channels {
+        assert! {
+            chan < buf.channels(),
+            "channel {} is out of bounds 0-{}",
+            chan,
+            buf.channels()
+        };
+    }
+
+    for &chan in channels {
+        for s in buf.channel_mut(chan).iter_mut() {
+            *s = s.invert();
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Utilities for measuring the level of audio buffers.
+
+use audio_core::{Channels, ChannelsMut};
+use std::f32::consts::PI;
+
+/// The oversampling factor used when estimating true peaks.
+const OVERSAMPLING: usize = 4;
+
+/// The number of neighbouring samples on either side which are used to
+/// interpolate a value between samples.
+const INTERPOLATION_TAPS: usize = 16;
+
+/// Get the peak level of the buffer in dBFS, that is in decibels relative to
+/// full scale.
+///
+/// A buffer which peaks at full scale (a magnitude of `1.0`) has a level of
+/// `0.0` dBFS. A silent buffer has a level of [f32::NEG_INFINITY].
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::interleaved![[0.5f32, -1.0, 0.25]; 2];
+/// assert!(buf::dbfs_peak(&buffer).abs() < 1e-6);
+///
+/// let buffer = audio::interleaved![[0.0f32; 4]; 2];
+/// assert_eq!(buf::dbfs_peak(&buffer), f32::NEG_INFINITY);
+/// ```
+pub fn dbfs_peak<B>(buf: B) -> f32
+where
+    B: Channels<f32>,
+{
+    20.0 * peak(buf).log10()
+}
+
+/// Get the headroom of the buffer in decibels, which is how much the buffer
+/// can be amplified before its peak clips at full scale.
+///
+/// This is the negation of [dbfs_peak]. A silent buffer has an infinite
+/// amount of headroom.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::sequential![[0.5f32, -0.25, 0.125]; 2];
+/// assert!((buf::headroom_db(&buffer) - 6.0206).abs() < 1e-3);
+///
+/// let buffer = audio::sequential![[0.0f32; 4]; 2];
+/// assert_eq!(buf::headroom_db(&buffer), f32::INFINITY);
+/// ```
+pub fn headroom_db<B>(buf: B) -> f32
+where
+    B: Channels<f32>,
+{
+    -dbfs_peak(buf)
+}
+
+/// Get the crest factor of every channel in the buffer in decibels, which is
+/// the ratio between its peak and its RMS level.
+///
+/// This describes the dynamics of a signal. Signals with sharp transients have
+/// a high crest factor, while heavily compressed signals have a low one. A
+/// sine tone has a crest factor of about 3 dB, and a square wave one of 0 dB.
+///
+/// Silent or empty channels have a crest factor of `0.0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::sequential![[1.0f32, -1.0, 1.0, -1.0], [0.0, 0.0, 1.0, 0.0], [0.0; 4]];
+/// let crest = buf::crest_factor(&buffer);
+///
+/// assert_eq!(crest.len(), 3);
+/// assert!(crest[0].abs() < 1e-6);
+/// assert!((crest[1] - 6.0206).abs() < 1e-3);
+/// assert_eq!(crest[2], 0.0);
+/// ```
+pub fn crest_factor<B>(buf: B) -> Vec<f32>
+where
+    B: Channels<f32>,
+{
+    (0..buf.channels())
+        .map(|chan| {
+            let channel = buf.channel(chan);
+
+            let (peak, sum) = channel.iter().fold((0.0f32, 0.0f32), |(peak, sum), s| {
+                (f32::max(peak, s.abs()), sum + s * s)
+            });
+
+            if peak == 0.0 {
+                return 0.0;
+            }
+
+            let rms = (sum / channel.frames() as f32).sqrt();
+            20.0 * (peak / rms).log10()
+        })
+        .collect()
+}
+
+/// Normalize the buffer so that its true peak is at `target_dbfs`.
+///
+/// Normalizing by the sample peak doesn't guarantee that the reconstructed
+/// signal won't clip, since the analog waveform can peak in between samples.
+/// To account for this, the true peak is estimated by oversampling the buffer
+/// four times, as recommended by ITU-R BS.1770. The values in between samples
+/// are interpolated with a Hann windowed sinc kernel spanning 16 samples on
+/// either side, treating samples outside of the buffer as silence.
+///
+/// A silent buffer is left untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// // Every sample has a magnitude of sqrt(1/2), but the signal is a sine
+/// // tone at a quarter of the sample rate which peaks at 1.0 in between
+/// // samples.
+/// let mut buffer = audio::generate!(|_, f| (std::f32::consts::PI * (f as f32 / 2.0 + 0.25)).sin(); [256]; 1);
+///
+/// buf::normalize_true_peak(&mut buffer, -6.0);
+///
+/// // The sample peak ends up 3 dB below the target.
+/// assert!(buf::dbfs_peak(&buffer) <= -9.0);
+/// ```
+pub fn normalize_true_peak<B>(mut buf: B, target_dbfs: f32)
+where
+    B: ChannelsMut<f32>,
+{
+    let peak = true_peak(&buf);
+
+    if peak == 0.0 {
+        return;
+    }
+
+    let gain = 10f32.powf(target_dbfs / 20.0) / peak;
+
+    for chan in 0..buf.channels() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            *s *= gain;
+        }
+    }
+}
+
+/// Estimate the largest magnitude of the reconstructed signal in the buffer,
+/// including in between samples.
+fn true_peak<B>(buf: B) -> f32
+where
+    B: Channels<f32>,
+{
+    // The kernels used to interpolate every fractional position in between
+    // two samples.
+    let kernels = (1..OVERSAMPLING)
+        .map(|phase| {
+            let offset = phase as f32 / OVERSAMPLING as f32;
+            let half = INTERPOLATION_TAPS as f32;
+
+            (0..INTERPOLATION_TAPS * 2)
+                .map(|k| {
+                    // NB: the distance from the sample at `n + 1 - TAPS + k`.
+                    let x = offset + INTERPOLATION_TAPS as f32 - 1.0 - k as f32;
+                    let window = 0.5 + 0.5 * (PI * x / half).cos();
+                    sinc(x) * window
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut peak = self::peak(&buf);
+
+    for chan in 0..buf.channels() {
+        let channel = buf.channel(chan);
+        let frames = channel.frames();
+
+        for n in 0..frames {
+            for kernel in &kernels {
+                let mut value = 0.0;
+
+                for (k, coeff) in kernel.iter().enumerate() {
+                    let at = (n + 1 + k).wrapping_sub(INTERPOLATION_TAPS);
+
+                    if at < frames {
+                        value += channel[at] * coeff;
+                    }
+                }
+
+                peak = f32::max(peak, value.abs());
+            }
+        }
+    }
+
+    peak
+}
+
+/// The normalized sinc function.
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+/// Get the largest sample magnitude in the buffer.
+fn peak<B>(buf: B) -> f32
+where
+    B: Channels<f32>,
+{
+    (0..buf.channels())
+        .flat_map(|chan| buf.channel(chan).iter())
+        .fold(0.0, |peak, s| f32::max(peak, s.abs()))
+}
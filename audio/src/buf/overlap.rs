@@ -0,0 +1,81 @@
+//! Overlap-add reconstruction of processed blocks.
+
+use crate::dynamic::Dynamic;
+use audio_core::{Channels, ExactSizeBuf, Sample, Translate};
+
+/// Sum `blocks` back together into a single buffer, offsetting each block by
+/// `hop` frames from the previous one.
+///
+/// This is the inverse of splitting a signal into overlapping, windowed blocks
+/// as is done in STFT-style processing. The resulting buffer has
+/// `(blocks.len() - 1) * hop + frames` frames, where `frames` is the length of
+/// the longest block.
+///
+/// Samples are accumulated as [f64] through the [Translate] trait, so
+/// integer samples saturate instead of overflowing when translated back.
+///
+/// # Panics
+///
+/// Panics if the blocks don't all have the same number of channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let a = audio::dynamic![[1.0f32, 1.0, 1.0, 1.0]];
+/// let b = audio::dynamic![[0.5f32, 0.5, 0.5, 0.5]];
+///
+/// let out = buf::overlap_add(&[a, b], 2);
+/// assert_eq!(out, audio::dynamic![[1.0f32, 1.0, 1.5, 1.5, 0.5, 0.5]]);
+/// ```
+pub fn overlap_add<B, T>(blocks: &[B], hop: usize) -> Dynamic<T>
+where
+    B: Channels<T> + ExactSizeBuf,
+    T: Sample + Translate<f64>,
+    f64: Translate<T>,
+{
+    let channels = match blocks.first() {
+        Some(block) => block.channels(),
+        None => return Dynamic::new(),
+    };
+
+    let frames = blocks
+        .iter()
+        .enumerate()
+        .map(|(n, block)| {
+            assert! {
+                block.channels() == channels,
+                "block {} has {} channels, but expected {}",
+                n,
+                block.channels(),
+                channels
+            };
+
+            n * hop + block.frames()
+        })
+        .max()
+        .unwrap_or_default();
+
+    let mut acc = vec![0.0f64; channels * frames];
+
+    for (n, block) in blocks.iter().enumerate() {
+        for chan in 0..channels {
+            let start = chan * frames + n * hop;
+
+            for (o, s) in acc[start..].iter_mut().zip(block.channel(chan)) {
+                *o += f64::translate(s);
+            }
+        }
+    }
+
+    let mut out = Dynamic::with_topology(channels, frames);
+
+    for (chan, acc) in acc.chunks(usize::max(frames, 1)).enumerate().take(channels) {
+        for (o, s) in out[chan].iter_mut().zip(acc) {
+            *o = T::translate(*s);
+        }
+    }
+
+    out
+}
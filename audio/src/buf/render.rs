@@ -0,0 +1,181 @@
+//! Utilities for rendering audio buffers into output devices.
+
+use audio_core::{Channels, Sample, Translate};
+
+/// The policy used by [render_to_interleaved] when the source has more
+/// channels than the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Downmix {
+    /// Channels which don't exist in the output are dropped.
+    Drop,
+    /// Every source channel `c` is mixed into output channel
+    /// `c % out_channels`, averaging the channels which are mixed together.
+    ///
+    /// For a quadraphonic source in the order front left, front right, rear
+    /// left and rear right rendered to stereo, this mixes the left and right
+    /// channels together respectively.
+    Average,
+}
+
+/// Render the buffer `src` into the flat interleaved slice `out`, which has
+/// `out_channels` channels, converting the number of channels as needed.
+///
+/// Channels are mapped as follows:
+/// * If `src` has the same number of channels as the output, they're copied
+///   as-is.
+/// * If `src` is mono, it's broadcast to every output channel.
+/// * If `src` has fewer channels than the output, they're copied to the first
+///   output channels and the rest are silenced.
+/// * If `src` has more channels than the output, they're handled according to
+///   `downmix`. See [Downmix].
+///
+/// Averaging is performed as [f32], so samples are translated to and from it.
+///
+/// The number of frames rendered is the number of whole frames which fit in
+/// `out`, and is returned. Frames past the end of `src` are silenced. Any
+/// trailing samples which don't make up a whole frame are left untouched.
+///
+/// # Panics
+///
+/// Panics if `out_channels` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf::{self, Downmix};
+///
+/// let src = audio::sequential![[1.0f32, 2.0, 3.0]];
+/// let mut out = [0.0f32; 6];
+///
+/// let frames = buf::render_to_interleaved(&src, &mut out, 2, Downmix::Average);
+/// assert_eq!(frames, 3);
+/// assert_eq!(out, [1.0, 1.0, 2.0, 2.0, 3.0, 3.0]);
+/// ```
+pub fn render_to_interleaved<B, T>(
+    src: B,
+    out: &mut [T],
+    out_channels: usize,
+    downmix: Downmix,
+) -> usize
+where
+    B: Channels<T>,
+    T: Sample + Translate<f32>,
+    f32: Translate<T>,
+{
+    assert!(
+        out_channels != 0,
+        "number of output channels must be non-zero"
+    );
+
+    let src_channels = src.channels();
+    let frames = out.len() / out_channels;
+    let out = &mut out[..frames * out_channels];
+
+    for o in 0..out_channels {
+        let (start, end) = match (src_channels, downmix) {
+            (1, _) => (0, 1),
+            (_, Downmix::Average) => (o, src_channels),
+            (_, Downmix::Drop) => (o, usize::min(o + 1, src_channels)),
+        };
+
+        let group = (start..end).step_by(out_channels);
+
+        let to = out.iter_mut().skip(o).step_by(out_channels);
+
+        match group.len() {
+            0 => {
+                for s in to {
+                    *s = T::ZERO;
+                }
+            }
+            1 => {
+                let from = src.channel(start);
+
+                for (n, s) in to.enumerate() {
+                    *s = if n < from.frames() { from[n] } else { T::ZERO };
+                }
+            }
+            len => {
+                for (n, s) in to.enumerate() {
+                    let mut sum = 0.0f32;
+
+                    for c in group.clone() {
+                        let from = src.channel(c);
+
+                        if n < from.frames() {
+                            sum += f32::translate(from[n]);
+                        }
+                    }
+
+                    *s = T::translate(sum / len as f32);
+                }
+            }
+        }
+    }
+
+    frames
+}
+
+/// Interleave the channels of several separate `sources` into the flat slice
+/// `out`, as if they were a single buffer.
+///
+/// Every source contributes its channels in order, so the output has as many
+/// channels as all sources combined. This allows for interleaving sources
+/// which are kept separate, like a collection of mono buffers, without first
+/// merging them into one buffer.
+///
+/// The number of frames rendered is the length of `out` divided by the total
+/// number of channels, and is returned. Frames past the end of a source are
+/// silenced.
+///
+/// # Panics
+///
+/// Panics if the sources have no channels, or if the length of `out` isn't a
+/// multiple of the total number of channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let left = audio::dynamic![[1.0f32, 2.0]];
+/// let rest = audio::sequential![[3.0f32, 4.0], [5.0, 6.0]];
+/// let mut out = [0.0f32; 6];
+///
+/// let frames = buf::interleave_sources(&[&left, &rest], &mut out);
+/// assert_eq!(frames, 2);
+/// assert_eq!(out, [1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+/// ```
+pub fn interleave_sources<T>(sources: &[&dyn Channels<T>], out: &mut [T]) -> usize
+where
+    T: Sample,
+{
+    let channels = sources.iter().map(|s| s.channels()).sum::<usize>();
+
+    assert!(channels != 0, "sources must have at least one channel");
+
+    assert! {
+        out.len() % channels == 0,
+        "output of length {} doesn't fit a whole number of frames with {} channels",
+        out.len(),
+        channels
+    };
+
+    let frames = out.len() / channels;
+    let mut o = 0;
+
+    for source in sources {
+        for chan in 0..source.channels() {
+            let from = source.channel(chan);
+            let to = out.iter_mut().skip(o).step_by(channels);
+
+            for (n, s) in to.enumerate() {
+                *s = if n < from.frames() { from[n] } else { T::ZERO };
+            }
+
+            o += 1;
+        }
+    }
+
+    frames
+}
@@ -0,0 +1,213 @@
+//! Utilities for detecting and producing silence in audio buffers.
+
+use audio_core::{Buf, Channels, ChannelsMut, ExactSizeBuf, Limit, Sample, Skip};
+use std::ops;
+
+/// Test if every sample in the buffer is silent.
+///
+/// A sample is considered silent if it is equal to [Sample::ZERO]. This stops
+/// at the first sample which isn't silent.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[0i16; 4]; 2];
+/// assert!(buf::is_silent(&buffer));
+///
+/// *buffer.frame_mut(1, 2).unwrap() = 1;
+/// assert!(!buf::is_silent(&buffer));
+/// ```
+pub fn is_silent<B, T>(buf: B) -> bool
+where
+    B: Channels<T>,
+    T: Sample + PartialEq,
+{
+    (0..buf.channels()).all(|chan| buf.channel(chan).iter().all(|s| s == T::ZERO))
+}
+
+/// Test if every sample in the buffer is silent, tolerating samples whose
+/// magnitude doesn't exceed `threshold`.
+///
+/// This is useful for floating point buffers where processing might leave a
+/// bit of numerical fuzz around [Sample::ZERO]. This stops at the first
+/// sample which isn't silent.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::dynamic![[0.0, 0.0001, -0.0001, 0.0]; 2];
+///
+/// assert!(!buf::is_silent(&buffer));
+/// assert!(buf::is_silent_below(&buffer, 0.001));
+/// assert!(!buf::is_silent_below(&buffer, 0.00001));
+/// ```
+pub fn is_silent_below<B, T>(buf: B, threshold: T) -> bool
+where
+    B: Channels<T>,
+    T: Sample + PartialOrd + ops::Neg<Output = T>,
+{
+    let min = -threshold;
+
+    (0..buf.channels()).all(|chan| buf.channel(chan).iter().all(|s| s <= threshold && s >= min))
+}
+
+/// Trim leading and trailing silence from the buffer.
+///
+/// A frame is considered silent if the sample in every channel has a magnitude
+/// which doesn't exceed `threshold`. The returned buffer is a view over the
+/// active region of `buf`, which is the region starting with the first
+/// non-silent frame and ending with the last non-silent frame.
+///
+/// If the whole buffer is silent, the returned view is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::{buf, ExactSizeBuf as _};
+///
+/// let from = audio::interleaved![[0.0, 0.0, 0.5, 0.0, 0.25, 0.0], [0.0, 0.0, 0.0, -1.0, 0.0, 0.0]];
+/// let trimmed = buf::trim_silence(&from, 0.0);
+///
+/// assert_eq!(trimmed.frames(), 3);
+///
+/// let mut to = audio::Interleaved::with_topology(2, 3);
+/// buf::copy(trimmed, &mut to);
+///
+/// assert_eq!(to.as_slice(), &[0.5, 0.0, 0.0, -1.0, 0.25, 0.0]);
+/// ```
+///
+/// A fully silent buffer is trimmed down to nothing.
+///
+/// ```rust
+/// use audio::{buf, ExactSizeBuf as _};
+///
+/// let from = audio::dynamic![[0.0, 0.001, -0.001, 0.0]; 2];
+///
+/// assert_eq!(buf::trim_silence(&from, 0.01).frames(), 0);
+/// assert_eq!(buf::trim_silence(&from, 0.0).frames(), 2);
+/// ```
+pub fn trim_silence<B, T>(buf: B, threshold: T) -> Limit<Skip<B>>
+where
+    B: Channels<T> + ExactSizeBuf,
+    T: Sample + PartialOrd + ops::Neg<Output = T>,
+{
+    let min = -threshold;
+    let frames = buf.frames();
+
+    let mut start = frames;
+    let mut end = 0;
+
+    for chan in 0..buf.channels() {
+        let channel = buf.channel(chan);
+        let is_active = |s: T| s > threshold || s < min;
+
+        if let Some(first) = channel.iter().position(is_active) {
+            start = usize::min(start, first);
+        }
+
+        if let Some(last) = channel.iter().rposition(is_active) {
+            end = usize::max(end, last + 1);
+        }
+    }
+
+    if start >= end {
+        return buf.skip(frames).limit(0);
+    }
+
+    buf.skip(start).limit(end - start)
+}
+
+/// Find the smallest bounding box of channels and frames which contains every
+/// sample whose magnitude exceeds `threshold`, returned as a `(channels,
+/// frames)` pair of ranges.
+///
+/// This is useful for cropping recordings and for detecting which channels
+/// actually carry a signal. The buffer is only scanned once.
+///
+/// If the whole buffer is silent, both returned ranges are empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::sequential![[0.0, 0.0, 0.0, 0.0], [0.0, 0.5, 0.0, 0.0], [0.0, 0.0, -0.5, 0.0]];
+///
+/// assert_eq!(buf::active_bounds(&buffer, 0.0), (1..3, 1..3));
+/// assert_eq!(buf::active_bounds(&buffer, 0.5), (0..0, 0..0));
+/// ```
+pub fn active_bounds<B, T>(buf: B, threshold: T) -> (ops::Range<usize>, ops::Range<usize>)
+where
+    B: Channels<T>,
+    T: Sample + PartialOrd + ops::Neg<Output = T>,
+{
+    let min = -threshold;
+    let is_active = |s: T| s > threshold || s < min;
+
+    let mut channels = None;
+    let mut start = usize::MAX;
+    let mut end = 0;
+
+    for chan in 0..buf.channels() {
+        let channel = buf.channel(chan);
+
+        let first = match channel.iter().position(is_active) {
+            Some(first) => first,
+            None => continue,
+        };
+
+        // NB: there's at least one active sample, so this can't fail.
+        let last = channel.iter().rposition(is_active).unwrap_or(first);
+
+        let first_chan = channels.map_or(chan, |c: ops::Range<usize>| c.start);
+        channels = Some(first_chan..chan + 1);
+        start = usize::min(start, first);
+        end = usize::max(end, last + 1);
+    }
+
+    match channels {
+        Some(channels) => (channels, start..end),
+        None => (0..0, 0..0),
+    }
+}
+
+/// Silence the frames in the range `start..end` in every channel of the
+/// buffer, by setting them to [Sample::ZERO].
+///
+/// The range is clamped to the frames available in the buffer, so an empty
+/// range or one which is out of bounds silences nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1, 2, 3, 4, 5]; 2];
+/// buf::silence_range(&mut buffer, 1, 3);
+///
+/// assert_eq!(buffer, audio::interleaved![[1, 0, 0, 4, 5]; 2]);
+/// ```
+pub fn silence_range<B, T>(mut buf: B, start: usize, end: usize)
+where
+    B: ChannelsMut<T>,
+    T: Sample,
+{
+    if start >= end {
+        return;
+    }
+
+    for chan in 0..buf.channels() {
+        for s in buf
+            .channel_mut(chan)
+            .skip(start)
+            .limit(end - start)
+            .iter_mut()
+        {
+            *s = T::ZERO;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Utilities for smoothing control signals.
+
+use audio_core::ChannelsMut;
+
+/// Smooth every channel of the buffer in place with a one-pole lowpass
+/// filter, which computes `y[n] = y[n - 1] + coeff * (x[n] - y[n - 1])`.
+///
+/// This is intended for smoothing parameter automation or envelopes to avoid
+/// zipper noise. The filter starts at the first sample of each channel, and
+/// no state is carried between calls. A `coeff` of `1.0` leaves the buffer
+/// untouched, while smaller values smooth more.
+///
+/// # Panics
+///
+/// Panics if `coeff` isn't in the range `(0, 1]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::dynamic![[0.0f32, 1.0, 1.0, 1.0]];
+/// buf::smooth_one_pole(&mut buffer, 0.5);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.0f32, 0.5, 0.75, 0.875]]);
+/// ```
+pub fn smooth_one_pole<B>(mut buf: B, coeff: f32)
+where
+    B: ChannelsMut<f32>,
+{
+    assert! {
+        coeff > 0.0 && coeff <= 1.0,
+        "smoothing coefficient {} is not in the range (0, 1]",
+        coeff
+    };
+
+    for chan in 0..buf.channels() {
+        let mut iter = buf.channel_mut(chan).iter_mut();
+
+        let mut y = match iter.next() {
+            Some(s) => *s,
+            None => continue,
+        };
+
+        for s in iter {
+            y += coeff * (*s - y);
+            *s = y;
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Utilities for frequency-domain analysis of audio buffers.
+
+use audio_core::Channels;
+use rustfft::num_complex::Complex;
+use rustfft::FftPlanner;
+
+/// Compute the spectrum of the given `channel` using a forward FFT.
+///
+/// The channel is copied into a contiguous scratch buffer and transformed
+/// with [rustfft], returning one unnormalized complex bin for every frame in
+/// the channel. Bin `k` corresponds to the frequency
+/// `k * sample_rate / frames`.
+///
+/// Any number of frames is supported, but the transform is the most efficient
+/// when it's a power of two. Buffers can be padded to one with
+/// [pad_to][crate::buf::pad_to] and
+/// [next_power_of_two_frames][crate::buf::next_power_of_two_frames].
+///
+/// This requires the `fft` feature.
+///
+/// # Panics
+///
+/// Panics if `channel` is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::interleaved![[1.0f32; 8]; 2];
+/// let spectrum = buf::spectrum(&buffer, 0);
+///
+/// // A constant signal only has energy in the DC bin.
+/// assert!((spectrum[0].re - 8.0).abs() < 1e-4);
+/// assert!(spectrum[1..].iter().all(|bin| bin.norm() < 1e-4));
+/// ```
+pub fn spectrum<B>(buf: B, channel: usize) -> Vec<Complex<f32>>
+where
+    B: Channels<f32>,
+{
+    assert! {
+        channel < buf.channels(),
+        "channel {} is out of bounds 0-{}",
+        channel,
+        buf.channels()
+    };
+
+    let mut bins = buf
+        .channel(channel)
+        .iter()
+        .map(|s| Complex::new(s, 0.0))
+        .collect::<Vec<_>>();
+
+    if bins.is_empty() {
+        return bins;
+    }
+
+    let fft = FftPlanner::new().plan_fft_forward(bins.len());
+    fft.process(&mut bins);
+    bins
+}
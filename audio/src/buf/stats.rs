@@ -0,0 +1,107 @@
+//! Utilities for collecting statistics about audio buffers.
+
+use audio_core::Channels;
+
+/// Statistics about every channel in a buffer, as collected by [stats].
+///
+/// Every measurement is stored as a slice with one element per channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufStats {
+    peak: Vec<f32>,
+    rms: Vec<f32>,
+    dc_offset: Vec<f32>,
+    non_finite: Vec<usize>,
+}
+
+impl BufStats {
+    /// The largest sample magnitude in each channel.
+    pub fn peak(&self) -> &[f32] {
+        &self.peak
+    }
+
+    /// The root mean square level of each channel.
+    pub fn rms(&self) -> &[f32] {
+        &self.rms
+    }
+
+    /// The DC offset of each channel, which is the mean of its samples.
+    pub fn dc_offset(&self) -> &[f32] {
+        &self.dc_offset
+    }
+
+    /// The number of samples in each channel which are either NaN or
+    /// infinite.
+    pub fn non_finite(&self) -> &[usize] {
+        &self.non_finite
+    }
+}
+
+/// Collect the peak, RMS level, DC offset, and number of non-finite samples of
+/// every channel in the buffer in a single pass.
+///
+/// This is cheaper than measuring each of them separately, since every sample
+/// is only visited once.
+///
+/// Non-finite samples are counted, but otherwise left out of the other
+/// measurements so that a single bad sample doesn't poison them. Channels
+/// which have no finite samples, including empty ones, have all their
+/// measurements set to zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let buffer = audio::sequential![[1.0f32, -1.0, 1.0, -1.0], [0.5, 0.5, f32::NAN, 0.5]];
+/// let stats = buf::stats(&buffer);
+///
+/// assert_eq!(stats.peak(), &[1.0, 0.5]);
+/// assert_eq!(stats.rms(), &[1.0, 0.5]);
+/// assert_eq!(stats.dc_offset(), &[0.0, 0.5]);
+/// assert_eq!(stats.non_finite(), &[0, 1]);
+/// ```
+pub fn stats<B>(buf: B) -> BufStats
+where
+    B: Channels<f32>,
+{
+    let channels = buf.channels();
+
+    let mut stats = BufStats {
+        peak: vec![0.0; channels],
+        rms: vec![0.0; channels],
+        dc_offset: vec![0.0; channels],
+        non_finite: vec![0; channels],
+    };
+
+    for chan in 0..channels {
+        let mut peak = 0.0f32;
+        let mut square = 0.0f32;
+        let mut sum = 0.0f32;
+        let mut count = 0usize;
+        let mut non_finite = 0usize;
+
+        for s in buf.channel(chan).iter() {
+            if !s.is_finite() {
+                non_finite += 1;
+                continue;
+            }
+
+            peak = f32::max(peak, s.abs());
+            square += s * s;
+            sum += s;
+            count += 1;
+        }
+
+        stats.non_finite[chan] = non_finite;
+
+        if count == 0 {
+            continue;
+        }
+
+        stats.peak[chan] = peak;
+        stats.rms[chan] = (square / count as f32).sqrt();
+        stats.dc_offset[chan] = sum / count as f32;
+    }
+
+    stats
+}
@@ -0,0 +1,82 @@
+//! Utilities for working with stereo buffers.
+
+use crate::dynamic::Dynamic;
+use audio_core::{Channels, ChannelsMut, ExactSizeBuf, Sample};
+
+/// Combine the mono buffers `left` and `right` into a freshly allocated
+/// stereo buffer, where channel 0 is left and channel 1 is right.
+///
+/// If the inputs are of different lengths, the shorter one is padded with
+/// [Sample::ZERO] to the length of the longer one.
+///
+/// # Panics
+///
+/// Panics unless both `left` and `right` have exactly one channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let left = audio::sequential![[1, 2, 3]];
+/// let right = audio::interleaved![[4, 5]];
+///
+/// let stereo = buf::stereo_from_mono(&left, &right);
+/// assert_eq!(stereo, audio::dynamic![[1, 2, 3], [4, 5, 0]]);
+/// ```
+pub fn stereo_from_mono<L, R, T>(left: L, right: R) -> Dynamic<T>
+where
+    L: Channels<T> + ExactSizeBuf,
+    R: Channels<T> + ExactSizeBuf,
+    T: Sample,
+{
+    assert! {
+        left.channels() == 1 && right.channels() == 1,
+        "expected mono inputs, but got {} and {} channels",
+        left.channels(),
+        right.channels()
+    };
+
+    let frames = usize::max(left.frames(), right.frames());
+    let mut out = Dynamic::with_topology(2, frames);
+
+    out.channel_mut(0).copy_from(left.channel(0));
+    out.channel_mut(1).copy_from(right.channel(0));
+    out
+}
+
+/// Split the stereo buffer `buf` into freshly allocated `(left, right)` mono
+/// buffers, where left is channel 0 and right is channel 1.
+///
+/// This is the inverse of [stereo_from_mono].
+///
+/// # Panics
+///
+/// Panics unless `buf` has exactly two channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let stereo = audio::interleaved![[1, 2, 3], [4, 5, 6]];
+///
+/// let (left, right) = buf::split_stereo(&stereo);
+/// assert_eq!(left, audio::dynamic![[1, 2, 3]]);
+/// assert_eq!(right, audio::dynamic![[4, 5, 6]]);
+/// ```
+pub fn split_stereo<B, T>(buf: B) -> (Dynamic<T>, Dynamic<T>)
+where
+    B: Channels<T>,
+    T: Sample,
+{
+    assert! {
+        buf.channels() == 2,
+        "expected a stereo input, but got {} channels",
+        buf.channels()
+    };
+
+    let left = super::channel_to_owned(&buf, 0);
+    let right = super::channel_to_owned(&buf, 1);
+    (left, right)
+}
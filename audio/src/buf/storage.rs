@@ -0,0 +1,47 @@
+//! Utilities for inspecting the storage backing audio buffers.
+
+use audio_core::Channels;
+
+/// Test if any channel of `a` is backed by memory which overlaps with the
+/// memory backing any channel of `b`.
+///
+/// This can be used to detect and refuse operations which read from one
+/// buffer while writing to another, where the two might be views into the same
+/// storage, like when constructed through raw pointers or [wrap][crate::wrap]
+/// adapters. Such operations produce corrupted output which is hard to debug.
+///
+/// Note that this is a best-effort heuristic based on the ranges of memory
+/// reported by [Channel::as_ptr_range][audio_core::Channel::as_ptr_range], and
+/// not a soundness guarantee. Interleaved channels are considered to cover the
+/// samples of every channel interleaved with them.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::{buf, Buf as _};
+///
+/// let a = audio::sequential![[0.0f32; 8]; 2];
+/// let b = audio::sequential![[0.0f32; 8]; 2];
+///
+/// assert!(buf::shares_storage(&a, (&a).skip(4)));
+/// assert!(!buf::shares_storage(&a, &b));
+/// ```
+pub fn shares_storage<A, B, T>(a: A, b: B) -> bool
+where
+    A: Channels<T>,
+    B: Channels<T>,
+{
+    for i in 0..a.channels() {
+        let a = a.channel(i).as_ptr_range();
+
+        for j in 0..b.channels() {
+            let b = b.channel(j).as_ptr_range();
+
+            if a.start < b.end && b.start < a.end {
+                return true;
+            }
+        }
+    }
+
+    false
+}
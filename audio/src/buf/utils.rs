@@ -1,22 +1,239 @@
 //! Utilities for manipulating audio buffers.
 
+use crate::dynamic::Dynamic;
 use audio_core::Translate;
-use audio_core::{Channels, ChannelsMut};
+use audio_core::{Channels, ChannelsMut, ExactSizeBuf, Sample};
+
+/// The number of frames copied at a time when exactly one of the buffers
+/// passed to [copy] is interleaved.
+const COPY_BLOCK: usize = 64;
 
 /// Copy from the buffer specified by `from` into the buffer specified by `to`.
 ///
-/// Only the common count of channels will be copied.
+/// Only the common count of channels and frames will be copied. The traversal
+/// is picked based on the layout of both buffers:
+/// * If both are interleaved, samples are copied one frame at a time so that
+///   both buffers are accessed in memory order.
+/// * If exactly one is interleaved, channels are copied in blocks of frames so
+///   that the strided side of the copy stays in cache.
+/// * Otherwise each channel is copied in full, as a slice if it's linear in
+///   both buffers.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let from = audio::sequential![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+/// let mut to = audio::interleaved![[0; 2]; 2];
+///
+/// buf::copy(&from, &mut to);
+/// assert_eq!(to.as_slice(), &[1, 5, 2, 6]);
+/// ```
 pub fn copy<I, O, T>(from: I, mut to: O)
 where
     I: Channels<T>,
     O: ChannelsMut<T>,
     T: Copy,
 {
+    audio_core::checks::frames_hint(&from);
+    audio_core::checks::frames_hint(&to);
+
     let end = usize::min(from.channels(), to.channels());
 
-    for chan in 0..end {
-        to.channel_mut(chan).copy_from(from.channel(chan));
+    if end == 0 {
+        return;
+    }
+
+    let to_channels = interleaved(&to, end).map(|(_, channels)| channels);
+
+    match (interleaved(&from, end), to_channels) {
+        (Some((from, from_channels)), Some(to_channels)) => {
+            let to = match to.channel_mut(0).into_interleaved() {
+                Some((to, ..)) => to,
+                None => return,
+            };
+
+            if from_channels == end && to_channels == end {
+                let len = usize::min(from.len(), to.len());
+                to[..len].copy_from_slice(&from[..len]);
+                return;
+            }
+
+            let frames = from.chunks_exact(from_channels);
+
+            for (o, f) in to.chunks_exact_mut(to_channels).zip(frames) {
+                o[..end].copy_from_slice(&f[..end]);
+            }
+        }
+        (Some(..), None) | (None, Some(..)) => {
+            let frames = (0..end)
+                .map(|chan| from.channel(chan).frames())
+                .max()
+                .unwrap_or_default();
+
+            for start in (0..frames).step_by(COPY_BLOCK) {
+                for chan in 0..end {
+                    let from = from.channel(chan).skip(start).limit(COPY_BLOCK);
+                    let mut to = to.channel_mut(chan).skip(start).limit(COPY_BLOCK);
+                    to.copy_from(from);
+                }
+            }
+        }
+        (None, None) => {
+            for chan in 0..end {
+                to.channel_mut(chan).copy_from(from.channel(chan));
+            }
+        }
+    }
+}
+
+/// Access the interleaved buffer backing the first `end` channels of `buf` and
+/// the number of channels interleaved in it.
+///
+/// This is only available if all of the channels are interleaved in order in
+/// the same underlying buffer.
+fn interleaved<B, T>(buf: &B, end: usize) -> Option<(&[T], usize)>
+where
+    B: ?Sized + Channels<T>,
+{
+    let (samples, channels) = match buf.channel(0).as_interleaved() {
+        Some((samples, channels, 0)) => (samples, channels),
+        _ => return None,
+    };
+
+    for chan in 1..end {
+        match buf.channel(chan).as_interleaved() {
+            Some((other, other_channels, channel))
+                if other.as_ptr_range() == samples.as_ptr_range()
+                    && other_channels == channels
+                    && channel == chan => {}
+            _ => return None,
+        }
     }
+
+    Some((samples, channels))
+}
+
+/// Materialize the buffer `buf` into a freshly allocated [Dynamic] buffer
+/// with the same topology.
+///
+/// This is the terminal operation for a chain of buffer adapters such as
+/// [Buf::skip][audio_core::Buf::skip] and
+/// [Buf::limit][audio_core::Buf::limit], producing an owned buffer which can
+/// be stored or handed off independently of the buffer it was read from.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::{buf, Buf as _};
+///
+/// let from = audio::interleaved![[1, 2, 3, 4, 5, 6, 7, 8]; 2];
+/// let to = buf::to_owned((&from).skip(2).limit(4));
+///
+/// assert_eq!(to, audio::dynamic![[3, 4, 5, 6]; 2]);
+/// ```
+pub fn to_owned<B, T>(buf: B) -> Dynamic<T>
+where
+    B: Channels<T> + ExactSizeBuf,
+    T: Sample,
+{
+    audio_core::checks::exact_size(&buf);
+
+    let mut out = Dynamic::with_topology(buf.channels(), buf.frames());
+    copy(buf, &mut out);
+    out
+}
+
+/// Copy a single `channel` of the buffer `buf` into a freshly allocated mono
+/// [Dynamic] buffer.
+///
+/// This is the counterpart to [stereo_from_mono][super::stereo_from_mono],
+/// and works regardless of the layout of `buf`.
+///
+/// # Panics
+///
+/// Panics if `channel` is out of bounds.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let from = audio::interleaved![[1, 2, 3], [4, 5, 6]];
+/// let to = buf::channel_to_owned(&from, 1);
+///
+/// assert_eq!(to, audio::dynamic![[4, 5, 6]]);
+/// ```
+pub fn channel_to_owned<B, T>(buf: B, channel: usize) -> Dynamic<T>
+where
+    B: Channels<T>,
+    T: Sample,
+{
+    assert! {
+        channel < buf.channels(),
+        "channel {} is out of bounds 0-{}",
+        channel,
+        buf.channels()
+    };
+
+    let from = buf.channel(channel);
+    let mut out = Dynamic::with_topology(1, from.frames());
+    out.channel_mut(0).copy_from(from);
+    out
+}
+
+/// Copy the buffer `buf` into a freshly allocated [Dynamic] buffer with
+/// exactly `frames` frames.
+///
+/// If `buf` is shorter than `frames`, the frames after it are padded with
+/// [Sample::ZERO]. If it is longer, the frames past `frames` are truncated.
+///
+/// This is commonly combined with [next_power_of_two_frames] to prepare a
+/// buffer for an FFT.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let from = audio::interleaved![[1, 2, 3]; 2];
+///
+/// let to = buf::pad_to(&from, buf::next_power_of_two_frames(&from));
+/// assert_eq!(to, audio::dynamic![[1, 2, 3, 0]; 2]);
+///
+/// let to = buf::pad_to(&from, 2);
+/// assert_eq!(to, audio::dynamic![[1, 2]; 2]);
+/// ```
+pub fn pad_to<B, T>(buf: B, frames: usize) -> Dynamic<T>
+where
+    B: Channels<T>,
+    T: Sample,
+{
+    let mut out = Dynamic::with_topology(buf.channels(), frames);
+    copy(buf, &mut out);
+    out
+}
+
+/// Get the smallest power of two which is greater than or equal to the
+/// number of frames in `buf`.
+///
+/// An empty buffer results in `1`, which is the smallest power of two.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// assert_eq!(buf::next_power_of_two_frames(audio::interleaved![[0; 100]; 2]), 128);
+/// assert_eq!(buf::next_power_of_two_frames(audio::interleaved![[0; 128]; 2]), 128);
+/// assert_eq!(buf::next_power_of_two_frames(audio::interleaved![[0; 0]; 2]), 1);
+/// ```
+pub fn next_power_of_two_frames<B>(buf: B) -> usize
+where
+    B: ExactSizeBuf,
+{
+    buf.frames().next_power_of_two()
 }
 
 /// Translate the content of one buffer `from` into the buffer specified by `to`.
@@ -29,9 +246,86 @@ where
     T: Translate<U>,
     U: Copy,
 {
+    audio_core::checks::frames_hint(&from);
+    audio_core::checks::frames_hint(&to);
+
     let end = usize::min(from.channels(), to.channels());
 
     for chan in 0..end {
         to.channel_mut(chan).translate_from(from.channel(chan));
     }
 }
+
+/// Apply `f` to every sample in `buf`, collecting the result into a new
+/// [Dynamic] buffer with the same topology.
+///
+/// The produced buffer may have a different sample type than the one being
+/// mapped, which makes this a generalization of [translate] useful for
+/// arbitrary transforms like lookup-table waveshapers.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let from = audio::interleaved![[1.0f32, -2.0, 3.0]; 2];
+/// let to = buf::map_samples(&from, |s| s * s);
+///
+/// assert_eq!(to, audio::dynamic![[1.0f32, 4.0, 9.0]; 2]);
+/// ```
+///
+/// Mapping into a different sample type:
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let from = audio::sequential![[0.0f32, 0.5, 1.0]; 2];
+/// let to = buf::map_samples(&from, |s| (s * 100.0) as i16);
+///
+/// assert_eq!(to, audio::dynamic![[0i16, 50, 100]; 2]);
+/// ```
+pub fn map_samples<B, T, U, F>(buf: B, f: F) -> Dynamic<U>
+where
+    B: Channels<T> + ExactSizeBuf,
+    T: Copy,
+    U: Sample,
+    F: Fn(T) -> U,
+{
+    let mut out = Dynamic::with_topology(buf.channels(), buf.frames());
+
+    for chan in 0..buf.channels() {
+        for (o, s) in out[chan].iter_mut().zip(buf.channel(chan)) {
+            *o = f(s);
+        }
+    }
+
+    out
+}
+
+/// Apply `f` to every sample in `buf` in place.
+///
+/// This is the allocation-free complement to [map_samples], suitable for
+/// applying a pointwise nonlinearity in a hot loop.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// let mut buffer = audio::interleaved![[1.0f32, -2.0, 3.0]; 2];
+/// buf::map_in_place(&mut buffer, |s| s * s);
+///
+/// assert_eq!(buffer.as_slice(), &[1.0, 1.0, 4.0, 4.0, 9.0, 9.0]);
+/// ```
+pub fn map_in_place<B, T, F>(mut buf: B, f: F)
+where
+    B: ChannelsMut<T>,
+    T: Copy,
+    F: Fn(T) -> T,
+{
+    for chan in 0..buf.channels() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            *s = f(*s);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Utilities for shaping audio buffers through a transfer function.
+
+use audio_core::ChannelsMut;
+
+/// Map every sample in the buffer through the transfer function described by
+/// the lookup `table`.
+///
+/// The table samples the transfer function uniformly over the input domain
+/// `[-1, 1]`, so the first entry is the output for `-1.0` and the last entry
+/// is the output for `1.0`. Inputs which fall between two entries are
+/// linearly interpolated, and inputs outside of the domain are clamped to it.
+///
+/// # Panics
+///
+/// Panics if `table` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::buf;
+///
+/// // Hard clip anything above a magnitude of 0.5.
+/// let table = [-0.5, -0.5, 0.0, 0.5, 0.5];
+///
+/// let mut buffer = audio::interleaved![[-1.0f32, -0.25, 0.25, 0.75]; 2];
+/// buf::waveshape(&mut buffer, &table);
+///
+/// assert_eq!(buffer.as_slice(), &[-0.5, -0.5, -0.25, -0.25, 0.25, 0.25, 0.5, 0.5]);
+/// ```
+pub fn waveshape<B>(mut buf: B, table: &[f32])
+where
+    B: ChannelsMut<f32>,
+{
+    assert!(!table.is_empty(), "waveshaper table must not be empty");
+
+    let last = table.len() - 1;
+
+    for chan in 0..buf.channels() {
+        for s in buf.channel_mut(chan).iter_mut() {
+            let x = s.clamp(-1.0, 1.0);
+            let pos = (x + 1.0) * 0.5 * last as f32;
+            let index = usize::min(pos as usize, last);
+            let next = usize::min(index + 1, last);
+            let fract = pos - index as f32;
+            *s = table[index] + (table[next] - table[index]) * fract;
+        }
+    }
+}
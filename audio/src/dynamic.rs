@@ -1,8 +1,11 @@
 //! A dynamically sized, multi-channel audio buffer.
 
+use crate::interleaved::Interleaved;
+use crate::sequential::Sequential;
 use audio_core::{
     Buf, Channel, ChannelMut, Channels, ChannelsMut, ExactSizeBuf, ResizableBuf, Sample,
 };
+use std::alloc;
 use std::cmp;
 use std::fmt;
 use std::hash;
@@ -40,6 +43,10 @@ pub struct Dynamic<T> {
     /// Allocated capacity of each channel. Each channel is guaranteed to be
     /// filled with as many values as is specified in this capacity.
     frames_cap: usize,
+    /// The alignment of the allocation of each channel. If this is larger than
+    /// the alignment of `T` the channels are allocated directly through
+    /// [std::alloc] instead of through [Vec].
+    align: usize,
 }
 
 impl<T> Dynamic<T> {
@@ -59,6 +66,7 @@ impl<T> Dynamic<T> {
             channels_cap: 0,
             frames: 0,
             frames_cap: 0,
+            align: mem::align_of::<T>(),
         }
     }
 
@@ -95,6 +103,87 @@ impl<T> Dynamic<T> {
             channels_cap: channels,
             frames,
             frames_cap: frames,
+            align: mem::align_of::<T>(),
+        }
+    }
+
+    /// Allocate an audio buffer with the given number of `channels`, no frames,
+    /// and room for at least `frames` frames in each channel.
+    ///
+    /// This is useful when a buffer is grown incrementally, since resizing it
+    /// within its [capacity][Dynamic::capacity] won't reallocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Dynamic::<f32>::with_capacity(2, 256);
+    ///
+    /// assert_eq!(buffer.channels(), 2);
+    /// assert_eq!(buffer.frames(), 0);
+    /// assert_eq!(buffer.capacity(), 256);
+    /// ```
+    pub fn with_capacity(channels: usize, frames: usize) -> Self
+    where
+        T: Sample,
+    {
+        let mut buffer = Self::with_topology(channels, frames);
+        buffer.frames = 0;
+        buffer
+    }
+
+    /// Allocate an audio buffer with the given topology, where the storage of
+    /// every channel is aligned to at least `align` bytes.
+    ///
+    /// This is useful for SIMD kernels which want to use aligned loads, like
+    /// AVX which wants 32-byte alignment. The alignment is retained when the
+    /// buffer is resized, and is reported through [Channels::alignment].
+    ///
+    /// Note that [Dynamic::into_vectors] has to copy the channels of an
+    /// over-aligned buffer, since a [Vec] can't take ownership of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Channels;
+    ///
+    /// let mut buffer = audio::Dynamic::<f32>::with_alignment(2, 256, 32);
+    ///
+    /// assert_eq!(buffer.frames(), 256);
+    /// assert_eq!(buffer.channels(), 2);
+    /// assert_eq!(buffer.alignment(), 32);
+    /// assert_eq!(buffer[1].as_ptr() as usize % 32, 0);
+    /// ```
+    pub fn with_alignment(channels: usize, frames: usize, align: usize) -> Self
+    where
+        T: Sample,
+    {
+        assert! {
+            align.is_power_of_two(),
+            "alignment {} is not a power of two",
+            align
+        };
+
+        let align = usize::max(align, mem::align_of::<T>());
+        let mut data = RawSlice::uninit(channels);
+
+        for n in 0..channels {
+            // Safety: We just allocated the vector w/ a capacity matching channels.
+            unsafe {
+                data.write(n, RawSlice::zeroed_aligned(frames, align));
+            }
+        }
+
+        Self {
+            data,
+            channels,
+            channels_cap: channels,
+            frames,
+            frames_cap: frames,
+            align,
         }
     }
 
@@ -126,6 +215,7 @@ impl<T> Dynamic<T> {
             channels_cap: C,
             frames: F,
             frames_cap: F,
+            align: mem::align_of::<T>(),
         };
 
         #[inline]
@@ -172,6 +262,7 @@ impl<T> Dynamic<T> {
             channels_cap: channels,
             frames: N,
             frames_cap: N,
+            align: mem::align_of::<T>(),
         };
 
         fn data_from_frames<T, const N: usize>(
@@ -196,6 +287,48 @@ impl<T> Dynamic<T> {
         }
     }
 
+    /// Allocate a dynamic audio buffer by deinterleaving the flat slice `data`
+    /// with the given number of `channels`.
+    ///
+    /// This is the typical way of taking the interleaved output of a sound
+    /// device and making it planar.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is zero or if the length of `data` isn't a
+    /// multiple of `channels`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::Dynamic::from_interleaved(&[1, 5, 2, 6, 3, 7, 4, 8], 2);
+    ///
+    /// assert_eq!(buffer.frames(), 4);
+    /// assert_eq!(buffer.channels(), 2);
+    /// assert_eq!(buffer, audio::dynamic![[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// ```
+    pub fn from_interleaved(data: &[T], channels: usize) -> Self
+    where
+        T: Sample,
+    {
+        assert! {
+            channels != 0 && data.len() % channels == 0,
+            "interleaved data of length {} can't be divided into {} channels",
+            data.len(),
+            channels
+        };
+
+        let mut buf = Self::with_topology(channels, data.len() / channels);
+
+        for (n, frame) in data.chunks_exact(channels).enumerate() {
+            for (chan, s) in frame.iter().enumerate() {
+                buf[chan][n] = *s;
+            }
+        }
+
+        buf
+    }
+
     /// Get the number of frames in the channels of an audio buffer.
     ///
     /// # Examples
@@ -268,9 +401,9 @@ impl<T> Dynamic<T> {
     /// Set the size of the buffer. The size is the size of each channel's
     /// buffer.
     ///
-    /// If the size of the buffer increases as a result, the new regions in the
-    /// frames will be zeroed. If the size decreases, the region will be left
-    /// untouched. So if followed by another increase, the data will be "dirty".
+    /// If the size of the buffer increases as a result, the new frames will be
+    /// zeroed, even if they were previously sized out. Use
+    /// [Dynamic::resize_with] to fill them with another value.
     ///
     /// # Examples
     ///
@@ -290,8 +423,9 @@ impl<T> Dynamic<T> {
     /// assert_eq!(buffer.frames(), 256);
     /// ```
     ///
-    /// Decreasing and increasing the size will not touch a buffer that has
-    /// already been allocated.
+    /// Decreasing and increasing the size will not reallocate a buffer that
+    /// has already been allocated, but the frames which are sized back in will
+    /// be zeroed.
     ///
     /// ```rust
     /// # let mut buffer = audio::Dynamic::<f32>::with_topology(4, 256);
@@ -302,9 +436,150 @@ impl<T> Dynamic<T> {
     /// assert!(buffer[1].get(128).is_none());
     ///
     /// buffer.resize(256);
-    /// assert_eq!(buffer[1][128], 42.0);
+    /// assert_eq!(buffer[1][128], 0.0);
     /// ```
     pub fn resize(&mut self, frames: usize)
+    where
+        T: Sample,
+    {
+        self.resize_with(frames, T::ZERO);
+    }
+
+    /// Set the size of the buffer, filling any new frames in every channel with
+    /// `value`.
+    ///
+    /// This is the same as [Dynamic::resize], except that the frames exposed when
+    /// the buffer grows are set to `value` instead of being zeroed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Dynamic::<f32>::with_topology(2, 2);
+    ///
+    /// buffer.resize_with(4, 1.0);
+    /// assert_eq!(buffer[0][1], 0.0);
+    /// assert_eq!(buffer[0][2], 1.0);
+    /// assert_eq!(buffer[1][3], 1.0);
+    ///
+    /// buffer.resize(1);
+    /// buffer.resize_with(3, 0.5);
+    /// assert_eq!(buffer[0][1], 0.5);
+    /// assert_eq!(buffer[1][2], 0.5);
+    /// ```
+    pub fn resize_with(&mut self, frames: usize, value: T)
+    where
+        T: Sample,
+    {
+        let from = self.frames;
+        self.reserve_frames(frames);
+        self.frames = frames;
+
+        if from < frames {
+            for chan in 0..self.channels {
+                for s in &mut self[chan][from..] {
+                    *s = value;
+                }
+            }
+        }
+
+        audio_core::checks::exact_size(&*self);
+    }
+
+    /// Reserve capacity for at least `additional` more frames in every
+    /// channel.
+    ///
+    /// Like [Vec::reserve], this might reserve more space than requested to
+    /// avoid frequent reallocations. After this, growing the buffer with
+    /// [Dynamic::resize] up to [Dynamic::capacity] frames will not reallocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Dynamic::<f32>::with_topology(2, 16);
+    ///
+    /// buffer.reserve(112);
+    /// assert!(buffer.capacity() >= 128);
+    /// assert_eq!(buffer.frames(), 16);
+    /// ```
+    pub fn reserve(&mut self, additional: usize)
+    where
+        T: Sample,
+    {
+        let frames = self
+            .frames
+            .checked_add(additional)
+            .expect("capacity overflow");
+        self.reserve_frames(frames);
+    }
+
+    /// Append a single frame to the end of the buffer, with one sample for
+    /// each channel.
+    ///
+    /// When the buffer is out of [capacity][Dynamic::capacity] it is grown
+    /// the same way as [Dynamic::resize], which at least doubles the capacity
+    /// so that pushing frames one at a time is amortized. Use
+    /// [Dynamic::reserve] up front if the number of frames is known.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `samples` doesn't match the number of channels
+    /// in the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Dynamic::<i16>::with_capacity(2, 16);
+    ///
+    /// buffer.push_frame(&[1, 2]);
+    /// buffer.push_frame(&[3, 4]);
+    ///
+    /// assert_eq!(buffer, audio::dynamic![[1, 3], [2, 4]]);
+    /// ```
+    pub fn push_frame(&mut self, samples: &[T])
+    where
+        T: Sample,
+    {
+        assert! {
+            samples.len() == self.channels,
+            "frame has {} samples but the buffer has {} channels",
+            samples.len(),
+            self.channels
+        };
+
+        let frame = self.frames;
+        self.resize(frame + 1);
+
+        for (chan, s) in samples.iter().enumerate() {
+            // Safety: we just resized the buffer to include the new frame and
+            // checked that there is one sample per channel.
+            unsafe {
+                *self.data.get_unchecked_mut(chan).get_unchecked_mut(frame) = *s;
+            }
+        }
+    }
+
+    /// Get the number of frames the buffer can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Dynamic::<f32>::with_capacity(2, 256);
+    ///
+    /// assert_eq!(buffer.capacity(), 256);
+    /// buffer.resize(64);
+    /// assert_eq!(buffer.capacity(), 256);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.frames_cap
+    }
+
+    /// Get the number of channels the buffer can hold without reallocating.
+    pub(crate) fn channels_capacity(&self) -> usize {
+        self.channels_cap
+    }
+
+    /// Make sure that the frame capacity can hold at least `frames` frames.
+    fn reserve_frames(&mut self, frames: usize)
     where
         T: Sample,
     {
@@ -321,15 +596,13 @@ impl<T> Dynamic<T> {
                     unsafe {
                         self.data
                             .get_unchecked_mut(n)
-                            .reserve_zeroed(from, additional)
+                            .reserve_zeroed_aligned(from, additional, self.align)
                     };
                 }
             }
 
             self.frames_cap = to;
         }
-
-        self.frames = frames;
     }
 
     /// Set the number of channels in use.
@@ -372,7 +645,7 @@ impl<T> Dynamic<T> {
             }
 
             for n in old_cap..new_cap {
-                let slice = RawSlice::zeroed(self.frames_cap);
+                let slice = RawSlice::zeroed_aligned(self.frames_cap, self.align);
 
                 // Safety: we control the capacity of channels and have just
                 // guranteed above that it is appropriate.
@@ -386,6 +659,8 @@ impl<T> Dynamic<T> {
 
         debug_assert!(channels <= self.channels_cap);
         self.channels = channels;
+
+        audio_core::checks::exact_size(&*self);
     }
 
     /// Get a reference to the buffer of the given channel.
@@ -562,6 +837,7 @@ impl<T> Dynamic<T> {
         let mut vecs = Vec::with_capacity(this.channels);
 
         let frames_cap = this.frames_cap;
+        let align = this.align;
 
         for n in 0..this.channels {
             // Safety: The capacity end lengths are trusted since they're part
@@ -570,9 +846,9 @@ impl<T> Dynamic<T> {
                 let mut slice = this.data.read(n);
 
                 if condition(n) {
-                    vecs.push(slice.into_vec(this.frames, frames_cap));
+                    vecs.push(slice.into_vec_aligned(this.frames, frames_cap, align));
                 } else {
-                    slice.drop_in_place(frames_cap);
+                    slice.drop_aligned(frames_cap, align);
                     vecs.push(Vec::new());
                 }
             }
@@ -583,7 +859,9 @@ impl<T> Dynamic<T> {
             // Safety: The capacity end lengths are trusted since they're part
             // of the audio buffer.
             unsafe {
-                this.data.get_unchecked_mut(n).drop_in_place(frames_cap);
+                this.data
+                    .get_unchecked_mut(n)
+                    .drop_aligned(frames_cap, align);
             }
         }
 
@@ -597,6 +875,141 @@ impl<T> Dynamic<T> {
 
         vecs
     }
+
+    /// Convert into an [Interleaved] buffer with the same topology and
+    /// samples.
+    ///
+    /// Since every channel of a [Dynamic] buffer is stored in its own
+    /// allocation, this needs to allocate a new buffer and transpose the
+    /// samples into it. The only exception is a buffer with a single channel,
+    /// which already has an interleaved layout so its allocation is reused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::dynamic![[1, 2, 3], [4, 5, 6]];
+    /// let buffer = buffer.into_interleaved();
+    ///
+    /// assert_eq!(buffer.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn into_interleaved(self) -> Interleaved<T>
+    where
+        T: Copy,
+    {
+        let channels = self.channels;
+        let frames = self.frames;
+
+        if channels == 1 {
+            let data = self.into_vectors().pop().unwrap_or_default();
+            return Interleaved::from_vec(data, channels, frames);
+        }
+
+        let mut data = Vec::with_capacity(channels * frames);
+
+        for frame in 0..frames {
+            for chan in 0..channels {
+                data.push(self[chan][frame]);
+            }
+        }
+
+        Interleaved::from_vec(data, channels, frames)
+    }
+
+    /// Convert into a [Sequential] buffer with the same topology and samples.
+    ///
+    /// Since every channel of a [Dynamic] buffer is stored in its own
+    /// allocation, this needs to allocate a new buffer and copy the channels
+    /// into it one after another. The only exception is a buffer with a single
+    /// channel, which already has a sequential layout so its allocation is
+    /// reused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::dynamic![[1, 2, 3], [4, 5, 6]];
+    /// let buffer = buffer.into_sequential();
+    ///
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn into_sequential(self) -> Sequential<T>
+    where
+        T: Copy,
+    {
+        let channels = self.channels;
+        let frames = self.frames;
+
+        if channels == 1 {
+            let data = self.into_vectors().pop().unwrap_or_default();
+            return Sequential::from_vec(data, channels, frames);
+        }
+
+        let mut data = Vec::with_capacity(channels * frames);
+
+        for channel in self.iter() {
+            data.extend_from_slice(channel);
+        }
+
+        Sequential::from_vec(data, channels, frames)
+    }
+
+    /// Reinterpret the samples of the buffer as a different [Pod] type `U`
+    /// without copying.
+    ///
+    /// This is a raw reinterpretation of the bytes in the buffer, so casting
+    /// `i32` samples to `f32` produces floats with the same bit patterns, not
+    /// the same values. To convert between sample formats see
+    /// [Translate][crate::Translate] instead.
+    ///
+    /// Since every channel keeps its allocation, this requires `U` to have the
+    /// same size and alignment as `T`. If it doesn't, the original buffer is
+    /// returned in [Err].
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// [Pod]: bytemuck::Pod
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::dynamic![[1.0f32, -1.0]; 2];
+    /// let buffer = buffer.cast::<u32>().unwrap();
+    ///
+    /// assert_eq!(&buffer[0], &[0x3f800000, 0xbf800000]);
+    ///
+    /// let buffer = audio::dynamic![[1.0f32, -1.0]; 2];
+    /// assert!(buffer.cast::<u16>().is_err());
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    pub fn cast<U>(self) -> Result<Dynamic<U>, Self>
+    where
+        T: bytemuck::Pod,
+        U: bytemuck::Pod,
+    {
+        if mem::size_of::<T>() != mem::size_of::<U>()
+            || mem::align_of::<T>() != mem::align_of::<U>()
+        {
+            return Err(self);
+        }
+
+        let this = mem::ManuallyDrop::new(self);
+
+        // Safety: `RawSlice` is a transparent pointer, so the array of channels
+        // has the same layout regardless of its sample type. Every channel
+        // allocation has the same size and alignment under `U` as under `T`,
+        // and both types are valid for any initialized bit pattern.
+        let data = RawSlice {
+            data: this.data.data.cast::<RawSlice<U>>(),
+        };
+
+        Ok(Dynamic {
+            data,
+            channels: this.channels,
+            channels_cap: this.channels_cap,
+            frames: this.frames,
+            frames_cap: this.frames_cap,
+            align: this.align,
+        })
+    }
 }
 
 impl<T> Default for Dynamic<T> {
@@ -663,11 +1076,19 @@ where
 
 impl<T> hash::Hash for Dynamic<T>
 where
-    T: hash::Hash,
+    T: hash::Hash + cmp::Eq,
 {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // NB: samples are hashed one at a time, and not as slices, so that
+        // buffers with the same logical content hash identically regardless
+        // of their layout.
+        self.channels.hash(state);
+        self.frames.hash(state);
+
         for channel in self.iter() {
-            channel.hash(state);
+            for sample in channel {
+                sample.hash(state);
+            }
         }
     }
 }
@@ -718,7 +1139,7 @@ impl<T> Drop for Dynamic<T> {
             unsafe {
                 self.data
                     .get_unchecked_mut(n)
-                    .drop_in_place(self.frames_cap);
+                    .drop_aligned(self.frames_cap, self.align);
             }
         }
 
@@ -749,6 +1170,10 @@ impl<T> Channels<T> for Dynamic<T> {
     fn channel(&self, channel: usize) -> Channel<'_, T> {
         Channel::linear(&self[channel])
     }
+
+    fn alignment(&self) -> usize {
+        self.align
+    }
 }
 
 impl<T> ResizableBuf for Dynamic<T>
@@ -874,6 +1299,69 @@ impl<T> RawSlice<T> {
         self.data = ptr::NonNull::new_unchecked(mem::ManuallyDrop::new(channel).as_mut_ptr());
     }
 
+    /// Construct a new zeroed raw slice with the given capacity, whose
+    /// allocation is aligned to at least `align` bytes.
+    ///
+    /// If `align` is larger than the alignment of `T`, the slice must be
+    /// dropped using [RawSlice::drop_aligned] with the same alignment.
+    fn zeroed_aligned(cap: usize, align: usize) -> Self
+    where
+        T: Sample,
+    {
+        if align <= mem::align_of::<T>() {
+            return Self::zeroed(cap);
+        }
+
+        let layout = Self::layout(cap, align);
+
+        if layout.size() == 0 {
+            // Safety: `align` is a non-zero power of two, so it's a well
+            // aligned dangling pointer just like the one used by `Vec`.
+            return Self {
+                data: unsafe { ptr::NonNull::new_unchecked(align as *mut T) },
+            };
+        }
+
+        // Safety: the layout has a non-zero size, and the type constrain of
+        // `T` guarantees that an all-zeros bit pattern is legal.
+        unsafe {
+            match ptr::NonNull::new(alloc::alloc_zeroed(layout) as *mut T) {
+                Some(data) => Self { data },
+                None => alloc::handle_alloc_error(layout),
+            }
+        }
+    }
+
+    /// The layout of an over-aligned allocation with the given capacity.
+    fn layout(cap: usize, align: usize) -> alloc::Layout {
+        mem::size_of::<T>()
+            .checked_mul(cap)
+            .and_then(|size| alloc::Layout::from_size_align(size, align).ok())
+            .expect("capacity overflow")
+    }
+
+    /// Resize the slice by reserving `additional` more elements in it, while
+    /// retaining an alignment of at least `align` bytes.
+    ///
+    /// # Safety
+    ///
+    /// Same as [RawSlice::reserve_zeroed], and `align` must match the one the
+    /// slice was allocated with.
+    unsafe fn reserve_zeroed_aligned(&mut self, len: usize, additional: usize, align: usize)
+    where
+        T: Sample,
+    {
+        if align <= mem::align_of::<T>() {
+            self.reserve_zeroed(len, additional);
+            return;
+        }
+
+        let new = Self::zeroed_aligned(len + additional, align);
+        ptr::copy_nonoverlapping(self.data.as_ptr(), new.data.as_ptr(), len);
+        self.drop_aligned(len, align);
+        *self = new;
+    }
+
     /// Resize the slice in place by reserving `additional` more elements in it
     /// without initializing them.
     ///
@@ -967,6 +1455,48 @@ impl<T> RawSlice<T> {
         let _ = Vec::from_raw_parts(self.data.as_ptr(), 0, len);
     }
 
+    /// Drop a slice which was allocated with an alignment of at least `align`
+    /// bytes.
+    ///
+    /// # Safety
+    ///
+    /// Same as [RawSlice::drop_in_place], and `align` must match the one the
+    /// slice was allocated with.
+    unsafe fn drop_aligned(&mut self, len: usize, align: usize) {
+        if align <= mem::align_of::<T>() {
+            self.drop_in_place(len);
+            return;
+        }
+
+        let layout = Self::layout(len, align);
+
+        if layout.size() != 0 {
+            alloc::dealloc(self.data.as_ptr() as *mut u8, layout);
+        }
+    }
+
+    /// Convert a slice which was allocated with an alignment of at least
+    /// `align` bytes into a vector.
+    ///
+    /// Over-aligned slices can't be owned by a [Vec], so they are moved into a
+    /// newly allocated one instead.
+    ///
+    /// # Safety
+    ///
+    /// Same as [RawSlice::into_vec], and `align` must match the one the slice
+    /// was allocated with.
+    unsafe fn into_vec_aligned(mut self, len: usize, cap: usize, align: usize) -> Vec<T> {
+        if align <= mem::align_of::<T>() {
+            return self.into_vec(len, cap);
+        }
+
+        let mut vec = Vec::with_capacity(len);
+        ptr::copy_nonoverlapping(self.data.as_ptr(), vec.as_mut_ptr(), len);
+        vec.set_len(len);
+        self.drop_aligned(cap, align);
+        vec
+    }
+
     /// Convert into a vector.
     ///
     /// # Safety
@@ -0,0 +1,13 @@
+//! Effects which process audio buffers in place.
+//!
+//! Like [filters][crate::filter], effects keep separate state for every
+//! channel so that a signal can be processed block by block.
+
+mod compressor;
+pub use self::compressor::Compressor;
+
+mod delay;
+pub use self::delay::Delay;
+
+mod gain;
+pub use self::gain::Gain;
@@ -0,0 +1,172 @@
+use crate::analysis::coefficient;
+use crate::level::Level;
+use crate::processor::Processor;
+use audio_core::ChannelsMut;
+
+/// A feed-forward compressor which reduces the level of a signal above a
+/// threshold.
+///
+/// The compressor is linked, so the level is detected from the loudest
+/// channel in each frame and the same gain reduction is applied to every
+/// channel, which preserves the stereo image. The detected level is smoothed
+/// with the attack and release time constants the same way as
+/// [envelope][crate::analysis::envelope], and the envelope persists across
+/// calls to [Compressor::process].
+///
+/// Levels above the threshold are reduced by the ratio, so with a ratio of
+/// `4.0` a signal which is 8 dB above the threshold comes out 2 dB above it.
+/// The makeup gain is applied after compression.
+///
+/// By default the threshold is 0 dBFS, the ratio is `1.0`, attack is 10
+/// milliseconds, release is 100 milliseconds and there is no makeup gain,
+/// which leaves any signal which doesn't exceed full scale untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::effect::Compressor;
+///
+/// let mut compressor = Compressor::new(44100.0);
+/// compressor.set_threshold_db(-20.0);
+/// compressor.set_ratio(4.0);
+/// compressor.set_attack(0.0);
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 16]; 2];
+/// compressor.process(&mut buffer);
+///
+/// // 0 dBFS is 20 dB above the threshold, which is reduced to 5 dB above it.
+/// let expected = 10.0f32.powf(-15.0 / 20.0);
+/// assert!(buffer[0].iter().all(|s| (s - expected).abs() < 1e-5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compressor {
+    sample_rate: f32,
+    threshold_db: f32,
+    ratio: f32,
+    attack: f32,
+    release: f32,
+    makeup: Level,
+    env: f32,
+    gains: Vec<f32>,
+}
+
+impl Compressor {
+    /// Construct a new compressor for signals at the given `sample_rate`.
+    pub fn new(sample_rate: f32) -> Self {
+        Self {
+            sample_rate,
+            threshold_db: 0.0,
+            ratio: 1.0,
+            attack: 0.01,
+            release: 0.1,
+            makeup: Level::UNITY,
+            env: 0.0,
+            gains: Vec::new(),
+        }
+    }
+
+    /// Get the threshold in dBFS above which the signal is compressed.
+    pub fn threshold_db(&self) -> f32 {
+        self.threshold_db
+    }
+
+    /// Set the threshold in dBFS above which the signal is compressed.
+    pub fn set_threshold_db(&mut self, threshold_db: f32) {
+        self.threshold_db = threshold_db;
+    }
+
+    /// Get the compression ratio.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Set the compression ratio.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is less than `1.0`.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        assert!(ratio >= 1.0, "ratio {} must be at least 1", ratio);
+        self.ratio = ratio;
+    }
+
+    /// Get the attack time constant in seconds.
+    pub fn attack(&self) -> f32 {
+        self.attack
+    }
+
+    /// Set the attack time constant in seconds.
+    pub fn set_attack(&mut self, attack: f32) {
+        self.attack = attack;
+    }
+
+    /// Get the release time constant in seconds.
+    pub fn release(&self) -> f32 {
+        self.release
+    }
+
+    /// Set the release time constant in seconds.
+    pub fn set_release(&mut self, release: f32) {
+        self.release = release;
+    }
+
+    /// Get the makeup gain applied after compression.
+    pub fn makeup(&self) -> Level {
+        self.makeup
+    }
+
+    /// Set the makeup gain applied after compression.
+    pub fn set_makeup(&mut self, makeup: Level) {
+        self.makeup = makeup;
+    }
+
+    /// Reset the envelope of the compressor, as if no audio has been
+    /// processed.
+    pub fn reset(&mut self) {
+        self.env = 0.0;
+    }
+
+    /// Process every channel in `buf` in place.
+    pub fn process<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        let attack = coefficient(self.attack, self.sample_rate);
+        let release = coefficient(self.release, self.sample_rate);
+
+        self.gains.clear();
+
+        for chan in 0..buf.channels() {
+            for (n, s) in buf.channel(chan).iter().enumerate() {
+                match self.gains.get_mut(n) {
+                    Some(peak) => *peak = f32::max(*peak, s.abs()),
+                    None => self.gains.push(s.abs()),
+                }
+            }
+        }
+
+        let makeup_db = self.makeup.as_db();
+
+        for g in &mut self.gains {
+            let c = if *g > self.env { attack } else { release };
+            self.env = *g + c * (self.env - *g);
+
+            let level_db = 20.0 * self.env.log10();
+            let over_db = f32::max(level_db - self.threshold_db, 0.0);
+            let gain_db = over_db / self.ratio - over_db + makeup_db;
+            *g = 10.0f32.powf(gain_db / 20.0);
+        }
+
+        for chan in 0..buf.channels() {
+            for (s, g) in buf.channel_mut(chan).iter_mut().zip(&self.gains) {
+                *s *= g;
+            }
+        }
+    }
+}
+
+impl Processor<f32> for Compressor {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+        Compressor::process(self, buf);
+    }
+}
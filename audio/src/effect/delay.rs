@@ -0,0 +1,99 @@
+use audio_core::ChannelsMut;
+
+/// A feedback delay line.
+///
+/// Each channel has its own ring buffer which is sized to the maximum delay
+/// specified when the effect is constructed. The contents of the delay line
+/// persist across calls to [Delay::process], so the tail of the effect
+/// continues into the following block.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::effect::Delay;
+///
+/// let mut delay = Delay::new(4);
+/// let mut buffer = audio::dynamic![[1.0f32, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]];
+///
+/// delay.process(&mut buffer, 2, 0.5, 1.0);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.0f32, 0.0, 1.0, 0.0, 0.5, 0.0, 0.25]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Delay {
+    max_delay: usize,
+    lines: Vec<Line>,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    data: Vec<f32>,
+    pos: usize,
+}
+
+impl Delay {
+    /// Construct a new delay which can delay a signal by up to `max_delay`
+    /// frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_delay` is zero.
+    pub fn new(max_delay: usize) -> Self {
+        assert!(max_delay > 0, "maximum delay must be non-zero");
+
+        Self {
+            max_delay,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Get the maximum delay in frames supported by this delay.
+    pub fn max_delay(&self) -> usize {
+        self.max_delay
+    }
+
+    /// Clear the delay lines, as if no audio has been processed.
+    pub fn reset(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Process every channel in `buf` in place.
+    ///
+    /// The signal is delayed by `delay_frames`, and `feedback` is the amount
+    /// of the delayed signal which is fed back into the delay line. `mix` is
+    /// the balance between the dry signal at `0.0` and the delayed signal at
+    /// `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delay_frames` is zero or larger than [Delay::max_delay].
+    pub fn process<B>(&mut self, mut buf: B, delay_frames: usize, feedback: f32, mix: f32)
+    where
+        B: ChannelsMut<f32>,
+    {
+        assert! {
+            delay_frames > 0 && delay_frames <= self.max_delay,
+            "delay {} is out of bounds 1-{}",
+            delay_frames,
+            self.max_delay
+        };
+
+        let max_delay = self.max_delay;
+
+        if self.lines.len() < buf.channels() {
+            self.lines.resize_with(buf.channels(), || Line {
+                data: vec![0.0; max_delay],
+                pos: 0,
+            });
+        }
+
+        for (chan, line) in self.lines.iter_mut().take(buf.channels()).enumerate() {
+            for s in buf.channel_mut(chan).iter_mut() {
+                let delayed = line.data[(line.pos + max_delay - delay_frames) % max_delay];
+                line.data[line.pos] = *s + delayed * feedback;
+                line.pos = (line.pos + 1) % max_delay;
+                *s = *s * (1.0 - mix) + delayed * mix;
+            }
+        }
+    }
+}
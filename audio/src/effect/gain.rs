@@ -0,0 +1,73 @@
+use crate::level::Level;
+use crate::processor::Processor;
+use audio_core::ChannelsMut;
+
+/// A gain stage which scales every sample by a constant factor.
+///
+/// The gain is specified as a [Level], which makes it explicit whether it's in
+/// decibels or a linear factor.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::effect::Gain;
+/// use audio::Level;
+///
+/// let mut gain = Gain::new(Level::from_linear(0.5));
+/// let mut buffer = audio::dynamic![[1.0f32, -0.5, 0.25]; 2];
+///
+/// gain.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.5f32, -0.25, 0.125]; 2]);
+///
+/// gain.set_gain(Level::from_db(-20.0));
+/// gain.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.05f32, -0.025, 0.0125]; 2]);
+/// ```
+///
+/// Plain numbers are not accepted, since it's ambiguous whether they're in
+/// decibels or a linear factor:
+///
+/// ```rust,compile_fail
+/// let gain = audio::effect::Gain::new(-6.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Gain {
+    gain: Level,
+}
+
+impl Gain {
+    /// Construct a new gain stage with the given `gain`.
+    pub fn new(gain: Level) -> Self {
+        Self { gain }
+    }
+
+    /// Get the gain applied by this stage.
+    pub fn gain(&self) -> Level {
+        self.gain
+    }
+
+    /// Set the gain applied by this stage.
+    pub fn set_gain(&mut self, gain: Level) {
+        self.gain = gain;
+    }
+
+    /// Process every channel in `buf` in place.
+    pub fn process<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        for chan in 0..buf.channels() {
+            for s in buf.channel_mut(chan).iter_mut() {
+                *s *= self.gain;
+            }
+        }
+    }
+}
+
+impl Processor<f32> for Gain {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+        Gain::process(self, buf);
+    }
+}
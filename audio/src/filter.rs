@@ -0,0 +1,13 @@
+//! Filters which process audio buffers in place.
+//!
+//! Filters keep separate state for every channel they've processed, so that
+//! a signal can be processed block by block without discontinuities.
+
+mod bank;
+pub use self::bank::FilterBank;
+
+mod biquad;
+pub use self::biquad::Biquad;
+
+mod fir;
+pub use self::fir::Fir;
@@ -0,0 +1,84 @@
+use crate::filter::Biquad;
+use crate::processor::Processor;
+use audio_core::ChannelsMut;
+
+/// A bank of biquad filters with one filter per channel, which allows every
+/// channel to be filtered with different coefficients.
+///
+/// This is useful for per-channel EQ, like applying a different room
+/// correction to each speaker in a multichannel setup. Every filter keeps its
+/// own state, which persists across calls to [FilterBank::process].
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::filter::{Biquad, FilterBank};
+///
+/// let mut bank = FilterBank::new(vec![
+///     Biquad::new(0.5, 0.0, 0.0, 1.0, 0.0, 0.0),
+///     Biquad::new(2.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+/// ]);
+///
+/// let mut buffer = audio::interleaved![[1.0f32; 4]; 2];
+/// bank.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::interleaved![[0.5f32; 4], [2.0; 4]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FilterBank {
+    filters: Vec<Biquad>,
+}
+
+impl FilterBank {
+    /// Construct a filter bank where the filter at index `n` is applied to
+    /// channel `n`.
+    pub fn new<T>(filters: T) -> Self
+    where
+        T: Into<Vec<Biquad>>,
+    {
+        Self {
+            filters: filters.into(),
+        }
+    }
+
+    /// Access the filters in the bank.
+    pub fn filters(&self) -> &[Biquad] {
+        &self.filters
+    }
+
+    /// Reset the state of every filter in the bank, as if no audio has been
+    /// processed.
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+    }
+
+    /// Filter every channel in `buf` in place with its corresponding filter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of filters in the bank doesn't match the number
+    /// of channels in `buf`.
+    pub fn process<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        assert! {
+            self.filters.len() == buf.channels(),
+            "filter bank has {} filters, but the buffer has {} channels",
+            self.filters.len(),
+            buf.channels()
+        };
+
+        for (chan, filter) in self.filters.iter_mut().enumerate() {
+            filter.process_channel(buf.channel_mut(chan));
+        }
+    }
+}
+
+impl Processor<f32> for FilterBank {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+        FilterBank::process(self, buf);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::processor::Processor;
+use audio_core::{ChannelMut, ChannelsMut};
+use std::f32::consts::PI;
+
+/// A second order IIR filter, also known as a biquad.
+///
+/// This is implemented in the transposed direct form II, with a separate set
+/// of state variables for each channel being processed. The state persists
+/// across calls to [Biquad::process], so a signal can be filtered one block at
+/// a time.
+///
+/// The constructors for common filter shapes are based on the [Audio EQ
+/// Cookbook].
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::filter::Biquad;
+///
+/// let mut filter = Biquad::lowpass(1000.0, 0.707, 44100.0);
+/// let mut buffer = audio::interleaved![[1.0f32; 256]; 2];
+///
+/// filter.process(&mut buffer);
+///
+/// // A lowpass filter lets a constant signal through.
+/// assert!((buffer.frame(0, 255).unwrap() - 1.0).abs() < 0.001);
+/// ```
+///
+/// [Audio EQ Cookbook]: https://www.w3.org/TR/audio-eq-cookbook/
+#[derive(Debug, Clone)]
+pub struct Biquad {
+    coefficients: Coefficients,
+    state: Vec<[f32; 2]>,
+}
+
+/// The normalized coefficients of a biquad.
+#[derive(Debug, Clone)]
+struct Coefficients {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl Biquad {
+    /// Construct a biquad from raw coefficients.
+    ///
+    /// The coefficients are normalized by `a0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Biquad;
+    ///
+    /// // The identity filter.
+    /// let mut filter = Biquad::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    /// let mut buffer = audio::dynamic![[0.5f32, -0.5, 0.25]; 2];
+    ///
+    /// filter.process(&mut buffer);
+    /// assert_eq!(buffer, audio::dynamic![[0.5f32, -0.5, 0.25]; 2]);
+    /// ```
+    pub fn new(b0: f32, b1: f32, b2: f32, a0: f32, a1: f32, a2: f32) -> Self {
+        Self {
+            coefficients: Coefficients {
+                b0: b0 / a0,
+                b1: b1 / a0,
+                b2: b2 / a0,
+                a1: a1 / a0,
+                a2: a2 / a0,
+            },
+            state: Vec::new(),
+        }
+    }
+
+    /// Construct a lowpass filter with the given `cutoff` frequency and `q`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Biquad;
+    ///
+    /// let filter = Biquad::lowpass(1000.0, 0.707, 44100.0);
+    /// ```
+    pub fn lowpass(cutoff: f32, q: f32, sample_rate: f32) -> Self {
+        let (cos, alpha) = intermediates(cutoff, q, sample_rate);
+
+        Self::new(
+            (1.0 - cos) / 2.0,
+            1.0 - cos,
+            (1.0 - cos) / 2.0,
+            1.0 + alpha,
+            -2.0 * cos,
+            1.0 - alpha,
+        )
+    }
+
+    /// Construct a highpass filter with the given `cutoff` frequency and `q`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Biquad;
+    ///
+    /// // Block DC offsets.
+    /// let mut filter = Biquad::highpass(20.0, 0.707, 44100.0);
+    /// let mut buffer = audio::interleaved![[0.5f32; 44100]; 1];
+    ///
+    /// filter.process(&mut buffer);
+    /// assert!(buffer.frame(0, 44099).unwrap().abs() < 0.001);
+    /// ```
+    pub fn highpass(cutoff: f32, q: f32, sample_rate: f32) -> Self {
+        let (cos, alpha) = intermediates(cutoff, q, sample_rate);
+
+        Self::new(
+            (1.0 + cos) / 2.0,
+            -(1.0 + cos),
+            (1.0 + cos) / 2.0,
+            1.0 + alpha,
+            -2.0 * cos,
+            1.0 - alpha,
+        )
+    }
+
+    /// Construct a peaking filter around the `center` frequency, which boosts
+    /// or cuts by `gain_db` decibels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Biquad;
+    ///
+    /// let filter = Biquad::peaking(1000.0, 1.0, 6.0, 44100.0);
+    /// ```
+    pub fn peaking(center: f32, q: f32, gain_db: f32, sample_rate: f32) -> Self {
+        let (cos, alpha) = intermediates(center, q, sample_rate);
+        let a = 10f32.powf(gain_db / 40.0);
+
+        Self::new(
+            1.0 + alpha * a,
+            -2.0 * cos,
+            1.0 - alpha * a,
+            1.0 + alpha / a,
+            -2.0 * cos,
+            1.0 - alpha / a,
+        )
+    }
+
+    /// Reset the state of the filter, as if no audio has been processed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Biquad;
+    ///
+    /// let mut filter = Biquad::lowpass(1000.0, 0.707, 44100.0);
+    ///
+    /// let mut a = audio::dynamic![[1.0f32; 16]; 1];
+    /// filter.process(&mut a);
+    /// filter.reset();
+    ///
+    /// let mut b = audio::dynamic![[1.0f32; 16]; 1];
+    /// filter.process(&mut b);
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn reset(&mut self) {
+        self.state.clear();
+    }
+
+    /// Filter every channel in `buf` in place.
+    ///
+    /// State is kept separately for each channel, and is allocated the first
+    /// time a channel is seen.
+    pub fn process<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        if self.state.len() < buf.channels() {
+            self.state.resize(buf.channels(), [0.0; 2]);
+        }
+
+        for (chan, state) in self.state.iter_mut().take(buf.channels()).enumerate() {
+            filter(&self.coefficients, state, buf.channel_mut(chan));
+        }
+    }
+
+    /// Filter a single channel in place, as if it was the first channel of a
+    /// buffer.
+    pub(super) fn process_channel(&mut self, channel: ChannelMut<'_, f32>) {
+        if self.state.is_empty() {
+            self.state.push([0.0; 2]);
+        }
+
+        filter(&self.coefficients, &mut self.state[0], channel);
+    }
+}
+
+impl Processor<f32> for Biquad {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+        Biquad::process(self, buf);
+    }
+}
+
+/// Filter a single channel in place with the given coefficients and state.
+fn filter(c: &Coefficients, [z1, z2]: &mut [f32; 2], channel: ChannelMut<'_, f32>) {
+    for s in channel.iter_mut() {
+        let x = *s;
+        let y = c.b0 * x + *z1;
+        *z1 = c.b1 * x - c.a1 * y + *z2;
+        *z2 = c.b2 * x - c.a2 * y;
+        *s = y;
+    }
+}
+
+/// Calculate the cosine of the angular frequency and alpha for the given
+/// parameters.
+fn intermediates(frequency: f32, q: f32, sample_rate: f32) -> (f32, f32) {
+    let w0 = 2.0 * PI * frequency / sample_rate;
+    let (sin, cos) = w0.sin_cos();
+    (cos, sin / (2.0 * q))
+}
@@ -0,0 +1,140 @@
+use crate::processor::Processor;
+use audio_core::ChannelsMut;
+
+/// A finite impulse response filter, which convolves the signal with a short
+/// kernel of coefficients, also known as taps.
+///
+/// This is implemented in the direct form, with the same taps used for every
+/// channel and a separate history of the last `taps - 1` input samples for
+/// each channel being processed. The history persists across calls to
+/// [Fir::process], so a signal can be filtered one block at a time.
+///
+/// This is suitable for short kernels like EQ curves or crossovers which have
+/// been baked into taps, or short convolution reverbs. The cost of filtering
+/// grows linearly with the number of taps.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::filter::Fir;
+///
+/// // A three-tap moving average.
+/// let mut filter = Fir::new(vec![1.0 / 3.0; 3]);
+/// let mut buffer = audio::dynamic![[3.0f32, 0.0, 0.0, 6.0, 6.0, 6.0]; 2];
+///
+/// filter.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[1.0f32, 1.0, 1.0, 2.0, 4.0, 6.0]; 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fir {
+    taps: Vec<f32>,
+    state: Vec<Vec<f32>>,
+}
+
+impl Fir {
+    /// Construct a filter from the given taps, where the first tap is applied
+    /// to the current sample, the second to the previous sample, and so on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `taps` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Fir;
+    ///
+    /// // A single sample delay.
+    /// let mut filter = Fir::new(vec![0.0, 1.0]);
+    /// let mut buffer = audio::dynamic![[1.0f32, 2.0, 3.0]; 1];
+    ///
+    /// filter.process(&mut buffer);
+    /// assert_eq!(buffer, audio::dynamic![[0.0f32, 1.0, 2.0]; 1]);
+    /// ```
+    pub fn new<T>(taps: T) -> Self
+    where
+        T: Into<Vec<f32>>,
+    {
+        let taps = taps.into();
+
+        assert!(!taps.is_empty(), "filter must have at least one tap");
+
+        Self {
+            taps,
+            state: Vec::new(),
+        }
+    }
+
+    /// Get the taps of the filter.
+    pub fn taps(&self) -> &[f32] {
+        &self.taps
+    }
+
+    /// Reset the state of the filter, as if no audio has been processed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::filter::Fir;
+    ///
+    /// let mut filter = Fir::new(vec![0.5, 0.5]);
+    ///
+    /// let mut a = audio::dynamic![[1.0f32; 4]; 1];
+    /// filter.process(&mut a);
+    /// filter.reset();
+    ///
+    /// let mut b = audio::dynamic![[1.0f32; 4]; 1];
+    /// filter.process(&mut b);
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn reset(&mut self) {
+        self.state.clear();
+    }
+
+    /// Filter every channel in `buf` in place.
+    ///
+    /// History is kept separately for each channel, and is allocated the
+    /// first time a channel is seen.
+    pub fn process<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        let history = self.taps.len() - 1;
+
+        if self.state.len() < buf.channels() {
+            self.state.resize(buf.channels(), vec![0.0; history]);
+        }
+
+        let (first, rest) = match self.taps.split_first() {
+            Some(split) => split,
+            None => return,
+        };
+
+        for (chan, state) in self.state.iter_mut().take(buf.channels()).enumerate() {
+            for s in buf.channel_mut(chan).iter_mut() {
+                let x = *s;
+
+                // NB: `state[k]` holds the input from `k + 1` samples ago.
+                let y = rest
+                    .iter()
+                    .zip(state.iter())
+                    .fold(first * x, |y, (tap, past)| y + tap * past);
+
+                if history > 0 {
+                    state.rotate_right(1);
+                    state[0] = x;
+                }
+
+                *s = y;
+            }
+        }
+    }
+}
+
+impl Processor<f32> for Fir {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+        Fir::process(self, buf);
+    }
+}
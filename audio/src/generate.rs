@@ -0,0 +1,179 @@
+//! Generators for standard test signals, which fill buffers in place.
+//!
+//! Generators keep their state between calls to `fill`, so that a signal can
+//! be generated one block at a time without discontinuities.
+
+use audio_core::ChannelsMut;
+use std::f32::consts::PI;
+
+/// Construct a generator for a sine tone with the given `freq` and
+/// `amplitude`, at the given `sample_rate`.
+///
+/// The tone starts at a phase of zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::generate;
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 8]; 2];
+/// generate::sine(12000.0, 0.5, 48000.0).fill(&mut buffer);
+///
+/// for chan in 0..2 {
+///     let expected = [0.0, 0.5, 0.0, -0.5, 0.0, 0.5, 0.0, -0.5];
+///
+///     for (s, e) in buffer[chan].iter().zip(expected.iter()) {
+///         assert!((s - e).abs() < 1e-6);
+///     }
+/// }
+/// ```
+pub fn sine(freq: f32, amplitude: f32, sample_rate: f32) -> Sine {
+    Sine {
+        phase: 0.0,
+        step: freq / sample_rate,
+        amplitude,
+    }
+}
+
+/// Construct a generator for uniformly distributed white noise in the range
+/// `[-amplitude, amplitude]`.
+///
+/// The noise is produced by a deterministic pseudo-random number generator
+/// initialized with `seed`, so the same seed always generates the same
+/// signal.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::generate;
+///
+/// let mut a = audio::dynamic![[0.0f32; 64]; 2];
+/// let mut b = audio::dynamic![[0.0f32; 64]; 2];
+///
+/// generate::white_noise(0.5, 42).fill(&mut a);
+/// generate::white_noise(0.5, 42).fill(&mut b);
+///
+/// assert_eq!(a, b);
+/// assert!(a.iter().flatten().all(|s| s.abs() <= 0.5));
+/// ```
+pub fn white_noise(amplitude: f32, seed: u64) -> WhiteNoise {
+    WhiteNoise {
+        state: seed,
+        amplitude,
+    }
+}
+
+/// Construct a generator for silence.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::generate;
+///
+/// let mut buffer = audio::interleaved![[1.0f32; 4]; 2];
+/// generate::silence().fill(&mut buffer);
+///
+/// assert_eq!(buffer, audio::interleaved![[0.0f32; 4]; 2]);
+/// ```
+pub fn silence() -> Silence {
+    Silence(())
+}
+
+/// A sine tone generator.
+///
+/// See [sine].
+#[derive(Debug, Clone)]
+pub struct Sine {
+    /// The phase of the next sample, in cycles.
+    phase: f32,
+    /// How much the phase advances for every sample, in cycles.
+    step: f32,
+    amplitude: f32,
+}
+
+impl Sine {
+    /// Fill every channel in `buf` with the tone, continuing from where the
+    /// previous fill left off.
+    ///
+    /// Every channel receives the same signal.
+    pub fn fill<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        let mut end = self.phase;
+
+        for chan in 0..buf.channels() {
+            let mut phase = self.phase;
+
+            for s in buf.channel_mut(chan).iter_mut() {
+                *s = (2.0 * PI * phase).sin() * self.amplitude;
+                // NB: wrap around to preserve precision for long signals.
+                phase = (phase + self.step).fract();
+            }
+
+            end = phase;
+        }
+
+        self.phase = end;
+    }
+}
+
+/// A white noise generator.
+///
+/// See [white_noise].
+#[derive(Debug, Clone)]
+pub struct WhiteNoise {
+    state: u64,
+    amplitude: f32,
+}
+
+impl WhiteNoise {
+    /// Fill every channel in `buf` with noise, continuing the sequence from
+    /// where the previous fill left off.
+    ///
+    /// Every channel receives an independent signal. Channels are filled in
+    /// order, one after another.
+    pub fn fill<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        for chan in 0..buf.channels() {
+            for s in buf.channel_mut(chan).iter_mut() {
+                *s = self.next() * self.amplitude;
+            }
+        }
+    }
+
+    /// Generate the next random value in the range `[-1, 1)`, using the
+    /// SplitMix64 algorithm.
+    fn next(&mut self) -> f32 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        // NB: the top 24 bits are exactly representable in an f32.
+        (z >> 40) as f32 / (1u32 << 23) as f32 - 1.0
+    }
+}
+
+/// A silence generator.
+///
+/// See [silence].
+#[derive(Debug, Clone)]
+pub struct Silence(());
+
+impl Silence {
+    /// Fill every channel in `buf` with silence.
+    pub fn fill<B>(&mut self, mut buf: B)
+    where
+        B: ChannelsMut<f32>,
+    {
+        for chan in 0..buf.channels() {
+            for s in buf.channel_mut(chan).iter_mut() {
+                *s = 0.0;
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use audio_core::{
     ResizableBuf, Sample,
 };
 use std::cmp;
+use std::convert::TryFrom;
 use std::fmt;
 use std::hash;
 use std::marker;
@@ -28,8 +29,9 @@ pub use self::iter::{Iter, IterMut};
 /// naturally makes the buffer a bit harder to work with, and we have to rely on
 /// iterators to access logical channels.
 ///
-/// Resized regions aren't zeroed, so certain operations might cause stale data
-/// to be visible after a resize.
+/// Frames which are sized back in by [Interleaved::resize] are zeroed, but the
+/// regions shuffled around by [Interleaved::resize_channels] aren't, so stale
+/// data might be visible after changing the number of channels.
 ///
 /// ```rust
 /// let mut buffer = audio::Interleaved::<f32>::with_topology(2, 4);
@@ -102,23 +104,34 @@ impl<T> Interleaved<T> {
         }
     }
 
-    /// Allocate an audio buffer from a fixed-size array.
+    /// Construct an audio buffer from a vector with the given topology,
+    /// without copying.
     ///
-    /// See [dynamic!].
+    /// The vector is expected to be stored frame by frame, with the samples for
+    /// each channel in a frame stored next to each other. Like
+    /// `0:0, 1:0, 0:1, 1:1`. This is the inverse of
+    /// [Interleaved::into_raw_parts].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` doesn't match `channels * frames`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut buffer = audio::interleaved![[2.0; 256]; 4];
+    /// let buffer = audio::Interleaved::from_vec(vec![1, 5, 2, 6, 3, 7, 4, 8], 2, 4);
     ///
-    /// assert_eq!(buffer.frames(), 256);
-    /// assert_eq!(buffer.channels(), 4);
-    ///
-    /// for chan in &buffer {
-    ///     assert!(chan.iter().eq(&[2.0; 256][..]));
-    /// }
+    /// assert_eq!(buffer, audio::interleaved![[1, 2, 3, 4], [5, 6, 7, 8]]);
     /// ```
     pub fn from_vec(data: Vec<T>, channels: usize, frames: usize) -> Self {
+        assert! {
+            Some(data.len()) == channels.checked_mul(frames),
+            "vector of length {} doesn't match the topology {}x{}",
+            data.len(),
+            channels,
+            frames
+        };
+
         Self {
             data,
             channels,
@@ -228,6 +241,9 @@ impl<T> Interleaved<T> {
 
     /// Take ownership of the backing vector.
     ///
+    /// The vector is stored frame by frame, with the samples for each channel
+    /// in a frame stored next to each other. Like `0:0, 1:0, 0:1, 1:1`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -249,6 +265,26 @@ impl<T> Interleaved<T> {
         self.data
     }
 
+    /// Take ownership of the backing vector along with the number of channels
+    /// and frames in the buffer, without copying.
+    ///
+    /// The vector is stored frame by frame, with the samples for each channel
+    /// in a frame stored next to each other. Like `0:0, 1:0, 0:1, 1:1`. The
+    /// buffer can be reconstructed with [Interleaved::from_vec].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::interleaved![[1, 2, 3, 4], [5, 6, 7, 8]];
+    /// let (data, channels, frames) = buffer.into_raw_parts();
+    ///
+    /// assert_eq!(data, vec![1, 5, 2, 6, 3, 7, 4, 8]);
+    /// assert_eq!((channels, frames), (2, 4));
+    /// ```
+    pub fn into_raw_parts(self) -> (Vec<T>, usize, usize) {
+        (self.data, self.channels, self.frames)
+    }
+
     /// Access the underlying vector as a slice.
     ///
     /// # Examples
@@ -287,6 +323,66 @@ impl<T> Interleaved<T> {
         &mut self.data
     }
 
+    /// Deinterleave the buffer into the caller-provided channel slices
+    /// `channels`, returning the number of frames written to each slice.
+    ///
+    /// This doesn't allocate, which makes it suitable for feeding planar
+    /// processors from an interleaved buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of slices doesn't match the number of channels in
+    /// the buffer, or if any slice is shorter than the number of frames in the
+    /// buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::interleaved![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let mut left = [0; 4];
+    /// let mut right = [0; 4];
+    ///
+    /// let frames = buffer.deinterleave_into(&mut [&mut left[..], &mut right[..]]);
+    ///
+    /// assert_eq!(frames, 3);
+    /// assert_eq!(left, [1, 2, 3, 0]);
+    /// assert_eq!(right, [4, 5, 6, 0]);
+    /// ```
+    pub fn deinterleave_into(&self, channels: &mut [&mut [T]]) -> usize
+    where
+        T: Copy,
+    {
+        assert! {
+            channels.len() == self.channels,
+            "expected {} channel slices, but got {}",
+            self.channels,
+            channels.len()
+        };
+
+        for (chan, to) in channels.iter().enumerate() {
+            assert! {
+                to.len() >= self.frames,
+                "channel slice {} has length {}, but expected at least {}",
+                chan,
+                to.len(),
+                self.frames
+            };
+        }
+
+        if self.channels == 0 {
+            return self.frames;
+        }
+
+        for (n, frame) in self.data.chunks_exact(self.channels).enumerate() {
+            for (to, s) in channels.iter_mut().zip(frame) {
+                to[n] = *s;
+            }
+        }
+
+        self.frames
+    }
+
     /// Get the number of frames in the channels of an audio buffer.
     ///
     /// # Examples
@@ -438,14 +534,15 @@ impl<T> Interleaved<T> {
         T: Sample,
     {
         self.inner_resize(channels, self.frames);
+        audio_core::checks::exact_size(&*self);
     }
 
     /// Set the size of the buffer. The size is the size of each channel's
     /// buffer.
     ///
-    /// If the size of the buffer increases as a result, the new regions in the
-    /// frames will be zeroed. If the size decreases, the region will be left
-    /// untouched. So if followed by another increase, the data will be "dirty".
+    /// If the size of the buffer increases as a result, the new frames will be
+    /// zeroed, even if they were previously sized out. Use
+    /// [Interleaved::resize_with] to fill them with another value.
     ///
     /// # Examples
     ///
@@ -485,7 +582,44 @@ impl<T> Interleaved<T> {
     where
         T: Sample,
     {
+        self.resize_with(frames, T::ZERO);
+    }
+
+    /// Set the size of the buffer, filling any new frames in every channel with
+    /// `value`.
+    ///
+    /// This is the same as [Interleaved::resize], except that the frames exposed when
+    /// the buffer grows are set to `value` instead of being zeroed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Interleaved::<f32>::with_topology(2, 2);
+    ///
+    /// buffer.resize_with(4, 1.0);
+    /// assert_eq!(buffer.frame(0, 1), Some(0.0));
+    /// assert_eq!(buffer.frame(0, 2), Some(1.0));
+    /// assert_eq!(buffer.frame(1, 3), Some(1.0));
+    ///
+    /// buffer.resize(1);
+    /// buffer.resize_with(3, 0.5);
+    /// assert_eq!(buffer.frame(0, 1), Some(0.5));
+    /// assert_eq!(buffer.frame(1, 2), Some(0.5));
+    /// ```
+    pub fn resize_with(&mut self, frames: usize, value: T)
+    where
+        T: Sample,
+    {
+        let from = self.frames;
         self.inner_resize(self.channels, frames);
+
+        if from < frames {
+            for s in &mut self.data[from * self.channels..frames * self.channels] {
+                *s = value;
+            }
+        }
+
+        audio_core::checks::exact_size(&*self);
     }
 
     /// Get a reference to a channel.
@@ -651,6 +785,82 @@ impl<T> Interleaved<T> {
         }
     }
 
+    /// Construct an iterator over all frames in the buffer, where each frame
+    /// is an array with one sample for each of the `N` channels.
+    ///
+    /// This is convenient when the number of channels is known up front, since
+    /// frames can be destructured without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer doesn't have exactly `N` channels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::interleaved![[1, 2, 3], [10, 20, 30]];
+    ///
+    /// let mono = buffer
+    ///     .frames_array()
+    ///     .map(|[l, r]| l + r)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(mono, vec![11, 22, 33]);
+    /// ```
+    pub fn frames_array<const N: usize>(&self) -> impl Iterator<Item = [T; N]> + '_
+    where
+        T: Copy,
+    {
+        assert! {
+            self.channels == N,
+            "expected {} channels, but the buffer has {}",
+            N,
+            self.channels
+        };
+
+        let data = &self.data[..self.frames * N];
+
+        (0..self.frames).map(
+            move |n| match <[T; N]>::try_from(&data[n * N..(n + 1) * N]) {
+                Ok(frame) => frame,
+                Err(..) => unreachable!(),
+            },
+        )
+    }
+
+    /// Construct an iterator over contiguous slices of the interleaved data,
+    /// each covering `chunk_frames` whole frames.
+    ///
+    /// Every slice starts at a frame boundary and contains
+    /// `chunk_frames * channels` samples, except for the last one which is
+    /// shorter if the number of frames isn't evenly divisible by
+    /// `chunk_frames`. This is useful for feeding kernels which operate on
+    /// contiguous spans of interleaved samples, like SIMD code.
+    ///
+    /// Note that the slices are only aligned to frame boundaries. No alignment
+    /// in memory beyond that of `T` is guaranteed, so code which requires
+    /// aligned loads has to check for it itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_frames` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::interleaved![[1, 2, 3], [10, 20, 30]];
+    ///
+    /// let mut it = buffer.frame_slices(2);
+    /// assert_eq!(it.next(), Some(&[1, 10, 2, 20][..]));
+    /// assert_eq!(it.next(), Some(&[3, 30][..]));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    pub fn frame_slices(&self, chunk_frames: usize) -> impl Iterator<Item = &[T]> + '_ {
+        assert!(chunk_frames != 0, "chunk frames must be non-zero");
+        let len = usize::max(chunk_frames * self.channels, 1);
+        self.as_slice().chunks(len)
+    }
+
     /// The internal resize function for interleaved channel buffers.
     /// Note: this is safe only because of the `T: Sample` bound. DO NOT REMOVE.
     fn inner_resize(&mut self, channels: usize, frames: usize)
@@ -688,7 +898,8 @@ impl<T> Interleaved<T> {
         let old_cap = self.data.capacity();
 
         if new_cap > old_cap {
-            self.data.reserve(new_cap - old_cap);
+            // NB: `reserve` is relative to the length and not the capacity.
+            self.data.reserve(new_cap - self.data.len());
             let new_cap = self.data.capacity();
 
             // Safety: capacity is governed by the underlying vector.
@@ -765,11 +976,17 @@ where
 
 impl<T> hash::Hash for Interleaved<T>
 where
-    T: hash::Hash,
+    T: hash::Hash + cmp::Eq,
 {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // NB: needs to hash identically to the other buffer types.
+        self.channels.hash(state);
+        self.frames.hash(state);
+
         for channel in self.iter() {
-            channel.hash(state);
+            for sample in channel.iter() {
+                sample.hash(state);
+            }
         }
     }
 }
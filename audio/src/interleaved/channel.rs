@@ -101,6 +101,8 @@ pub struct ChannelIter<'a, T> {
 unsafe impl<T> Send for ChannelIter<'_, T> where T: Sync {}
 unsafe impl<T> Sync for ChannelIter<'_, T> where T: Sync {}
 
+impl<T> ExactSizeIterator for ChannelIter<'_, T> {}
+
 impl<'a, T> Iterator for ChannelIter<'a, T> {
     type Item = &'a T;
 
@@ -109,6 +111,11 @@ impl<'a, T> Iterator for ChannelIter<'a, T> {
         self.frame += 1;
         Some(item)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.frames.saturating_sub(self.frame);
+        (len, Some(len))
+    }
 }
 
 pub(crate) struct RawChannelRef<T: ?Sized> {
@@ -330,6 +337,22 @@ impl<'a, T> ChannelMut<'a, T> {
     }
 
     /// Construct a mutable iterator over the current channel.
+    ///
+    /// This steps over the frames of the channel by the number of channels
+    /// in the buffer, so only the samples belonging to this channel are
+    /// visited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Interleaved::<u32>::with_topology(3, 4);
+    ///
+    /// for (n, s) in buffer.get_mut(1).unwrap().iter_mut().enumerate() {
+    ///     *s = n as u32 + 1;
+    /// }
+    ///
+    /// assert_eq!(buffer.as_slice(), &[0, 1, 0, 0, 2, 0, 0, 3, 0, 0, 4, 0]);
+    /// ```
     pub fn iter_mut(&mut self) -> ChannelIterMut<'_, T> {
         ChannelIterMut {
             inner: self.inner,
@@ -402,12 +425,21 @@ pub struct ChannelIterMut<'a, T> {
 unsafe impl<T> Send for ChannelIterMut<'_, T> where T: Send {}
 unsafe impl<T> Sync for ChannelIterMut<'_, T> where T: Sync {}
 
+impl<T> ExactSizeIterator for ChannelIterMut<'_, T> {}
+
 impl<'a, T> Iterator for ChannelIterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Safety: every frame is visited at most once and frames in the same
+        // channel never alias, so handing out disjoint `&'a mut T` is fine.
         let item = unsafe { &mut *self.inner.frame_mut(self.frame)? };
         self.frame += 1;
         Some(item)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.frames.saturating_sub(self.frame);
+        (len, Some(len))
+    }
 }
@@ -5,7 +5,7 @@
 //!
 //! The primary traits that govern this is [ReadBuf] and [WriteBuf].
 
-pub use audio_core::{ReadBuf, WriteBuf};
+pub use audio_core::{Exact, ReadBuf, WriteBuf};
 
 mod utils;
 pub use self::utils::{copy_remaining, translate_remaining};
@@ -18,3 +18,6 @@ pub use self::write::Write;
 
 mod read_write;
 pub use self::read_write::ReadWrite;
+
+mod deinterleaver;
+pub use self::deinterleaver::Deinterleaver;
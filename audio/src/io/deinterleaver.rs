@@ -0,0 +1,104 @@
+use crate::sequential::Sequential;
+use audio_core::{Buf, Channel, Channels, ExactSizeBuf, ReadBuf, ResizableBuf, Sample};
+
+/// Adapter which reads from an interleaved [ReadBuf] and presents what it has
+/// read as a planar buffer.
+///
+/// This lets planar processing code consume interleaved input without the
+/// caller having to own a conversion step.
+///
+/// The transposition is performed eagerly into an internal scratch buffer
+/// when [Deinterleaver::fill] is called. This costs a copy per sample, but in
+/// return every channel is exposed as a linear slice which is cheap to access
+/// repeatedly. Lazily striding over the interleaved source would avoid the
+/// copy, but that is what [Read][crate::io::Read] already does.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::{Channels, ExactSizeBuf, ReadBuf};
+/// use audio::io;
+///
+/// let from = audio::wrap::interleaved(&[1, 5, 2, 6, 3, 7, 4, 8][..], 2);
+/// let mut planar = io::Deinterleaver::new(io::Read::new(from));
+///
+/// assert_eq!(planar.fill(), 4);
+/// assert!(!planar.as_ref().has_remaining());
+///
+/// assert_eq!(planar.frames(), 4);
+/// assert!(planar.channel(0).iter().eq([1, 2, 3, 4]));
+/// assert!(planar.channel(1).iter().eq([5, 6, 7, 8]));
+/// ```
+pub struct Deinterleaver<R, T> {
+    reader: R,
+    buf: Sequential<T>,
+}
+
+impl<R, T> Deinterleaver<R, T> {
+    /// Construct a new deinterleaving adapter around the given `reader`.
+    ///
+    /// The adapter is initially empty until [Deinterleaver::fill] is called.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Sequential::new(),
+        }
+    }
+
+    /// Transpose all remaining frames in the underlying reader into the
+    /// planar scratch buffer, replacing its previous content.
+    ///
+    /// The underlying reader is advanced by the number of frames read, which
+    /// is returned.
+    pub fn fill(&mut self) -> usize
+    where
+        R: ReadBuf + Channels<T>,
+        T: Sample,
+    {
+        let frames = self.reader.remaining();
+        self.buf.resize_topology(self.reader.channels(), frames);
+        crate::buf::copy(&self.reader, &mut self.buf);
+        self.reader.advance(frames);
+        frames
+    }
+
+    /// Access the underlying reader.
+    #[inline]
+    pub fn as_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Access the underlying reader mutably.
+    #[inline]
+    pub fn as_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Convert into the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, T> Buf for Deinterleaver<R, T> {
+    fn frames_hint(&self) -> Option<usize> {
+        Some(self.buf.frames())
+    }
+
+    fn channels(&self) -> usize {
+        self.buf.channels()
+    }
+}
+
+impl<R, T> ExactSizeBuf for Deinterleaver<R, T> {
+    fn frames(&self) -> usize {
+        self.buf.frames()
+    }
+}
+
+impl<R, T> Channels<T> for Deinterleaver<R, T> {
+    fn channel(&self, channel: usize) -> Channel<'_, T> {
+        self.buf.channel(channel)
+    }
+}
@@ -0,0 +1,129 @@
+//! A gain value which keeps track of whether it's specified in decibels or as
+//! a linear factor.
+
+use std::ops;
+
+/// A gain which can be constructed from and converted to both decibels and a
+/// linear factor.
+///
+/// Gains are easy to mix up when passed around as plain numbers, since `-6.0`
+/// is a sensible gain in decibels but a nonsensical linear factor. Using this
+/// type at API boundaries makes the unit explicit. For the same reason plain
+/// numbers don't convert into it, it has to be constructed with either
+/// [Level::from_db] or [Level::from_linear].
+///
+/// Samples of type [f32] and [f64] can be multiplied by a gain directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::Level;
+///
+/// let gain = Level::from_db(-6.0);
+/// assert!((gain.as_linear() - 0.501).abs() < 1e-3);
+///
+/// assert_eq!(1.0f32 * Level::from_linear(0.5), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Level {
+    linear: f32,
+}
+
+impl Level {
+    /// A gain which leaves the signal untouched, which is `0.0` dB.
+    pub const UNITY: Self = Self { linear: 1.0 };
+
+    /// Construct a gain from a linear factor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Level;
+    ///
+    /// assert_eq!(Level::from_linear(1.0).as_db(), 0.0);
+    /// assert_eq!(Level::from_linear(0.0).as_db(), f32::NEG_INFINITY);
+    /// ```
+    pub fn from_linear(linear: f32) -> Self {
+        Self { linear }
+    }
+
+    /// Construct a gain from decibels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Level;
+    ///
+    /// assert_eq!(Level::from_db(0.0).as_linear(), 1.0);
+    /// assert_eq!(Level::from_db(-20.0).as_linear(), 0.1);
+    /// ```
+    pub fn from_db(db: f32) -> Self {
+        Self {
+            linear: 10.0f32.powf(db / 20.0),
+        }
+    }
+
+    /// Get the gain as a linear factor.
+    pub fn as_linear(self) -> f32 {
+        self.linear
+    }
+
+    /// Get the gain in decibels.
+    ///
+    /// A linear gain of zero is negative infinity decibels.
+    pub fn as_db(self) -> f32 {
+        20.0 * self.linear.log10()
+    }
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::UNITY
+    }
+}
+
+/// Gains are combined by multiplying them, which adds them in decibels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::Level;
+///
+/// let gain = Level::from_db(-6.0) * Level::from_db(-14.0);
+/// assert!((gain.as_db() + 20.0).abs() < 1e-4);
+/// ```
+impl ops::Mul for Level {
+    type Output = Level;
+
+    fn mul(self, rhs: Level) -> Self::Output {
+        Level::from_linear(self.linear * rhs.linear)
+    }
+}
+
+impl ops::Mul<Level> for f32 {
+    type Output = f32;
+
+    fn mul(self, rhs: Level) -> Self::Output {
+        self * rhs.linear
+    }
+}
+
+impl ops::MulAssign<Level> for f32 {
+    fn mul_assign(&mut self, rhs: Level) {
+        *self *= rhs.linear;
+    }
+}
+
+impl ops::Mul<Level> for f64 {
+    type Output = f64;
+
+    fn mul(self, rhs: Level) -> Self::Output {
+        self * rhs.linear as f64
+    }
+}
+
+impl ops::MulAssign<Level> for f64 {
+    fn mul_assign(&mut self, rhs: Level) {
+        *self *= rhs.linear as f64;
+    }
+}
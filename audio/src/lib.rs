@@ -120,12 +120,25 @@
 
 #[macro_use]
 mod macros;
+pub mod analysis;
 pub mod buf;
 pub mod dynamic;
+pub mod effect;
+pub mod filter;
+pub mod generate;
 pub mod interleaved;
 pub mod io;
+pub mod level;
+pub mod looper;
+pub mod ms;
+pub mod pcm;
+pub mod pool;
+pub mod processor;
+pub mod resample;
 pub mod sequential;
+pub mod timed;
 mod utils;
+pub mod wav;
 pub mod wrap;
 
 #[cfg(test)]
@@ -133,6 +146,10 @@ mod tests;
 
 pub use self::dynamic::Dynamic;
 pub use self::interleaved::Interleaved;
+pub use self::level::Level;
+pub use self::looper::LoopPlayer;
+pub use self::pool::{BufferPool, PooledBuffer};
 pub use self::sequential::Sequential;
+pub use self::timed::Timed;
 
 pub use audio_core::*;
@@ -0,0 +1,156 @@
+//! Sample-accurate looping playback of a buffer.
+
+use crate::dynamic::Dynamic;
+use audio_core::{ChannelsMut, Sample};
+
+/// A player which reads a buffer from the start, and once it reaches the end of
+/// its loop region wraps back around to the start of the loop.
+///
+/// This is the core of a sampler voice. The play position is maintained
+/// between calls to [LoopPlayer::read_into], so a sample can be played back one
+/// block at a time without discontinuities.
+///
+/// # Examples
+///
+/// ```rust
+/// let buffer = audio::dynamic![[1.0f32, 2.0, 3.0, 4.0, 5.0]];
+/// let mut player = audio::LoopPlayer::new(buffer, 2, 4);
+///
+/// let mut out = audio::dynamic![[0.0f32; 4]];
+///
+/// player.read_into(&mut out);
+/// assert_eq!(out, audio::dynamic![[1.0, 2.0, 3.0, 4.0]]);
+///
+/// player.read_into(&mut out);
+/// assert_eq!(out, audio::dynamic![[3.0, 4.0, 3.0, 4.0]]);
+/// ```
+pub struct LoopPlayer<T> {
+    buffer: Dynamic<T>,
+    start: usize,
+    end: usize,
+    position: usize,
+}
+
+impl<T> LoopPlayer<T>
+where
+    T: Sample,
+{
+    /// Construct a new player for `buffer`, which loops over the frames in
+    /// `start..end`.
+    ///
+    /// Playback starts at the first frame of the buffer, so any frames before
+    /// the loop region are only played once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the loop region is empty or if it extends past the end of the
+    /// buffer.
+    pub fn new(buffer: Dynamic<T>, start: usize, end: usize) -> Self {
+        assert! {
+            start < end && end <= buffer.frames(),
+            "loop region {}..{} is out of bounds for a buffer with {} frames",
+            start,
+            end,
+            buffer.frames()
+        };
+
+        Self {
+            buffer,
+            start,
+            end,
+            position: 0,
+        }
+    }
+
+    /// Get the frame in the buffer which will be read next.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::dynamic![[0.0f32; 8]; 2];
+    /// let mut player = audio::LoopPlayer::new(buffer, 2, 6);
+    /// assert_eq!(player.position(), 0);
+    ///
+    /// player.read_into(&mut audio::dynamic![[0.0f32; 10]; 2]);
+    /// assert_eq!(player.position(), 2);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Move the play position to the given frame in the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is at or past the end of the loop region.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::dynamic![[1.0f32, 2.0, 3.0, 4.0]];
+    /// let mut player = audio::LoopPlayer::new(buffer, 0, 4);
+    /// player.seek(3);
+    ///
+    /// let mut out = audio::dynamic![[0.0f32; 3]];
+    /// player.read_into(&mut out);
+    /// assert_eq!(out, audio::dynamic![[4.0, 1.0, 2.0]]);
+    /// ```
+    pub fn seek(&mut self, position: usize) {
+        assert! {
+            position < self.end,
+            "position {} is past the end of the loop region at {}",
+            position,
+            self.end
+        };
+
+        self.position = position;
+    }
+
+    /// Fill `out` with frames from the buffer, wrapping around to the start of
+    /// the loop region every time its end is reached.
+    ///
+    /// The number of frames read is the length of the shortest channel in
+    /// `out`. Only the channels which are common to the buffer and `out` are
+    /// written to.
+    pub fn read_into<B>(&mut self, mut out: B)
+    where
+        B: ChannelsMut<T>,
+    {
+        let channels = usize::min(self.buffer.channels(), out.channels());
+
+        let frames = (0..out.channels())
+            .map(|chan| out.channel_mut(chan).frames())
+            .min()
+            .unwrap_or_default();
+
+        let mut written = 0;
+
+        while written < frames {
+            let len = usize::min(frames - written, self.end - self.position);
+            let range = self.position..self.position + len;
+
+            for chan in 0..channels {
+                out.channel_mut(chan)
+                    .skip(written)
+                    .copy_from_slice(&self.buffer[chan][range.clone()]);
+            }
+
+            written += len;
+            self.position += len;
+
+            if self.position == self.end {
+                self.position = self.start;
+            }
+        }
+    }
+
+    /// Access the buffer being played.
+    pub fn buffer(&self) -> &Dynamic<T> {
+        &self.buffer
+    }
+
+    /// Convert the player back into the buffer being played.
+    pub fn into_inner(self) -> Dynamic<T> {
+        self.buffer
+    }
+}
@@ -30,6 +30,15 @@
 /// assert_eq!(&buf[0], &[0, 1, 2, 3]);
 /// assert_eq!(&buf[1], &[4, 5, 6, 7]);
 /// ```
+///
+/// Evaluating a function of the channel and the frame for every sample.
+///
+/// ```rust
+/// let buf = audio::dynamic![|c, f| c + f * 10; [4]; 2];
+///
+/// assert_eq!(&buf[0], &[0, 10, 20, 30]);
+/// assert_eq!(&buf[1], &[1, 11, 21, 31]);
+/// ```
 #[macro_export]
 macro_rules! dynamic {
     // Branch of the macro used when we can perform a literal instantiation of
@@ -64,6 +73,23 @@ macro_rules! dynamic {
         $crate::Dynamic::from_frames([$($value),*], $channels)
     };
 
+    // Build a dynamic audio buffer by evaluating a function for every sample.
+    //
+    // `$frames` and `$channels` should evaluate to `usize`, and `$generator`
+    // should be callable as `Fn(usize, usize) -> T`.
+    ($generator:expr; [$frames:expr]; $channels:expr) => {{
+        let generator = $generator;
+        let mut buffer = $crate::Dynamic::with_topology($channels, $frames);
+
+        for (c, chan) in buffer.iter_mut().enumerate() {
+            for (f, s) in chan.iter_mut().enumerate() {
+                *s = generator(c, f);
+            }
+        }
+
+        buffer
+    }};
+
     // Build a dynamic audio buffer from a specific topology of channels.
     ($($channel:expr),* $(,)?) => {
         $crate::Dynamic::from_array([$($channel),*])
@@ -100,6 +126,14 @@ macro_rules! dynamic {
 ///
 /// assert_eq!(buf.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7])
 /// ```
+///
+/// Evaluating a function of the channel and the frame for every sample.
+///
+/// ```rust
+/// let buf = audio::sequential![|c, f| c + f * 10; [4]; 2];
+///
+/// assert_eq!(buf.as_slice(), &[0, 10, 20, 30, 1, 11, 21, 31]);
+/// ```
 #[macro_export]
 macro_rules! sequential {
     // Branch of the macro used when we can evaluate an expression that is
@@ -116,6 +150,23 @@ macro_rules! sequential {
         $crate::Sequential::from_frames([$($value),*], $channels)
     };
 
+    // Build a sequential audio buffer by evaluating a function for every sample.
+    //
+    // `$frames` and `$channels` should evaluate to `usize`, and `$generator`
+    // should be callable as `Fn(usize, usize) -> T`.
+    ($generator:expr; [$frames:expr]; $channels:expr) => {{
+        let generator = $generator;
+        let mut buffer = $crate::Sequential::with_topology($channels, $frames);
+
+        for (c, chan) in buffer.iter_mut().enumerate() {
+            for (f, s) in chan.iter_mut().enumerate() {
+                *s = generator(c, f);
+            }
+        }
+
+        buffer
+    }};
+
     // Build a sequential audio buffer from a specific topology of channels.
     ($($channel:expr),* $(,)?) => {
         $crate::Sequential::from_array([$($channel),*])
@@ -152,6 +203,14 @@ macro_rules! sequential {
 ///
 /// assert_eq!(buf.as_slice(), &[0, 4, 1, 5, 2, 6, 3, 7])
 /// ```
+///
+/// Evaluating a function of the channel and the frame for every sample.
+///
+/// ```rust
+/// let buf = audio::interleaved![|c, f| c + f * 10; [4]; 2];
+///
+/// assert_eq!(buf.as_slice(), &[0, 1, 10, 11, 20, 21, 30, 31]);
+/// ```
 #[macro_export]
 macro_rules! interleaved {
     // Branch of the macro used when we can evaluate an expression that is
@@ -168,8 +227,91 @@ macro_rules! interleaved {
         $crate::Interleaved::from_frames([$($value),*], $channels)
     };
 
+    // Build an interleaved audio buffer by evaluating a function for every sample.
+    //
+    // `$frames` and `$channels` should evaluate to `usize`, and `$generator`
+    // should be callable as `Fn(usize, usize) -> T`.
+    ($generator:expr; [$frames:expr]; $channels:expr) => {{
+        let generator = $generator;
+        let mut buffer = $crate::Interleaved::with_topology($channels, $frames);
+
+        for (c, mut chan) in buffer.iter_mut().enumerate() {
+            for (f, s) in chan.iter_mut().enumerate() {
+                *s = generator(c, f);
+            }
+        }
+
+        buffer
+    }};
+
     // Build an interleaved audio buffer from a specific topology of channels.
     ($($channel:expr),* $(,)?) => {
         $crate::Interleaved::from_array([$($channel),*])
     };
 }
+
+/// Construct a dynamic audio buffer by evaluating a function for every sample.
+///
+/// The function is called with the channel and the frame of each sample, in
+/// that order. This is useful for constructing test signals.
+///
+/// This is the same as the function branch of [dynamic!], see also
+/// [sequential!] and [interleaved!].
+///
+/// # Examples
+///
+/// ```rust
+/// let buf = audio::generate!(|c, f| (c * 10 + f) as i16; [4]; 2);
+///
+/// assert_eq!(&buf[0], &[0, 1, 2, 3]);
+/// assert_eq!(&buf[1], &[10, 11, 12, 13]);
+/// ```
+///
+/// Generating a sine wave with a phase offset per channel.
+///
+/// ```rust
+/// use std::f32::consts::PI;
+///
+/// let buf = audio::generate!(|c, f| (f as f32 * PI / 8.0 + c as f32 * PI / 2.0).sin(); [256]; 2);
+///
+/// assert_eq!(buf.frames(), 256);
+/// assert_eq!(buf.channels(), 2);
+///
+/// assert!(buf[0][0].abs() < 1e-6);
+/// assert!((buf[0][4] - 1.0).abs() < 1e-6);
+/// assert!((buf[1][0] - 1.0).abs() < 1e-6);
+/// assert!(buf[1][4].abs() < 1e-6);
+/// ```
+#[macro_export]
+macro_rules! generate {
+    ($generator:expr; [$frames:expr]; $channels:expr) => {
+        $crate::dynamic![$generator; [$frames]; $channels]
+    };
+}
+
+/// Construct a [Chain][crate::processor::Chain] which runs the given
+/// processors in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::effect::Gain;
+/// use audio::processor::Processor as _;
+/// use audio::Level;
+///
+/// let mut chain = audio::chain![Gain::new(Level::from_linear(2.0)), Gain::new(Level::from_linear(0.25))];
+/// assert_eq!(chain.len(), 2);
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 4]; 2];
+/// chain.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.5f32; 4]; 2]);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    ($($processor:expr),* $(,)?) => {{
+        let mut chain = $crate::processor::Chain::new();
+        $(chain.push($processor);)*
+        chain
+    }};
+}
@@ -0,0 +1,99 @@
+//! Mid-side encoding and decoding of stereo buffers.
+//!
+//! Mid-side processing represents a stereo signal as its sum (mid) and
+//! difference (side), which is central to stereo widening and mastering.
+//!
+//! # Examples
+//!
+//! Widening a stereo signal by boosting the side channel.
+//!
+//! ```rust
+//! use audio::ChannelsMut as _;
+//! use audio::ms;
+//!
+//! let mut buffer = audio::dynamic![[1.0f32, 0.5], [0.0, 0.5]];
+//!
+//! ms::encode(&mut buffer);
+//!
+//! for s in buffer.channel_mut(1).iter_mut() {
+//!     *s *= 2.0;
+//! }
+//!
+//! ms::decode(&mut buffer);
+//!
+//! assert_eq!(buffer, audio::dynamic![[1.5f32, 0.5], [-0.5, 0.5]]);
+//! ```
+
+use audio_core::ChannelsMut;
+
+/// Encode a stereo buffer from left and right in place into mid in the first
+/// channel and side in the second.
+///
+/// This is calculated as `M = (L + R) / 2` and `S = (L - R) / 2`.
+///
+/// # Panics
+///
+/// Panics unless the buffer has exactly two channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::ms;
+///
+/// let mut buffer = audio::interleaved![[1.0f32, 0.5], [0.0, 0.5]];
+/// ms::encode(&mut buffer);
+///
+/// assert_eq!(buffer.as_slice(), &[0.5, 0.5, 0.5, 0.0]);
+/// ```
+pub fn encode<B>(buf: B)
+where
+    B: ChannelsMut<f32>,
+{
+    transform(buf, |l, r| ((l + r) * 0.5, (l - r) * 0.5));
+}
+
+/// Decode a stereo buffer from mid and side in place into left in the first
+/// channel and right in the second.
+///
+/// This is the inverse of [encode], calculated as `L = M + S` and
+/// `R = M - S`.
+///
+/// # Panics
+///
+/// Panics unless the buffer has exactly two channels.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::ms;
+///
+/// let mut buffer = audio::interleaved![[0.5f32, 0.5], [0.5, 0.0]];
+/// ms::decode(&mut buffer);
+///
+/// assert_eq!(buffer.as_slice(), &[1.0, 0.0, 0.5, 0.5]);
+/// ```
+pub fn decode<B>(buf: B)
+where
+    B: ChannelsMut<f32>,
+{
+    transform(buf, |m, s| (m + s, m - s));
+}
+
+fn transform<B>(mut buf: B, f: impl Fn(f32, f32) -> (f32, f32))
+where
+    B: ChannelsMut<f32>,
+{
+    assert! {
+        buf.channels() == 2,
+        "mid-side processing requires 2 channels, but the buffer has {}",
+        buf.channels()
+    };
+
+    let frames = usize::min(buf.channel(0).frames(), buf.channel(1).frames());
+
+    for n in 0..frames {
+        let (a, b) = f(buf.channel(0)[n], buf.channel(1)[n]);
+        buf.channel_mut(0)[n] = a;
+        buf.channel_mut(1)[n] = b;
+    }
+}
@@ -0,0 +1,285 @@
+//! Conversion between audio buffers and raw PCM bytes.
+//!
+//! Raw PCM is the sample data of a stream without any container around it,
+//! like what's written to a pipe or a socket. The layout of the data is
+//! described by a [PcmFormat].
+
+use crate::dynamic::Dynamic;
+use audio_core::{Channels, ExactSizeBuf, Translate};
+use std::io;
+
+/// The encoding of a single sample in raw PCM data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// 16-bit signed integers.
+    I16,
+    /// 24-bit signed integers, packed into three bytes.
+    I24,
+    /// 32-bit signed integers.
+    I32,
+    /// 32-bit floating point numbers.
+    F32,
+}
+
+impl Encoding {
+    /// The number of bytes used to store a single sample.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::pcm::Encoding;
+    ///
+    /// assert_eq!(Encoding::I16.bytes(), 2);
+    /// assert_eq!(Encoding::I24.bytes(), 3);
+    /// ```
+    pub fn bytes(self) -> usize {
+        match self {
+            Self::I16 => 2,
+            Self::I24 => 3,
+            Self::I32 | Self::F32 => 4,
+        }
+    }
+}
+
+/// The byte order of samples in raw PCM data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// The least significant byte comes first.
+    Little,
+    /// The most significant byte comes first.
+    Big,
+}
+
+/// How the channels of raw PCM data are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// The samples of every frame are stored together, one channel after
+    /// another.
+    Interleaved,
+    /// Every channel is stored in its entirety, one after another.
+    Sequential,
+}
+
+/// The format of raw PCM data.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::pcm::{Encoding, Endianness, Layout, PcmFormat};
+///
+/// let format = PcmFormat::new(Encoding::I24).with_endianness(Endianness::Big);
+///
+/// assert_eq!(format.encoding(), Encoding::I24);
+/// assert_eq!(format.endianness(), Endianness::Big);
+/// assert_eq!(format.layout(), Layout::Interleaved);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PcmFormat {
+    encoding: Encoding,
+    endianness: Endianness,
+    layout: Layout,
+}
+
+impl PcmFormat {
+    /// Construct a format with the given sample `encoding`, which is
+    /// little-endian and interleaved.
+    pub fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            endianness: Endianness::Little,
+            layout: Layout::Interleaved,
+        }
+    }
+
+    /// Modify the byte order of the format.
+    pub fn with_endianness(self, endianness: Endianness) -> Self {
+        Self { endianness, ..self }
+    }
+
+    /// Modify the channel layout of the format.
+    pub fn with_layout(self, layout: Layout) -> Self {
+        Self { layout, ..self }
+    }
+
+    /// The encoding of every sample.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// The byte order of every sample.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// How channels are laid out.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Encode a single sample and append it to `out`.
+    fn encode(&self, s: f32, out: &mut Vec<u8>) {
+        let (bytes, len) = match self.encoding {
+            Encoding::I16 => (i32::from(i16::translate(s)).to_be_bytes(), 2),
+            // NB: the top 24 bits of the 32-bit integer.
+            Encoding::I24 => ((i32::translate(s) >> 8).to_be_bytes(), 3),
+            Encoding::I32 => (i32::translate(s).to_be_bytes(), 4),
+            Encoding::F32 => (s.to_be_bytes(), 4),
+        };
+
+        let bytes = &bytes[4 - len..];
+
+        match self.endianness {
+            Endianness::Little => out.extend(bytes.iter().rev()),
+            Endianness::Big => out.extend_from_slice(bytes),
+        }
+    }
+
+    /// Decode a single sample from its bytes.
+    fn decode(&self, b: &[u8]) -> f32 {
+        let mut bytes = [0u8; 4];
+        let len = b.len();
+
+        // NB: place the bytes in big-endian order at the top of the array,
+        // so that smaller integers are scaled up to 32 bits.
+        for (n, o) in bytes[..len].iter_mut().enumerate() {
+            *o = match self.endianness {
+                Endianness::Little => b[len - 1 - n],
+                Endianness::Big => b[n],
+            };
+        }
+
+        match self.encoding {
+            Encoding::I16 => f32::translate(i16::from_be_bytes([bytes[0], bytes[1]])),
+            Encoding::I24 => i24_to_f32([bytes[0], bytes[1], bytes[2]]),
+            Encoding::I32 => f32::translate(i32::from_be_bytes(bytes)),
+            Encoding::F32 => f32::from_be_bytes(bytes),
+        }
+    }
+}
+
+/// Decode a 24-bit integer sample from its big-endian bytes.
+///
+/// The sample is scaled up to 32 bits and translated from there, so that it
+/// follows the same convention as the other integer encodings.
+pub(crate) fn i24_to_f32(bytes: [u8; 3]) -> f32 {
+    f32::translate(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+/// Encode the buffer as raw PCM bytes in the given `format`.
+///
+/// Samples are translated with saturation, so samples outside of the range
+/// `[-1, 1]` are clipped when encoded as integers. Channels which are shorter
+/// than the buffer are padded with silence.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::pcm::{self, Encoding, Endianness, PcmFormat};
+///
+/// let buffer = audio::dynamic![[0.5f32, 1.0], [-1.0, 0.0]];
+///
+/// let format = PcmFormat::new(Encoding::I16).with_endianness(Endianness::Big);
+/// let bytes = pcm::to_pcm_bytes(&buffer, format);
+///
+/// assert_eq!(bytes, vec![0x3f, 0xff, 0x80, 0x00, 0x7f, 0xff, 0x00, 0x00]);
+/// ```
+pub fn to_pcm_bytes<B>(buf: B, format: PcmFormat) -> Vec<u8>
+where
+    B: Channels<f32> + ExactSizeBuf,
+{
+    let channels = buf.channels();
+    let frames = buf.frames();
+
+    let mut out = Vec::with_capacity(channels * frames * format.encoding.bytes());
+
+    let sample = |chan: usize, frame: usize| {
+        let channel = buf.channel(chan);
+
+        if frame < channel.frames() {
+            channel[frame]
+        } else {
+            0.0
+        }
+    };
+
+    match format.layout {
+        Layout::Interleaved => {
+            for frame in 0..frames {
+                for chan in 0..channels {
+                    format.encode(sample(chan, frame), &mut out);
+                }
+            }
+        }
+        Layout::Sequential => {
+            for chan in 0..channels {
+                for frame in 0..frames {
+                    format.encode(sample(chan, frame), &mut out);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Decode raw PCM `bytes` in the given `format` with the given number of
+/// `channels` into a freshly allocated buffer.
+///
+/// # Errors
+///
+/// Errors with [io::ErrorKind::InvalidData] if the length of `bytes` isn't a
+/// whole number of frames.
+///
+/// # Panics
+///
+/// Panics if `channels` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::pcm::{self, Encoding, PcmFormat};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let bytes = [0x00, 0x40, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x80];
+///
+/// let buffer = pcm::from_pcm_bytes(&bytes, PcmFormat::new(Encoding::I16), 2)?;
+/// assert_eq!(buffer, audio::dynamic![[16384.0 / 32767.0, 0.0], [-0.5, -1.0]]);
+///
+/// assert!(pcm::from_pcm_bytes(&bytes[..7], PcmFormat::new(Encoding::I16), 2).is_err());
+/// # Ok(()) }
+/// ```
+pub fn from_pcm_bytes(
+    bytes: &[u8],
+    format: PcmFormat,
+    channels: usize,
+) -> io::Result<Dynamic<f32>> {
+    assert!(channels != 0, "number of channels must be non-zero");
+
+    let width = format.encoding.bytes();
+    let frame_size = width * channels;
+
+    if bytes.len() % frame_size != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} bytes is not a whole number of {} byte frames",
+                bytes.len(),
+                frame_size
+            ),
+        ));
+    }
+
+    let frames = bytes.len() / frame_size;
+    let mut out = Dynamic::with_topology(channels, frames);
+
+    for (n, b) in bytes.chunks_exact(width).enumerate() {
+        let (chan, frame) = match format.layout {
+            Layout::Interleaved => (n % channels, n / channels),
+            Layout::Sequential => (n / frames, n % frames),
+        };
+
+        out[chan][frame] = format.decode(b);
+    }
+
+    Ok(out)
+}
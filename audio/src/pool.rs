@@ -0,0 +1,147 @@
+//! A pool of audio buffers which can be recycled to avoid allocating.
+
+use crate::dynamic::Dynamic;
+use audio_core::Sample;
+use std::cell::RefCell;
+use std::mem;
+use std::ops;
+
+/// A pool of [Dynamic] buffers which are recycled once they're no longer in
+/// use.
+///
+/// Real-time audio processing should avoid allocating, so once the pool has
+/// warmed up acquiring buffers with the same or a smaller number of channels
+/// and frames will reuse previously allocated buffers.
+///
+/// # Examples
+///
+/// ```rust
+/// let pool = audio::BufferPool::<f32>::new();
+///
+/// let ptr = {
+///     let mut buffer = pool.acquire(2, 256);
+///     buffer[0][0] = 1.0;
+///     buffer[0].as_ptr()
+/// };
+///
+/// let buffer = pool.acquire(2, 128);
+/// assert_eq!(buffer[0].as_ptr(), ptr);
+/// assert_eq!(buffer[0][0], 0.0);
+/// ```
+pub struct BufferPool<T> {
+    free: RefCell<Vec<Dynamic<T>>>,
+}
+
+impl<T> BufferPool<T> {
+    /// Construct a new empty buffer pool.
+    pub fn new() -> Self {
+        Self {
+            free: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Get the number of buffers which are currently free to be reused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let pool = audio::BufferPool::<f32>::new();
+    /// assert_eq!(pool.len(), 0);
+    ///
+    /// drop(pool.acquire(2, 16));
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    /// Test if there are no buffers which are free to be reused.
+    pub fn is_empty(&self) -> bool {
+        self.free.borrow().is_empty()
+    }
+
+    /// Acquire a silent buffer with the given topology.
+    ///
+    /// A free buffer which can hold `channels` channels of `frames` frames
+    /// without reallocating is reused if there is one, otherwise a new buffer
+    /// is allocated. The buffer is returned to the pool once the [PooledBuffer]
+    /// is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::{Buf as _, ExactSizeBuf as _};
+    ///
+    /// let pool = audio::BufferPool::<i16>::new();
+    /// let buffer = pool.acquire(2, 64);
+    ///
+    /// assert_eq!(buffer.channels(), 2);
+    /// assert_eq!(buffer.frames(), 64);
+    /// ```
+    pub fn acquire(&self, channels: usize, frames: usize) -> PooledBuffer<'_, T>
+    where
+        T: Sample,
+    {
+        let buf = {
+            let mut free = self.free.borrow_mut();
+
+            free.iter()
+                .position(|buf| buf.channels_capacity() >= channels && buf.capacity() >= frames)
+                .map(|index| free.swap_remove(index))
+        };
+
+        let buf = match buf {
+            Some(mut buf) => {
+                buf.resize_channels(channels);
+                buf.resize(frames);
+
+                for chan in buf.iter_mut() {
+                    for s in chan {
+                        *s = T::ZERO;
+                    }
+                }
+
+                buf
+            }
+            None => Dynamic::with_topology(channels, frames),
+        };
+
+        PooledBuffer { pool: self, buf }
+    }
+}
+
+impl<T> Default for BufferPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A buffer acquired from a [BufferPool], which is returned to the pool when
+/// dropped.
+///
+/// See [BufferPool::acquire].
+pub struct PooledBuffer<'a, T> {
+    pool: &'a BufferPool<T>,
+    buf: Dynamic<T>,
+}
+
+impl<T> ops::Deref for PooledBuffer<'_, T> {
+    type Target = Dynamic<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buf
+    }
+}
+
+impl<T> ops::DerefMut for PooledBuffer<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buf
+    }
+}
+
+impl<T> Drop for PooledBuffer<'_, T> {
+    fn drop(&mut self) {
+        let buf = mem::take(&mut self.buf);
+        self.pool.free.borrow_mut().push(buf);
+    }
+}
@@ -0,0 +1,147 @@
+//! Processors which can be chained together into effect pipelines.
+//!
+//! A [Processor] modifies a buffer in place, keeping whatever state it needs
+//! across calls so that a signal can be processed block by block. Processors
+//! can be composed into a [Chain] using the [chain!] macro.
+//!
+//! ```rust
+//! use audio::effect::Gain;
+//! use audio::filter::Biquad;
+//! use audio::processor::Processor as _;
+//! use audio::Level;
+//!
+//! let half = Gain::new(Level::from_linear(0.5));
+//! let mut chain = audio::chain![half, Biquad::lowpass(1000.0, 0.707, 44100.0)];
+//! let mut buffer = audio::interleaved![[1.0f32; 256]; 2];
+//!
+//! chain.process(&mut buffer);
+//!
+//! assert!((buffer.frame(0, 255).unwrap() - 0.5).abs() < 0.001);
+//! ```
+
+use audio_core::ChannelsMut;
+
+/// A processor which modifies audio buffers in place.
+///
+/// The buffer is passed in as a trait object so that processors of different
+/// types can be stored together, like in a [Chain].
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::processor::Processor;
+/// use audio::ChannelsMut;
+///
+/// struct Silence;
+///
+/// impl Processor<f32> for Silence {
+///     fn process(&mut self, buf: &mut dyn ChannelsMut<f32>) {
+///         for chan in 0..buf.channels() {
+///             for s in buf.channel_mut(chan).iter_mut() {
+///                 *s = 0.0;
+///             }
+///         }
+///     }
+/// }
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 4]; 2];
+/// Silence.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.0f32; 4]; 2]);
+/// ```
+pub trait Processor<T> {
+    /// Process every channel in `buf` in place.
+    fn process(&mut self, buf: &mut dyn ChannelsMut<T>);
+}
+
+impl<P, T> Processor<T> for &mut P
+where
+    P: ?Sized + Processor<T>,
+{
+    #[inline]
+    fn process(&mut self, buf: &mut dyn ChannelsMut<T>) {
+        (**self).process(buf);
+    }
+}
+
+impl<P, T> Processor<T> for Box<P>
+where
+    P: ?Sized + Processor<T>,
+{
+    #[inline]
+    fn process(&mut self, buf: &mut dyn ChannelsMut<T>) {
+        (**self).process(buf);
+    }
+}
+
+/// A sequence of processors which are run one after another over the same
+/// buffer.
+///
+/// See [chain!] for a convenient way to construct a chain.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::effect::Gain;
+/// use audio::processor::{Chain, Processor as _};
+/// use audio::Level;
+///
+/// let mut chain = Chain::new();
+/// chain.push(Gain::new(Level::from_linear(0.5)));
+/// chain.push(Gain::new(Level::from_linear(0.5)));
+///
+/// let mut buffer = audio::dynamic![[1.0f32; 4]; 2];
+/// chain.process(&mut buffer);
+///
+/// assert_eq!(buffer, audio::dynamic![[0.25f32; 4]; 2]);
+/// ```
+pub struct Chain<T> {
+    processors: Vec<Box<dyn Processor<T>>>,
+}
+
+impl<T> Chain<T> {
+    /// Construct a new empty chain, which leaves buffers untouched.
+    pub fn new() -> Self {
+        Self {
+            processors: Vec::new(),
+        }
+    }
+
+    /// Append a processor to the end of the chain.
+    pub fn push<P>(&mut self, processor: P)
+    where
+        P: 'static + Processor<T>,
+    {
+        self.processors.push(Box::new(processor));
+    }
+
+    /// Get the number of processors in the chain.
+    pub fn len(&self) -> usize {
+        self.processors.len()
+    }
+
+    /// Test if the chain doesn't have any processors.
+    pub fn is_empty(&self) -> bool {
+        self.processors.is_empty()
+    }
+}
+
+impl<T> Default for Chain<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<Box<dyn Processor<T>>>> for Chain<T> {
+    fn from(processors: Vec<Box<dyn Processor<T>>>) -> Self {
+        Self { processors }
+    }
+}
+
+impl<T> Processor<T> for Chain<T> {
+    fn process(&mut self, buf: &mut dyn ChannelsMut<T>) {
+        for processor in &mut self.processors {
+            processor.process(buf);
+        }
+    }
+}
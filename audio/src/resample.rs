@@ -0,0 +1,127 @@
+//! Conversion of audio buffers between sample rates which are related by an
+//! integer factor, like 48 kHz and 24 kHz.
+//!
+//! This is much cheaper than converting between arbitrary sample rates, since
+//! every output frame corresponds directly to an input frame.
+
+use crate::dynamic::Dynamic;
+use crate::filter::Biquad;
+use audio_core::{Channels, ExactSizeBuf};
+
+/// The quality factors of two cascaded biquads making up a fourth order
+/// Butterworth filter.
+const BUTTERWORTH_Q: [f32; 2] = [0.541_196_1, 1.306_563];
+
+/// The cutoff of the lowpass filters relative to the lower of the two sample
+/// rates, which leaves some room for the filters to roll off before Nyquist.
+const CUTOFF: f32 = 0.45;
+
+/// Decimate `src` by an integer `factor`, keeping every `factor`-th frame
+/// starting with the first.
+///
+/// The output has `frames / factor` frames, rounded up.
+///
+/// If `prefilter` is set, the signal is lowpass filtered below the Nyquist
+/// frequency of the new sample rate before it's decimated. Without it, any
+/// content above the new Nyquist frequency is aliased down into the audible
+/// range, so it should only be disabled if the signal is known to be band
+/// limited already.
+///
+/// # Panics
+///
+/// Panics if `factor` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::resample;
+///
+/// let src = audio::interleaved![[1.0f32, 2.0, 3.0, 4.0, 5.0]; 2];
+/// let out = resample::decimate(&src, 2, false);
+///
+/// assert_eq!(out, audio::dynamic![[1.0f32, 3.0, 5.0]; 2]);
+/// ```
+pub fn decimate<B>(src: B, factor: usize, prefilter: bool) -> Dynamic<f32>
+where
+    B: Channels<f32> + ExactSizeBuf,
+{
+    assert!(factor != 0, "decimation factor must be non-zero");
+
+    let mut filtered = crate::buf::to_owned(src);
+
+    if prefilter && factor > 1 {
+        lowpass(&mut filtered, factor);
+    }
+
+    let frames = filtered.frames().div_ceil(factor);
+    let mut out = Dynamic::with_topology(filtered.channels(), frames);
+
+    for (to, from) in out.iter_mut().zip(filtered.iter()) {
+        for (o, s) in to.iter_mut().zip(from.iter().step_by(factor)) {
+            *o = *s;
+        }
+    }
+
+    out
+}
+
+/// Interpolate `src` by an integer `factor`, so that the output has `factor`
+/// times as many frames.
+///
+/// If `filter` is set, `factor - 1` frames of silence are inserted after every
+/// frame and the result is lowpass filtered below the Nyquist frequency of the
+/// original sample rate, which smoothly interpolates between frames.
+/// Otherwise every frame is simply repeated `factor` times, which is cheaper
+/// but introduces images of the signal above the original Nyquist frequency.
+///
+/// # Panics
+///
+/// Panics if `factor` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::resample;
+///
+/// let src = audio::sequential![[1.0f32, 2.0, 3.0]; 2];
+/// let out = resample::interpolate(&src, 2, false);
+///
+/// assert_eq!(out, audio::dynamic![[1.0f32, 1.0, 2.0, 2.0, 3.0, 3.0]; 2]);
+/// ```
+pub fn interpolate<B>(src: B, factor: usize, filter: bool) -> Dynamic<f32>
+where
+    B: Channels<f32> + ExactSizeBuf,
+{
+    assert!(factor != 0, "interpolation factor must be non-zero");
+
+    let mut out = Dynamic::with_topology(src.channels(), src.frames() * factor);
+
+    for (chan, to) in out.iter_mut().enumerate() {
+        for (o, s) in to.chunks_mut(factor).zip(src.channel(chan).iter()) {
+            if filter {
+                // NB: compensate for the energy lost to the inserted silence.
+                o[0] = s * factor as f32;
+            } else {
+                for o in o {
+                    *o = s;
+                }
+            }
+        }
+    }
+
+    if filter && factor > 1 {
+        lowpass(&mut out, factor);
+    }
+
+    out
+}
+
+/// Lowpass filter `buf` below the Nyquist frequency of a sample rate which is
+/// `factor` times lower than its own.
+fn lowpass(buf: &mut Dynamic<f32>, factor: usize) {
+    let cutoff = CUTOFF / factor as f32;
+
+    for q in BUTTERWORTH_Q.iter().copied() {
+        Biquad::lowpass(cutoff, q, 1.0).process(&mut *buf);
+    }
+}
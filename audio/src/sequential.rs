@@ -24,8 +24,8 @@ pub use self::iter::{Iter, IterMut};
 /// worst cases, this might result in having to copy a memory region
 /// byte-by-byte since they might overlap.
 ///
-/// Resized regions also aren't zeroed, so certain operations might cause stale
-/// data to be visible after a resize.
+/// Frames which are sized back in after a resize are zeroed, so stale data
+/// from the shuffled channels doesn't become visible.
 ///
 /// ```rust
 /// let mut buffer = audio::Sequential::<f32>::with_topology(2, 4);
@@ -39,8 +39,8 @@ pub use self::iter::{Iter, IterMut};
 ///
 /// buffer.resize(4);
 ///
-/// assert_eq!(&buffer[0], &[1.0, 2.0, 3.0, 2.0]); // <- 2.0 is stale data.
-/// assert_eq!(&buffer[1], &[2.0, 3.0, 4.0, 5.0]); // <- 5.0 is stale data.
+/// assert_eq!(&buffer[0], &[1.0, 2.0, 3.0, 0.0]);
+/// assert_eq!(&buffer[1], &[2.0, 3.0, 4.0, 0.0]);
 /// ```
 ///
 /// To access the full, currently assumed *valid* slice you can use
@@ -103,23 +103,34 @@ impl<T> Sequential<T> {
         }
     }
 
-    /// Allocate an audio buffer from a fixed-size array.
+    /// Construct an audio buffer from a vector with the given topology,
+    /// without copying.
     ///
-    /// See [sequential!].
+    /// The vector is expected to be stored channel by channel, with all the
+    /// frames of one channel stored before the next channel. Like
+    /// `0:0, 0:1, 1:0, 1:1`. This is the inverse of
+    /// [Sequential::into_raw_parts].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` doesn't match `channels * frames`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut buffer = audio::sequential![[2.0; 256]; 4];
-    ///
-    /// assert_eq!(buffer.frames(), 256);
-    /// assert_eq!(buffer.channels(), 4);
+    /// let buffer = audio::Sequential::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8], 2, 4);
     ///
-    /// for chan in &buffer {
-    ///     assert_eq!(chan, vec![2.0; 256]);
-    /// }
+    /// assert_eq!(buffer, audio::sequential![[1, 2, 3, 4], [5, 6, 7, 8]]);
     /// ```
     pub fn from_vec(data: Vec<T>, channels: usize, frames: usize) -> Self {
+        assert! {
+            Some(data.len()) == channels.checked_mul(frames),
+            "vector of length {} doesn't match the topology {}x{}",
+            data.len(),
+            channels,
+            frames
+        };
+
         Self {
             data,
             channels,
@@ -223,6 +234,9 @@ impl<T> Sequential<T> {
 
     /// Take ownership of the backing vector.
     ///
+    /// The vector is stored channel by channel, with all the frames of one
+    /// channel stored before the next channel. Like `0:0, 0:1, 1:0, 1:1`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -238,6 +252,26 @@ impl<T> Sequential<T> {
         self.data
     }
 
+    /// Take ownership of the backing vector along with the number of channels
+    /// and frames in the buffer, without copying.
+    ///
+    /// The vector is stored channel by channel, with all the frames of one
+    /// channel stored before the next channel. Like `0:0, 0:1, 1:0, 1:1`. The
+    /// buffer can be reconstructed with [Sequential::from_vec].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let buffer = audio::sequential![[1, 2, 3, 4], [5, 6, 7, 8]];
+    /// let (data, channels, frames) = buffer.into_raw_parts();
+    ///
+    /// assert_eq!(data, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!((channels, frames), (2, 4));
+    /// ```
+    pub fn into_raw_parts(self) -> (Vec<T>, usize, usize) {
+        (self.data, self.channels, self.frames)
+    }
+
     /// Access the underlying vector as a slice.
     ///
     /// # Examples
@@ -348,14 +382,15 @@ impl<T> Sequential<T> {
         T: Sample,
     {
         self.resize_inner(self.channels, self.frames, channels, self.frames);
+        audio_core::checks::exact_size(&*self);
     }
 
     /// Set the size of the buffer. The size is the size of each channel's
     /// buffer.
     ///
-    /// If the size of the buffer increases as a result, the new regions in the
-    /// frames will be zeroed. If the size decreases, the region will be left
-    /// untouched. So if followed by another increase, the data will be "dirty".
+    /// If the size of the buffer increases as a result, the new frames will be
+    /// zeroed, even if they were previously sized out. Use
+    /// [Sequential::resize_with] to fill them with another value.
     ///
     /// # Examples
     ///
@@ -375,7 +410,8 @@ impl<T> Sequential<T> {
     /// assert_eq!(buffer.frames(), 256);
     /// ```
     ///
-    /// Decreasing and increasing the size will modify the underlying buffer:
+    /// Decreasing and then increasing the size zeroes the frames which are
+    /// sized back in:
     ///
     /// ```rust
     /// # let mut buffer = audio::Sequential::<f32>::with_topology(4, 256);
@@ -391,12 +427,9 @@ impl<T> Sequential<T> {
     ///
     /// # Stale data
     ///
-    /// Resizing a channel doesn't "free" the underlying data or zero previously
-    /// initialized regions.
-    ///
-    /// Old regions which were previously sized out and ignored might contain
-    /// stale data from previous uses. So this should be kept in mind when
-    /// resizing this buffer dynamically.
+    /// Resizing a channel doesn't "free" the underlying data, but frames which
+    /// are sized back in are always zeroed so that stale data from previous
+    /// uses can't leak into the buffer.
     ///
     /// ```rust
     /// let mut buffer = audio::Sequential::<f32>::new();
@@ -431,26 +464,63 @@ impl<T> Sequential<T> {
     /// assert_eq!(buffer.get(1), Some(&expected[..64]));
     /// assert_eq!(buffer.get(2), None);
     ///
-    /// // increase - this causes some weirdness.
+    /// // increase
     /// buffer.resize(128);
     ///
-    /// let first_overlapping = expected[..64]
+    /// let zero_extended = expected[..64]
     ///     .iter()
-    ///     .chain(expected[..64].iter())
     ///     .copied()
+    ///     .chain(std::iter::repeat(0.0).take(64))
     ///     .collect::<Vec<_>>();
     ///
-    /// assert_eq!(buffer.get(0), Some(&first_overlapping[..]));
-    /// // Note: second channel matches perfectly up with an old channel that was
-    /// // masked out.
-    /// assert_eq!(buffer.get(1), Some(&expected[..]));
+    /// assert_eq!(buffer.get(0), Some(&zero_extended[..]));
+    /// assert_eq!(buffer.get(1), Some(&zero_extended[..]));
     /// assert_eq!(buffer.get(2), None);
     /// ```
     pub fn resize(&mut self, frames: usize)
     where
         T: Sample,
     {
-        self.resize_inner(self.channels, self.frames, self.channels, frames);
+        self.resize_with(frames, T::ZERO);
+    }
+
+    /// Set the size of the buffer, filling any new frames in every channel with
+    /// `value`.
+    ///
+    /// This is the same as [Sequential::resize], except that the frames exposed when
+    /// the buffer grows are set to `value` instead of being zeroed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut buffer = audio::Sequential::<f32>::with_topology(2, 2);
+    ///
+    /// buffer.resize_with(4, 1.0);
+    /// assert_eq!(buffer[0][1], 0.0);
+    /// assert_eq!(buffer[0][2], 1.0);
+    /// assert_eq!(buffer[1][3], 1.0);
+    ///
+    /// buffer.resize(1);
+    /// buffer.resize_with(3, 0.5);
+    /// assert_eq!(buffer[0][1], 0.5);
+    /// assert_eq!(buffer[1][2], 0.5);
+    /// ```
+    pub fn resize_with(&mut self, frames: usize, value: T)
+    where
+        T: Sample,
+    {
+        let from = self.frames;
+        self.resize_inner(self.channels, from, self.channels, frames);
+
+        if from < frames {
+            for chan in 0..self.channels {
+                for s in &mut self.data[chan * frames + from..(chan + 1) * frames] {
+                    *s = value;
+                }
+            }
+        }
+
+        audio_core::checks::exact_size(&*self);
     }
 
     /// Get the capacity of the interleaved buffer in number of frames.
@@ -563,8 +633,11 @@ impl<T> Sequential<T> {
         let old_cap = self.data.capacity();
         let new_len = to_channels * to_frames;
 
+        // NB: growing within the existing capacity doesn't reallocate, and
+        // shrinking only changes the length so that capacity is retained.
         if old_cap < new_len {
-            let additional = new_len - self.data.capacity();
+            // NB: `reserve` is relative to the length and not the capacity.
+            let additional = new_len - self.data.len();
             self.data.reserve(additional);
 
             // zero the additional capacity.
@@ -645,11 +718,17 @@ where
 
 impl<T> hash::Hash for Sequential<T>
 where
-    T: hash::Hash,
+    T: hash::Hash + cmp::Eq,
 {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // NB: needs to hash identically to the other buffer types.
+        self.channels.hash(state);
+        self.frames.hash(state);
+
         for channel in self.iter() {
-            channel.hash(state);
+            for sample in channel {
+                sample.hash(state);
+            }
         }
     }
 }
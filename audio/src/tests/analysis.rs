@@ -0,0 +1,66 @@
+#[test]
+fn test_envelope_step_response() {
+    use crate::analysis;
+
+    const E: f32 = 0.367_879_44;
+
+    let mut buffer = crate::sequential![[0.0f32; 300]; 2];
+
+    for s in &mut buffer[0][..100] {
+        *s = 1.0;
+    }
+
+    for s in &mut buffer[1][..100] {
+        *s = -0.5;
+    }
+
+    // 10 samples of attack and 50 samples of release.
+    let env = analysis::envelope(&buffer, 0.01, 0.05, 1000.0);
+    assert_eq!(env.channels(), 2);
+    assert_eq!(env.frames(), 300);
+
+    for (chan, level) in [(0, 1.0f32), (1, 0.5)].iter().copied() {
+        let env = &env[chan];
+
+        assert!(env[..100].windows(2).all(|w| w[0] < w[1]));
+        assert!(env[100..].windows(2).all(|w| w[0] > w[1]));
+
+        assert!((env[9] - level * (1.0 - E)).abs() < 1e-3);
+        assert!((env[99] - level).abs() < 1e-3);
+        assert!((env[149] - env[99] * E).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_integrated_loudness() {
+    use crate::filter::Biquad;
+    use crate::{analysis, generate};
+
+    // Noise with a high frequency roll-off, calibrated to -20 dBFS RMS.
+    let mut buffer = crate::Dynamic::<f32>::with_topology(2, 48000);
+    generate::white_noise(1.0, 7).fill(&mut buffer);
+    Biquad::lowpass(1000.0, 0.707, 48000.0).process(&mut buffer);
+
+    let square = buffer.iter().flatten().map(|s| s * s).sum::<f32>();
+    let rms = (square / (2.0 * 48000.0)).sqrt();
+
+    for s in buffer.iter_mut().flatten() {
+        *s *= 0.1 / rms;
+    }
+
+    let quiet = analysis::integrated_loudness(&buffer, 48000.0);
+    assert!(quiet > -25.0 && quiet < -15.0, "{}", quiet);
+
+    for s in buffer.iter_mut().flatten() {
+        *s *= 2.0;
+    }
+
+    let loud = analysis::integrated_loudness(&buffer, 48000.0);
+    assert!((loud - quiet - 6.02).abs() < 0.05, "{} {}", quiet, loud);
+
+    let silent = crate::dynamic![[0.0f32; 16]; 2];
+    assert_eq!(
+        analysis::integrated_loudness(&silent, 48000.0),
+        f32::NEG_INFINITY
+    );
+}
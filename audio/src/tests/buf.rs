@@ -0,0 +1,1414 @@
+#[test]
+fn test_map_samples_topology() {
+    use crate::{buf, Channels as _};
+
+    let from = crate::interleaved![[1i16, 2, 3, 4, 5]; 3];
+    let to = buf::map_samples(&from, |s| s as f32 * 0.5);
+
+    assert_eq!(to.channels(), 3);
+    assert_eq!(to.frames(), 5);
+
+    for chan in 0..3 {
+        assert!(to.channel(chan).iter().eq([0.5, 1.0, 1.5, 2.0, 2.5]));
+    }
+
+    let from = crate::sequential![[0u8; 0]; 4];
+    let to = buf::map_samples(&from, |s| s as i16);
+    assert_eq!(to.channels(), 4);
+    assert_eq!(to.frames(), 0);
+}
+
+#[test]
+fn test_map_in_place_negate() {
+    use crate::buf;
+
+    let mut buffer = crate::interleaved![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+    buf::map_in_place(&mut buffer, |s| -s);
+    assert_eq!(buffer.as_slice(), &[-1, -5, -2, -6, -3, -7, -4, -8]);
+
+    let mut buffer = crate::sequential![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+    buf::map_in_place(&mut buffer, |s| -s);
+    assert_eq!(buffer.as_slice(), &[-1, -2, -3, -4, -5, -6, -7, -8]);
+
+    let mut buffer = crate::dynamic![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+    buf::map_in_place(&mut buffer, |s| -s);
+    assert_eq!(buffer, crate::dynamic![[-1, -2, -3, -4], [-5, -6, -7, -8]]);
+}
+
+#[test]
+fn test_hash_across_layouts() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut state = DefaultHasher::new();
+        value.hash(&mut state);
+        state.finish()
+    }
+
+    let dynamic = crate::dynamic![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+    let sequential = crate::sequential![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+    let interleaved = crate::interleaved![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+
+    assert_eq!(hash(&dynamic), hash(&sequential));
+    assert_eq!(hash(&dynamic), hash(&interleaved));
+
+    // Same data, different topology.
+    let other = crate::interleaved![[1i16, 2], [3, 4], [5, 6], [7, 8]];
+    assert_ne!(hash(&sequential), hash(&other));
+
+    let mut cache = HashMap::new();
+    cache.insert(dynamic, "clip");
+    assert_eq!(
+        cache.get(&crate::dynamic![[1i16, 2, 3, 4], [5, 6, 7, 8]]),
+        Some(&"clip")
+    );
+    assert_eq!(
+        cache.get(&crate::dynamic![[1i16, 2, 3, 4], [5, 6, 7, 9]]),
+        None
+    );
+}
+
+#[test]
+fn test_resize_steady_state_does_not_reallocate() {
+    use crate::ResizableBuf;
+
+    fn check<B>(mut buf: B, ptr: impl Fn(&B) -> (*const f32, usize))
+    where
+        B: ResizableBuf,
+    {
+        // Warm up.
+        buf.resize_topology(2, 1024);
+        let expected = ptr(&buf);
+
+        for _ in 0..4 {
+            buf.resize(256);
+            assert_eq!(ptr(&buf), expected);
+            buf.resize(1024);
+            assert_eq!(ptr(&buf), expected);
+            buf.resize(512);
+            assert_eq!(ptr(&buf), expected);
+        }
+    }
+
+    check(crate::Interleaved::<f32>::new(), |b| {
+        (b.as_slice().as_ptr(), b.capacity())
+    });
+    check(crate::Sequential::<f32>::new(), |b| {
+        (b.as_slice().as_ptr(), b.capacity())
+    });
+    check(crate::Dynamic::<f32>::new(), |b| {
+        (b[0].as_ptr(), b.capacity())
+    });
+}
+
+#[test]
+fn test_resize_grow_past_capacity_after_shrink() {
+    let mut buf = crate::Sequential::<f32>::with_topology(2, 64);
+    buf.resize(16);
+    buf.resize(1024);
+    assert_eq!(buf.as_slice().len(), 2048);
+    assert!(buf.as_slice().iter().all(|s| *s == 0.0));
+
+    let mut buf = crate::Interleaved::<f32>::with_topology(2, 64);
+    buf.resize(16);
+    buf.resize(1024);
+    assert_eq!(buf.as_slice().len(), 2048);
+    assert!(buf.as_slice().iter().all(|s| *s == 0.0));
+}
+
+#[test]
+fn test_copy_layouts() {
+    use crate::buf;
+
+    let sequential = crate::sequential![[1i16, 2, 3], [4, 5, 6]];
+    let interleaved = crate::interleaved![[1i16, 2, 3], [4, 5, 6]];
+
+    let mut to = crate::sequential![[0i16; 3]; 2];
+    buf::copy(&sequential, &mut to);
+    assert_eq!(to, sequential);
+
+    let mut to = crate::sequential![[0i16; 3]; 2];
+    buf::copy(&interleaved, &mut to);
+    assert_eq!(to, sequential);
+
+    let mut to = crate::interleaved![[0i16; 3]; 2];
+    buf::copy(&sequential, &mut to);
+    assert_eq!(to, interleaved);
+
+    let mut to = crate::interleaved![[0i16; 3]; 2];
+    buf::copy(&interleaved, &mut to);
+    assert_eq!(to, interleaved);
+}
+
+#[test]
+fn test_copy_mismatched_topologies() {
+    use crate::buf;
+
+    let from = crate::interleaved![[1i16, 2, 3, 4], [5, 6, 7, 8]];
+
+    // More channels and fewer frames in the destination.
+    let mut to = crate::sequential![[0i16; 2]; 3];
+    buf::copy(&from, &mut to);
+    assert_eq!(to.as_slice(), &[1, 2, 5, 6, 0, 0]);
+
+    // Fewer channels and more frames in the destination.
+    let mut to = crate::dynamic![[0i16; 6]; 1];
+    buf::copy(&from, &mut to);
+    assert_eq!(to, crate::dynamic![[1, 2, 3, 4, 0, 0]]);
+
+    let from = crate::sequential![[1i16, 2], [3, 4], [5, 6]];
+    let mut to = crate::interleaved![[0i16; 3]; 2];
+    buf::copy(&from, &mut to);
+    assert_eq!(to.as_slice(), &[1, 3, 2, 4, 0, 0]);
+}
+
+#[test]
+fn test_copy_layouts_large() {
+    use crate::{buf, Channels as _};
+
+    // Large enough to span several blocks, with a partial block at the end.
+    let mut sequential = crate::sequential![[0u32; 150]; 3];
+
+    for (chan, channel) in sequential.iter_mut().enumerate() {
+        for (frame, s) in channel.iter_mut().enumerate() {
+            *s = (chan * 1000 + frame) as u32;
+        }
+    }
+
+    let mut interleaved = crate::interleaved![[0u32; 150]; 3];
+    buf::copy(&sequential, &mut interleaved);
+
+    for frame in 0..150 {
+        for chan in 0..3 {
+            assert_eq!(
+                interleaved.channel(chan)[frame],
+                (chan * 1000 + frame) as u32
+            );
+        }
+    }
+
+    let mut to = crate::interleaved![[0u32; 150]; 3];
+    buf::copy(&interleaved, &mut to);
+    assert_eq!(to, interleaved);
+
+    let mut to = crate::dynamic![[0u32; 150]; 3];
+    buf::copy(&interleaved, &mut to);
+    assert_eq!(to, buf::to_owned(&sequential));
+
+    let mut to = crate::sequential![[0u32; 150]; 3];
+    buf::copy(&sequential, &mut to);
+    assert_eq!(to, sequential);
+}
+
+#[test]
+fn test_copy_interleaved_mismatched_channels() {
+    use crate::{buf, Buf as _};
+
+    let from = crate::interleaved![[1i16, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+    let mut to = crate::interleaved![[0i16; 4]; 2];
+    buf::copy(&from, &mut to);
+    assert_eq!(to.as_slice(), &[1, 4, 2, 5, 3, 6, 0, 0]);
+
+    let mut to = crate::interleaved![[0i16; 2]; 4];
+    buf::copy(&from, &mut to);
+    assert_eq!(to.as_slice(), &[1, 4, 7, 0, 2, 5, 8, 0]);
+
+    // Adapters which shift the frames of an interleaved buffer.
+    let mut to = crate::interleaved![[0i16; 3]; 3];
+    buf::copy((&from).skip(1), &mut to);
+    assert_eq!(to.as_slice(), &[2, 5, 8, 3, 6, 9, 0, 0, 0]);
+}
+
+#[test]
+fn test_overlap_add_hann_reconstruction() {
+    use crate::buf;
+    use std::f32::consts::PI;
+
+    const BLOCK: usize = 64;
+    const HOP: usize = BLOCK / 2;
+
+    let signal = (0..1024)
+        .map(|n| (n as f32 * 0.03).sin() * 0.8)
+        .collect::<Vec<_>>();
+
+    // Periodic hann window, which sums to one at 50% overlap.
+    let window = (0..BLOCK)
+        .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f32 / BLOCK as f32).cos())
+        .collect::<Vec<_>>();
+
+    let blocks = (0..=(signal.len() - BLOCK) / HOP)
+        .map(|b| crate::generate!(|_, f| signal[b * HOP + f] * window[f]; [BLOCK]; 2))
+        .collect::<Vec<_>>();
+
+    let out = buf::overlap_add(&blocks, HOP);
+    assert_eq!(out.channels(), 2);
+    assert_eq!(out.frames(), signal.len());
+
+    // The first and last half block are only covered by one window.
+    for chan in 0..2 {
+        for n in HOP..signal.len() - HOP {
+            assert!((out[chan][n] - signal[n]).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_overlap_add_saturates_integers() {
+    use crate::buf;
+
+    let a = crate::dynamic![[30000i16; 4]];
+    let b = crate::dynamic![[30000i16; 4]];
+
+    let out = buf::overlap_add(&[a, b], 2);
+    assert_eq!(
+        out,
+        crate::dynamic![[30000i16, 30000, i16::MAX, i16::MAX, 30000, 30000]]
+    );
+}
+
+#[test]
+fn test_invert_right_channel() {
+    use crate::buf;
+
+    let mut buffer = crate::sequential![[0.5f32, -1.0, 0.25], [0.5, -1.0, 0.25]];
+    buf::invert_channels(&mut buffer, &[1]);
+    assert_eq!(buffer.as_slice(), &[0.5, -1.0, 0.25, -0.5, 1.0, -0.25]);
+
+    let mut buffer = crate::interleaved![[0u8, 128, 255], [0, 128, 255]];
+    buf::invert_channels(&mut buffer, &[1]);
+    assert_eq!(buffer.as_slice(), &[0, 255, 128, 128, 255, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_invert_out_of_bounds() {
+    let mut buffer = crate::sequential![[0.5f32; 4]; 2];
+    crate::buf::invert_channels(&mut buffer, &[2]);
+}
+
+#[test]
+fn test_vec_round_trip() {
+    let buffer = crate::interleaved![[1i16, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let (data, channels, frames) = buffer.into_raw_parts();
+    let ptr = data.as_ptr();
+    let buffer = crate::Interleaved::from_vec(data, channels, frames);
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+    assert_eq!(
+        buffer,
+        crate::interleaved![[1i16, 2, 3], [4, 5, 6], [7, 8, 9]]
+    );
+
+    let buffer = crate::sequential![[1i16, 2, 3], [4, 5, 6]];
+    let (data, channels, frames) = buffer.into_raw_parts();
+    let ptr = data.as_ptr();
+    let buffer = crate::Sequential::from_vec(data, channels, frames);
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+    assert_eq!(buffer, crate::sequential![[1i16, 2, 3], [4, 5, 6]]);
+
+    let buffer = crate::sequential![[1i16, 2, 3], [4, 5, 6]];
+    let buffer = crate::Sequential::from_vec(buffer.into_vec(), 3, 2);
+    assert_eq!(buffer, crate::sequential![[1i16, 2], [3, 4], [5, 6]]);
+}
+
+#[test]
+#[should_panic]
+fn test_from_vec_mismatched_topology() {
+    let _ = crate::Interleaved::from_vec(vec![0i16; 7], 2, 4);
+}
+
+#[test]
+fn test_to_owned_frame_range() {
+    use crate::{buf, Buf as _};
+
+    let from = crate::sequential![[0, 1, 2, 3, 4, 5, 6, 7], [10, 11, 12, 13, 14, 15, 16, 17]];
+    let to = buf::to_owned((&from).skip(2).limit(4));
+
+    assert_eq!(to.channels(), 2);
+    assert_eq!(to.frames(), 4);
+    assert_eq!(to, crate::dynamic![[2, 3, 4, 5], [12, 13, 14, 15]]);
+
+    let from = crate::interleaved![[0, 1, 2, 3, 4, 5, 6, 7], [10, 11, 12, 13, 14, 15, 16, 17]];
+    let to = buf::to_owned((&from).skip(2).limit(4));
+    assert_eq!(to, crate::dynamic![[2, 3, 4, 5], [12, 13, 14, 15]]);
+
+    let to = buf::to_owned((&from).skip(6).limit(4));
+    assert_eq!(to, crate::dynamic![[6, 7], [16, 17]]);
+}
+
+#[test]
+fn test_for_each_frame_rotation() {
+    use crate::buf;
+
+    let (sin, cos) = std::f32::consts::FRAC_PI_2.sin_cos();
+
+    let mut buffer = crate::interleaved![[1.0f32, 0.0, 0.5], [0.0, 1.0, 0.5]];
+
+    buf::for_each_frame(&mut buffer, |frame| {
+        let (x, y) = (frame[0], frame[1]);
+        frame[0] = x * cos - y * sin;
+        frame[1] = x * sin + y * cos;
+    });
+
+    let expected = [(0.0, 1.0), (-1.0, 0.0), (-0.5, 0.5)];
+
+    for (n, (x, y)) in expected.iter().enumerate() {
+        assert!((buffer.frame(0, n).unwrap() - x).abs() < 1e-6);
+        assert!((buffer.frame(1, n).unwrap() - y).abs() < 1e-6);
+    }
+
+    let mut buffer = crate::sequential![[1i16, 2], [3, 4], [5, 6]];
+    buf::for_each_frame(&mut buffer, |frame| frame.reverse());
+    assert_eq!(buffer, crate::sequential![[5i16, 6], [3, 4], [1, 2]]);
+}
+
+#[test]
+fn test_for_each_frame_many_channels() {
+    use crate::buf;
+
+    let mut buffer = crate::Dynamic::<u32>::with_topology(40, 3);
+    buf::for_each_frame(&mut buffer, |frame| {
+        for (chan, s) in frame.iter_mut().enumerate() {
+            *s = chan as u32;
+        }
+    });
+
+    for chan in 0..40 {
+        assert_eq!(buffer[chan], [chan as u32; 3]);
+    }
+}
+
+#[test]
+fn test_correlation() {
+    use crate::buf;
+    use rand::Rng as _;
+
+    let mut buffer = crate::Dynamic::<f32>::with_topology(4, 4096);
+
+    let mut rng = rand::thread_rng();
+    rng.fill(&mut buffer[0][..]);
+    rng.fill(&mut buffer[2][..]);
+
+    for n in 0..4096 {
+        buffer[1][n] = -buffer[0][n];
+    }
+
+    assert!((buf::correlation(&buffer, 0, 0) - 1.0).abs() < 1e-6);
+    assert!((buf::correlation(&buffer, 0, 1) + 1.0).abs() < 1e-6);
+    assert!(buf::correlation(&buffer, 0, 2).abs() < 0.1);
+
+    // Silent channels have no variance.
+    assert_eq!(buf::correlation(&buffer, 0, 3), 0.0);
+    assert_eq!(buf::correlation(&buffer, 3, 3), 0.0);
+}
+
+#[test]
+fn test_approx_eq() {
+    use crate::{buf, ApproxEq as _};
+
+    assert!(1.0f32.approx_eq(1.0 + 0.0009, 0.001));
+    assert!(!1.0f32.approx_eq(1.0 + 0.0011, 0.001));
+    assert!(1.0f64.approx_eq(1.0 - 0.0009, 0.001));
+    assert!(!1.0f64.approx_eq(1.0 - 0.0011, 0.001));
+    assert!(!f32::NAN.approx_eq(f32::NAN, 1.0));
+
+    assert!(1000i16.approx_eq(1000, 0));
+    assert!(!1000i16.approx_eq(1001, 100));
+    assert!(!200u8.approx_eq(201, 255));
+
+    let a = crate::dynamic![[1.0f32, 2.0], [3.0, 4.0]];
+    let b = crate::interleaved![[1.0009f32, 2.0], [3.0, 3.9991]];
+    let c = crate::interleaved![[1.0011f32, 2.0], [3.0, 4.0]];
+
+    assert!(buf::approx_eq(&a, &b, 0.001));
+    assert!(!buf::approx_eq(&a, &c, 0.001));
+
+    // Topologies have to match.
+    assert!(!buf::approx_eq(&a, crate::dynamic![[1.0f32, 2.0]], 0.001));
+    assert!(!buf::approx_eq(&a, crate::dynamic![[1.0f32], [3.0]], 0.001));
+
+    let a = crate::sequential![[1i16, 2, 3]; 2];
+    assert!(buf::approx_eq(&a, crate::interleaved![[1i16, 2, 3]; 2], 0));
+    assert!(!buf::approx_eq(&a, crate::interleaved![[1i16, 2, 4]; 2], 5));
+}
+
+#[test]
+fn test_cycle_wraps_around() {
+    use crate::{Buf as _, ExactSizeBuf as _};
+
+    let from = crate::sequential![[1, 2, 3, 4], [5, 6, 7, 8]];
+    let cycle = (&from).cycle(10);
+
+    assert_eq!(cycle.channels(), 2);
+    assert_eq!(cycle.frames(), 10);
+
+    let expected = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2];
+
+    for (n, e) in expected.iter().enumerate() {
+        assert_eq!(cycle.frame(0, n), Some(*e));
+        assert_eq!(cycle.frame(1, n), Some(*e + 4));
+    }
+
+    assert_eq!(cycle.frame(0, 10), None);
+
+    let mut to = crate::interleaved![[0; 10]; 2];
+    cycle.copy_into(&mut to);
+    assert_eq!(
+        to,
+        crate::interleaved![
+            [1, 2, 3, 4, 1, 2, 3, 4, 1, 2],
+            [5, 6, 7, 8, 5, 6, 7, 8, 5, 6]
+        ]
+    );
+}
+
+#[test]
+fn test_cycle_empty_is_silent() {
+    use crate::{Buf as _, ExactSizeBuf as _};
+
+    let from = crate::interleaved![[0i16; 0]; 2];
+    let cycle = (&from).cycle(3);
+
+    assert_eq!(cycle.frames(), 3);
+    assert_eq!(cycle.frame(1, 2), Some(0));
+
+    let mut to = crate::interleaved![[1i16; 3]; 2];
+    cycle.copy_into(&mut to);
+    assert_eq!(to, crate::interleaved![[0i16; 3]; 2]);
+}
+
+#[test]
+fn test_headroom_half_scale() {
+    use crate::buf;
+
+    let buffer = crate::generate!(|_, f| 0.5 * (f as f32 * 0.1).sin().signum(); [64]; 2);
+
+    assert!((buf::dbfs_peak(&buffer) + 6.0206).abs() < 0.01);
+    assert!((buf::headroom_db(&buffer) - 6.0206).abs() < 0.01);
+
+    // The peak is taken across all channels.
+    let mut buffer = crate::interleaved![[0.25f32; 8]; 2];
+    *buffer.frame_mut(1, 5).unwrap() = -0.5;
+    assert!((buf::headroom_db(&buffer) - 6.0206).abs() < 0.01);
+
+    let buffer = crate::dynamic![[0.0f32; 8]; 2];
+    assert_eq!(buf::headroom_db(&buffer), f32::INFINITY);
+    assert_eq!(buf::dbfs_peak(&buffer), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_range_matches_skip_limit() {
+    use crate::{Buf as _, Channels as _, ExactSizeBuf as _};
+
+    let interleaved =
+        crate::interleaved![[1, 2, 3, 4, 5, 6, 7, 8], [9, 10, 11, 12, 13, 14, 15, 16]];
+    let sequential = crate::sequential![[1, 2, 3, 4, 5, 6, 7, 8], [9, 10, 11, 12, 13, 14, 15, 16]];
+
+    let range = (&interleaved).range(2, 6);
+    let manual = (&interleaved).skip(2).limit(4);
+    assert_eq!(range.frames(), manual.frames());
+
+    for chan in 0..2 {
+        assert!(range.channel(chan).iter().eq(manual.channel(chan).iter()));
+    }
+
+    let range = (&sequential).range(2, 6);
+    let manual = (&sequential).skip(2).limit(4);
+    assert_eq!(range.frames(), manual.frames());
+
+    for chan in 0..2 {
+        assert!(range.channel(chan).iter().eq(manual.channel(chan).iter()));
+    }
+
+    assert!((&sequential).range(6, 100).channel(1).iter().eq([15, 16]));
+    assert_eq!((&sequential).range(6, 2).frames(), 0);
+    assert_eq!((&sequential).range(6, 2).channel(0).iter().count(), 0);
+}
+
+#[test]
+fn test_range_mut() {
+    use crate::{buf, Buf as _};
+
+    let mut buffer = crate::sequential![[0; 8]; 2];
+    buf::map_in_place((&mut buffer).range(2, 4), |_| 1);
+    assert_eq!(
+        buffer.as_slice(),
+        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_find_and_replace_non_finite() {
+    use crate::buf;
+
+    let mut buffer = crate::sequential![[0.5f32; 16]; 3];
+    assert_eq!(buf::find_non_finite(&buffer), None);
+
+    buffer[2][11] = f32::NAN;
+    assert_eq!(buf::find_non_finite(&buffer), Some((2, 11)));
+
+    buffer[2][4] = f32::NEG_INFINITY;
+    assert_eq!(buf::find_non_finite(&buffer), Some((2, 4)));
+
+    buf::replace_non_finite(&mut buffer, -1.0);
+    assert_eq!(buf::find_non_finite(&buffer), None);
+    assert_eq!(buffer[2][11], -1.0);
+    assert_eq!(buffer[2][4], -1.0);
+    assert_eq!(buffer[1][11], 0.5);
+}
+
+#[test]
+fn test_waveshape_identity_and_hard_clip() {
+    use crate::buf;
+
+    let input = [-1.5f32, -1.0, -0.8, -0.33, 0.0, 0.1, 0.5, 0.9, 1.0, 2.0];
+
+    let identity = (0..=16).map(|n| n as f32 / 8.0 - 1.0).collect::<Vec<_>>();
+    let mut buffer = crate::sequential![[0.0f32; 10]; 2];
+    buffer[0].copy_from_slice(&input);
+    buffer[1].copy_from_slice(&input);
+    buf::waveshape(&mut buffer, &identity);
+
+    for chan in 0..2 {
+        for (s, x) in buffer[chan].iter().zip(input.iter()) {
+            let expected = x.clamp(-1.0, 1.0);
+            assert!((s - expected).abs() < 1e-6, "{} != {}", s, expected);
+        }
+    }
+
+    let hard_clip = [-0.5f32, -0.5, 0.0, 0.5, 0.5];
+    let mut buffer = crate::interleaved![[0.0f32; 10]; 1];
+    buffer.as_slice_mut().copy_from_slice(&input);
+    buf::waveshape(&mut buffer, &hard_clip);
+
+    for (s, x) in buffer.as_slice().iter().zip(input.iter()) {
+        let expected = x.clamp(-0.5, 0.5);
+        assert!((s - expected).abs() < 1e-6, "{} != {}", s, expected);
+    }
+}
+
+#[test]
+fn test_pad_to_power_of_two() {
+    use crate::buf;
+
+    let mut from = crate::sequential![[0.0f32; 100]; 2];
+
+    for chan in 0..2 {
+        for (n, s) in from[chan].iter_mut().enumerate() {
+            *s = (n + chan * 100 + 1) as f32;
+        }
+    }
+
+    let frames = buf::next_power_of_two_frames(&from);
+    assert_eq!(frames, 128);
+
+    let to = buf::pad_to(&from, frames);
+    assert_eq!(to.frames(), 128);
+
+    for chan in 0..2 {
+        assert_eq!(&to[chan][..100], &from[chan][..]);
+        assert!(to[chan][100..].iter().all(|s| *s == 0.0));
+    }
+}
+
+#[test]
+fn test_shares_storage() {
+    use crate::{buf, wrap, Buf as _};
+
+    let interleaved = crate::interleaved![[0i16; 16]; 2];
+    assert!(buf::shares_storage(&interleaved, (&interleaved).skip(8)));
+    assert!(buf::shares_storage(
+        &interleaved,
+        (&interleaved).range(2, 4)
+    ));
+
+    let sequential = crate::sequential![[0i16; 16]; 2];
+    assert!(!buf::shares_storage(&interleaved, &sequential));
+
+    // Disjoint views into the same storage don't share.
+    let data = [0i16; 16];
+    let first = wrap::sequential(&data[..8], 2);
+    let second = wrap::sequential(&data[8..], 2);
+    assert!(!buf::shares_storage(&first, &second));
+    assert!(buf::shares_storage(&first, wrap::sequential(&data[..], 4)));
+
+    // Empty views never share.
+    assert!(!buf::shares_storage(&interleaved, (&interleaved).skip(16)));
+}
+
+#[test]
+fn test_calibrate() {
+    use crate::buf;
+
+    let offsets = [0.1f32, -0.2, 0.0];
+    let gains = [2.0f32, 0.5, -1.0];
+
+    let mut buffer = crate::sequential![[0.0f32; 16]; 3];
+
+    for chan in 0..3 {
+        for (n, s) in buffer[chan].iter_mut().enumerate() {
+            *s = (n as f32 / 16.0).sin() + offsets[chan];
+        }
+    }
+
+    buf::calibrate(&mut buffer, &offsets, &gains);
+
+    for chan in 0..3 {
+        for (n, s) in buffer[chan].iter().enumerate() {
+            let expected = (n as f32 / 16.0).sin() * gains[chan];
+            assert!((s - expected).abs() < 1e-6, "{} != {}", s, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_calibrate_mismatched_channels() {
+    let mut buffer = crate::sequential![[0.0f32; 16]; 2];
+    crate::buf::calibrate(&mut buffer, &[0.0, 0.0], &[1.0]);
+}
+
+#[cfg(feature = "fft")]
+#[test]
+fn test_spectrum_single_bin_sine() {
+    use crate::buf;
+    use std::f32::consts::PI;
+
+    const FRAMES: usize = 64;
+    const BIN: usize = 4;
+
+    let mut buffer = crate::sequential![[0.0f32; FRAMES]; 2];
+
+    for (n, s) in buffer[1].iter_mut().enumerate() {
+        *s = (2.0 * PI * (BIN * n) as f32 / FRAMES as f32).sin();
+    }
+
+    let spectrum = buf::spectrum(&buffer, 1);
+    assert_eq!(spectrum.len(), FRAMES);
+
+    for (k, bin) in spectrum.iter().enumerate() {
+        if k == BIN || k == FRAMES - BIN {
+            assert!((bin.norm() - FRAMES as f32 / 2.0).abs() < 1e-3, "bin {}", k);
+        } else {
+            assert!(bin.norm() < 1e-3, "bin {}: {}", k, bin.norm());
+        }
+    }
+
+    assert!(buf::spectrum(&buffer, 0)
+        .iter()
+        .all(|bin| bin.norm() == 0.0));
+}
+
+#[test]
+fn test_render_mono_to_stereo() {
+    use crate::buf::{self, Downmix};
+
+    let src = crate::sequential![[1i16, 2, 3, 4]];
+    let mut out = [0i16; 12];
+
+    // The output is longer than the source, so the tail is silenced.
+    let frames = buf::render_to_interleaved(&src, &mut out, 2, Downmix::Drop);
+    assert_eq!(frames, 6);
+    assert_eq!(out, [1, 1, 2, 2, 3, 3, 4, 4, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_render_quad_to_stereo() {
+    use crate::buf::{self, Downmix};
+
+    let src = crate::interleaved![[0.5f32, 0.5], [-0.5, 1.0], [0.25, 0.5], [0.5, 0.0]];
+
+    let mut out = [0.0f32; 5];
+    let frames = buf::render_to_interleaved(&src, &mut out, 2, Downmix::Average);
+    assert_eq!(frames, 2);
+    // NB: the trailing partial frame is untouched.
+    assert_eq!(out, [0.375, 0.0, 0.5, 0.5, 0.0]);
+
+    let mut out = [0.0f32; 4];
+    buf::render_to_interleaved(&src, &mut out, 2, Downmix::Drop);
+    assert_eq!(out, [0.5, -0.5, 0.5, 1.0]);
+
+    // Upmixing a non-mono source silences the extra channels.
+    let mut out = [1.0f32; 12];
+    buf::render_to_interleaved(&src, &mut out, 6, Downmix::Average);
+    assert_eq!(
+        out,
+        [0.5, -0.5, 0.25, 0.5, 0.0, 0.0, 0.5, 1.0, 0.5, 0.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+fn test_stereo_from_mono_ramps() {
+    use crate::buf;
+
+    let mut left = crate::sequential![[0.0f32; 32]];
+    let mut right = crate::sequential![[0.0f32; 24]];
+
+    for (n, s) in left[0].iter_mut().enumerate() {
+        *s = n as f32 / 32.0;
+    }
+
+    for (n, s) in right[0].iter_mut().enumerate() {
+        *s = -(n as f32) / 24.0;
+    }
+
+    let stereo = buf::stereo_from_mono(&left, &right);
+    assert_eq!(stereo.channels(), 2);
+    assert_eq!(stereo.frames(), 32);
+
+    assert_eq!(&stereo[0], &left[0]);
+    assert_eq!(&stereo[1][..24], &right[0]);
+    assert!(stereo[1][24..].iter().all(|s| *s == 0.0));
+}
+
+#[test]
+#[should_panic]
+fn test_stereo_from_mono_multi_channel() {
+    let left = crate::sequential![[0.0f32; 4]; 2];
+    let right = crate::sequential![[0.0f32; 4]];
+    crate::buf::stereo_from_mono(&left, &right);
+}
+
+#[test]
+fn test_split_stereo_round_trip() {
+    use crate::buf;
+
+    let mut stereo = crate::sequential![[0.0f32; 32]; 2];
+
+    for (n, s) in stereo[0].iter_mut().enumerate() {
+        *s = n as f32;
+    }
+
+    for (n, s) in stereo[1].iter_mut().enumerate() {
+        *s = -(n as f32);
+    }
+
+    let (left, right) = buf::split_stereo(&stereo);
+    assert_eq!(&left[0], &stereo[0]);
+    assert_eq!(&right[0], &stereo[1]);
+
+    let combined = buf::stereo_from_mono(&left, &right);
+    assert_eq!(buf::to_owned(&stereo), combined);
+}
+
+#[test]
+#[should_panic]
+fn test_split_stereo_mono() {
+    crate::buf::split_stereo(crate::dynamic![[0.0f32; 4]]);
+}
+
+#[test]
+fn test_channel_to_owned() {
+    use crate::{buf, Channels as _};
+
+    let mut from = crate::interleaved![[0i16; 16]; 2];
+
+    for (n, s) in from.as_slice_mut().iter_mut().enumerate() {
+        *s = n as i16;
+    }
+
+    let right = buf::channel_to_owned(&from, 1);
+    assert_eq!(right.channels(), 1);
+    assert_eq!(right.frames(), 16);
+    assert!(right.channel(0).iter().eq(from.channel(1).iter()));
+
+    // Round trip through a split and merge.
+    let left = buf::channel_to_owned(&from, 0);
+    let merged = buf::stereo_from_mono(&left, &right);
+    assert!(merged.channel(0).iter().eq(from.channel(0).iter()));
+    assert!(merged.channel(1).iter().eq(from.channel(1).iter()));
+}
+
+#[test]
+#[should_panic]
+fn test_channel_to_owned_out_of_bounds() {
+    let from = crate::interleaved![[0i16; 16]; 2];
+    crate::buf::channel_to_owned(&from, 2);
+}
+
+#[test]
+fn test_channels_iter() {
+    use crate::{Channels as _, ChannelsMut};
+
+    fn sums(buf: &impl crate::Channels<i32>) -> Vec<i32> {
+        buf.channels_iter().map(|c| c.iter().sum()).collect()
+    }
+
+    fn fill(mut buf: impl ChannelsMut<i32>) -> usize {
+        let mut channels = buf.channels_iter_mut();
+        let len = channels.len();
+        let mut n = 0;
+
+        while let Some(channel) = channels.next() {
+            n += 1;
+
+            for s in channel.iter_mut() {
+                *s = n;
+            }
+        }
+
+        assert!(channels.is_empty());
+        len
+    }
+
+    let mut interleaved = crate::interleaved![[0; 4]; 3];
+    let mut sequential = crate::sequential![[0; 4]; 3];
+    let mut dynamic = crate::dynamic![[0; 4]; 3];
+
+    assert_eq!(interleaved.channels_iter().len(), 3);
+
+    // Every channel is mutated independently.
+    assert_eq!(fill(&mut interleaved), 3);
+    assert_eq!(fill(&mut sequential), 3);
+    assert_eq!(fill(&mut dynamic), 3);
+
+    assert_eq!(sums(&interleaved), vec![4, 8, 12]);
+    assert_eq!(sums(&sequential), vec![4, 8, 12]);
+    assert_eq!(sums(&dynamic), vec![4, 8, 12]);
+    assert_eq!(
+        interleaved.as_slice(),
+        &[1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]
+    );
+
+    for (a, b) in interleaved.channels_iter().zip(sequential.channels_iter()) {
+        assert_eq!(a, b);
+    }
+
+    // Trait objects are iterated through a reference.
+    let buf: &dyn crate::Channels<i32> = &dynamic;
+    assert_eq!((&buf).channels_iter().count(), 3);
+
+    // Adapters only hand out their own part of every channel.
+    let mut buffer = crate::sequential![[0; 4]; 3];
+    fill(crate::Buf::skip(&mut buffer, 2));
+    assert_eq!(buffer.as_slice(), &[0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 3, 3]);
+}
+
+#[test]
+fn test_fade_in_out() {
+    use crate::buf;
+
+    let mut buffer = crate::sequential![[0.5f32; 64]; 2];
+    buf::fade_in(&mut buffer, 16);
+    buf::fade_out(&mut buffer, 16);
+
+    for chan in 0..2 {
+        let channel = &buffer[chan];
+
+        assert!(channel[0].abs() < 1e-6);
+        assert!(channel[63].abs() < 1e-6);
+        assert!(channel[..17].windows(2).all(|w| w[0] < w[1]));
+        assert!(channel[47..].windows(2).all(|w| w[0] > w[1]));
+        assert!(channel[16..48].iter().all(|s| *s == 0.5));
+
+        // Equal power: halfway through the fade the gain is sqrt(1/2).
+        assert!((channel[8] - 0.5 * 0.5f32.sqrt()).abs() < 1e-6);
+        assert!((channel[55] - 0.5 * 0.5f32.sqrt()).abs() < 1e-6);
+    }
+
+    // Fades longer than the buffer are clamped.
+    let mut buffer = crate::interleaved![[1.0f32; 4]; 1];
+    buf::fade_in(&mut buffer, 100);
+    assert!(buffer.as_slice().windows(2).all(|w| w[0] < w[1]));
+    assert!((buffer.as_slice()[2] - 0.5f32.sqrt()).abs() < 1e-6);
+}
+
+#[test]
+fn test_fade_to_gain() {
+    use crate::buf;
+
+    let mut buffer = crate::sequential![[1.0f32; 100]; 2];
+    buf::fade_to_gain(&mut buffer, 0.0, -20.0);
+
+    for chan in 0..2 {
+        let channel = &buffer[chan];
+
+        assert!((channel[0] - 0.1).abs() < 1e-6);
+        assert!(channel.windows(2).all(|w| w[0] < w[1]));
+
+        // Halfway through the ramp the gain is halfway in decibels, which is
+        // the geometric rather than the arithmetic mean of the endpoints.
+        assert!((channel[50] - 10f32.powf(-10.0 / 20.0)).abs() < 1e-5);
+
+        for (n, s) in channel.iter().enumerate() {
+            let db = 20.0 * s.log10();
+            assert!((db - (-20.0 + 20.0 * n as f32 / 100.0)).abs() < 1e-3);
+        }
+    }
+
+    // Fading in from silence.
+    let mut buffer = crate::interleaved![[0.5f32; 8]; 1];
+    buf::fade_to_gain(&mut buffer, 0.0, f32::NEG_INFINITY);
+    assert_eq!(buffer.as_slice()[0], 0.0);
+    assert!(buffer.as_slice().iter().all(|s| s.is_finite()));
+    assert!((buffer.as_slice()[4] - 0.5 * 10f32.powf(-60.0 / 20.0)).abs() < 1e-6);
+
+    // Fading out to silence.
+    let mut buffer = crate::interleaved![[0.5f32; 8]; 1];
+    buf::fade_to_gain(&mut buffer, f32::NEG_INFINITY, 0.0);
+    assert_eq!(buffer.as_slice()[0], 0.5);
+    assert!(buffer.as_slice().windows(2).all(|w| w[0] > w[1]));
+}
+
+#[test]
+fn test_clamp() {
+    use crate::buf;
+
+    let mut buffer = crate::interleaved![[0.0f32; 4]; 2];
+    buffer
+        .as_slice_mut()
+        .copy_from_slice(&[-2.0, 0.25, 0.5, 1.5, 1.0, -1.0, 3.0, -0.75]);
+
+    buf::clamp(&mut buffer, -1.0, 1.0);
+
+    assert_eq!(
+        buffer.as_slice(),
+        &[-1.0, 0.25, 0.5, 1.0, 1.0, -1.0, 1.0, -0.75]
+    );
+
+    let mut buffer = crate::sequential![[0i16, 100, -100, 20000]; 2];
+    buf::clamp(&mut buffer, -50, 10000);
+    assert_eq!(&buffer[1], &[0, 100, -50, 10000]);
+}
+
+#[test]
+fn test_apply_mask() {
+    use crate::{Buf as _, ChannelMask};
+
+    let buf = crate::sequential![[1, 2], [3, 4], [5, 6]];
+
+    let render = |mask: &ChannelMask| {
+        let mut to = crate::sequential![[-1; 2]; 3];
+        (&buf).apply_mask(mask).copy_into(&mut to);
+        to
+    };
+
+    // Mute one channel.
+    let mut mask = ChannelMask::new();
+    mask.mute(1);
+    assert_eq!(render(&mask), crate::sequential![[1, 2], [0, 0], [5, 6]]);
+
+    // Solo one channel.
+    let mut mask = ChannelMask::new();
+    mask.solo(2);
+    assert_eq!(render(&mask), crate::sequential![[0, 0], [0, 0], [5, 6]]);
+
+    // Solo overrides mute, both for the soloed channel and for the others.
+    let mut mask = ChannelMask::new();
+    mask.mute(0);
+    mask.mute(1);
+    mask.solo(1);
+    assert_eq!(render(&mask), crate::sequential![[0, 0], [3, 4], [0, 0]]);
+
+    mask.unsolo(1);
+    assert_eq!(render(&mask), crate::sequential![[0, 0], [0, 0], [5, 6]]);
+
+    // The underlying buffer is untouched.
+    assert_eq!(buf, crate::sequential![[1, 2], [3, 4], [5, 6]]);
+}
+
+#[test]
+fn test_apply_mask_channels() {
+    use crate::{buf, Buf as _, ChannelMask, Channels as _};
+
+    let buf = crate::interleaved![[1, 2, 3], [4, 5, 6]];
+
+    let mut mask = ChannelMask::new();
+    mask.solo(1);
+
+    let masked = (&buf).apply_mask(&mask);
+    assert_eq!(masked.channels(), 2);
+    assert!(masked.channel(0).iter().eq([0, 0, 0]));
+    assert_eq!(masked.channel(1), buf.channel(1));
+
+    // Masked buffers compose with other adapters and the buffer utilities.
+    let mut to = crate::sequential![[-1; 2]; 2];
+    buf::copy((&masked).skip(1), &mut to);
+    assert_eq!(to, crate::sequential![[0, 0], [5, 6]]);
+
+    let mut to = crate::dynamic![[0.0f32; 3]; 2];
+    buf::translate(&masked, &mut to);
+    assert_eq!(to[0], [0.0, 0.0, 0.0]);
+    assert!(to[1][0] > 0.0);
+}
+
+#[test]
+fn test_channel_mask_many_channels() {
+    use crate::ChannelMask;
+
+    let mut mask = ChannelMask::new();
+    mask.mute(200);
+    assert!(mask.is_audible(199));
+    assert!(!mask.is_audible(200));
+    assert!(mask.is_audible(201));
+
+    mask.solo(130);
+    assert!(mask.is_audible(130));
+    assert!(!mask.is_audible(0));
+
+    // Clearing state which was never set is fine.
+    mask.unmute(1000);
+    mask.unsolo(1000);
+    assert!(mask.is_audible(130));
+}
+
+#[test]
+fn test_apply_gain_envelope() {
+    use crate::buf;
+
+    // A ramp per channel, with a different slope for every channel.
+    let mut buffer = crate::sequential![[2.0f32; 8]; 2];
+    let env = crate::generate!(|c, f| (f * (c + 1)) as f32 / 8.0; [8]; 2);
+
+    buf::apply_gain_envelope(&mut buffer, &env);
+
+    for chan in 0..2 {
+        for f in 0..8 {
+            assert_eq!(buffer[chan][f], 2.0 * (f * (chan + 1)) as f32 / 8.0);
+        }
+    }
+
+    // A shorter mono envelope is broadcast across channels, and frames past its
+    // end are left untouched.
+    let mut buffer = crate::interleaved![[1.0f32; 4]; 3];
+    let env = crate::dynamic![[0.5f32, -1.0]];
+
+    buf::apply_gain_envelope(&mut buffer, &env);
+
+    assert_eq!(buffer, crate::interleaved![[0.5f32, -1.0, 1.0, 1.0]; 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_apply_gain_envelope_channel_mismatch() {
+    let mut buffer = crate::interleaved![[1.0f32; 4]; 3];
+    crate::buf::apply_gain_envelope(&mut buffer, crate::dynamic![[1.0f32; 4]; 2]);
+}
+
+#[test]
+fn test_normalize_true_peak() {
+    use crate::buf;
+    use std::f32::consts::PI;
+
+    // A tone at a quarter of the sample rate, phase shifted so that every
+    // sample lands at sqrt(1/2) of the amplitude while the waveform itself
+    // peaks in between samples.
+    let tone = |f: usize| 0.8 * (PI * (f as f32 / 2.0 + 0.25)).sin();
+
+    let mut buffer = crate::generate!(|_, f| tone(f); [1024]; 2);
+    let sample_peak = buf::dbfs_peak(&buffer);
+    assert!((sample_peak - 20.0 * (0.8 * 0.5f32.sqrt()).log10()).abs() < 1e-3);
+
+    buf::normalize_true_peak(&mut buffer, -1.0);
+
+    // Oversample the middle of the normalized signal, where the edges don't
+    // matter, by reconstructing it at four times the sample rate.
+    let gain = buffer[0][0] / tone(0);
+    let mut true_peak = 0.0f32;
+
+    for n in (400 * 4)..(600 * 4) {
+        let t = n as f32 / 4.0;
+        true_peak = f32::max(
+            true_peak,
+            (gain * 0.8 * (PI * (t / 2.0 + 0.25)).sin()).abs(),
+        );
+    }
+
+    // NB: the abrupt start and end of the signal ring a little when
+    // reconstructed, which makes the estimate slightly conservative.
+    let true_peak = 20.0 * true_peak.log10();
+    assert!(true_peak <= -1.0, "{}", true_peak);
+    assert!(true_peak >= -1.0 - 0.25, "{}", true_peak);
+
+    // Plain sample peak normalization would have overshot by 3 dB.
+    assert!(buf::dbfs_peak(&buffer) <= -1.0 - 3.0103);
+
+    // Silence is left untouched.
+    let mut buffer = crate::sequential![[0.0f32; 16]; 2];
+    buf::normalize_true_peak(&mut buffer, 0.0);
+    assert!(buf::is_silent(&buffer));
+}
+
+#[test]
+fn test_with_gain() {
+    use crate::{Buf as _, ExactSizeBuf as _};
+
+    let buf = crate::sequential![[0.5f32, -1.0, 0.25, 2.0], [1.0, 0.0, -0.5, 4.0]];
+    let gained = (&buf).with_gain(0.5);
+
+    assert_eq!(gained.channels(), 2);
+    assert_eq!(gained.frames(), 4);
+    assert!(gained.iter(0).eq([0.25, -0.5, 0.125, 1.0]));
+    assert!(gained.iter(1).eq([0.5, 0.0, -0.25, 2.0]));
+
+    // Views compose with other combinators.
+    let mut to = crate::interleaved![[0.0f32; 2]; 2];
+    (&buf).skip(2).with_gain(0.5).copy_into(&mut to);
+    assert_eq!(to, crate::interleaved![[0.125, 1.0], [-0.25, 2.0]]);
+
+    // The source is untouched.
+    assert_eq!(
+        buf,
+        crate::sequential![[0.5f32, -1.0, 0.25, 2.0], [1.0, 0.0, -0.5, 4.0]]
+    );
+}
+
+#[test]
+fn test_crest_factor() {
+    use crate::buf;
+    use std::f32::consts::PI;
+
+    // Whole periods of a sine tone in the first channel, and a square wave in
+    // the second.
+    let buffer = crate::generate!(|c, f| match c {
+        0 => (2.0 * PI * f as f32 / 64.0).sin() * 0.5,
+        _ => if f % 64 < 32 { 0.5 } else { -0.5 },
+    }; [4096]; 2);
+
+    let crest = buf::crest_factor(&buffer);
+
+    assert_eq!(crest.len(), 2);
+    assert!((crest[0] - 3.0103).abs() < 0.01, "{}", crest[0]);
+    assert!(crest[1].abs() < 1e-4, "{}", crest[1]);
+
+    let silent = crate::dynamic![[0.0f32; 16]; 2];
+    assert_eq!(buf::crest_factor(&silent), vec![0.0, 0.0]);
+
+    let empty = crate::Dynamic::<f32>::with_topology(1, 0);
+    assert_eq!(buf::crest_factor(&empty), vec![0.0]);
+}
+
+#[test]
+fn test_interleave_sources() {
+    use crate::buf;
+
+    let a = crate::dynamic![[1.0f32, 2.0, 3.0]];
+    let b = crate::sequential![[4.0f32, 5.0]];
+    let c = crate::interleaved![[7.0f32, 8.0, 9.0]];
+
+    let mut out = [-1.0f32; 9];
+    let frames = buf::interleave_sources(&[&a, &b, &c], &mut out);
+
+    assert_eq!(frames, 3);
+    assert_eq!(out, [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 0.0, 9.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_interleave_sources_mismatched_output() {
+    let a = crate::dynamic![[0.0f32; 4]];
+    let b = crate::dynamic![[0.0f32; 4]];
+    let mut out = [0.0f32; 7];
+    crate::buf::interleave_sources(&[&a, &b], &mut out);
+}
+
+#[test]
+fn test_take_channels() {
+    use crate::{Buf as _, Channels as _, ChannelsMut as _, ExactSizeBuf as _};
+
+    let mut buffer = crate::sequential![[1.0f32; 4], [2.0; 4], [3.0; 4], [4.0; 4]];
+
+    {
+        let mut view = (&mut buffer).take_channels(2);
+        assert_eq!(view.channels(), 2);
+        assert_eq!(view.frames(), 4);
+
+        for chan in 0..view.channels() {
+            for s in view.channel_mut(chan).iter_mut() {
+                *s *= 10.0;
+            }
+        }
+    }
+
+    assert_eq!(
+        buffer,
+        crate::sequential![[10.0f32; 4], [20.0; 4], [3.0; 4], [4.0; 4]]
+    );
+
+    let view = (&buffer).take_channels(2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        view.channel(2);
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_smooth_one_pole_step() {
+    use crate::buf;
+
+    let mut buffer = crate::interleaved![[0.0f32; 64]; 2];
+
+    for chan in 0..2 {
+        for f in 1..64 {
+            *buffer.frame_mut(chan, f).unwrap() = 1.0;
+        }
+    }
+
+    buf::smooth_one_pole(&mut buffer, 0.1);
+
+    for chan in 0..2 {
+        assert_eq!(buffer.frame(chan, 0), Some(0.0));
+
+        // After the step, the distance to the target shrinks by a factor of
+        // `1 - coeff` with every sample.
+        for f in 1..64 {
+            let expected = 1.0 - 0.9f32.powi(f as i32);
+            assert!((buffer.frame(chan, f).unwrap() - expected).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_smooth_one_pole_invalid_coeff() {
+    crate::buf::smooth_one_pole(&mut crate::dynamic![[0.0f32; 4]], 0.0);
+}
+
+#[test]
+fn test_stats_consistency() {
+    use crate::buf;
+    use std::f32::consts::PI;
+
+    let buffer = crate::generate!(|c, f| match c {
+        0 => (2.0 * PI * f as f32 / 32.0).sin() * 0.5 + 0.25,
+        _ => if f % 2 == 0 { 0.75 } else { -0.25 },
+    }; [256]; 2);
+
+    let stats = buf::stats(&buffer);
+    let crest = buf::crest_factor(&buffer);
+
+    for chan in 0..2 {
+        let channel = &buffer[chan];
+
+        let peak = channel.iter().fold(0.0f32, |p, s| f32::max(p, s.abs()));
+        let rms = (channel.iter().map(|s| s * s).sum::<f32>() / 256.0).sqrt();
+        let mean = channel.iter().sum::<f32>() / 256.0;
+
+        assert!((stats.peak()[chan] - peak).abs() < 1e-5);
+        assert!((stats.rms()[chan] - rms).abs() < 1e-5);
+        assert!((stats.dc_offset()[chan] - mean).abs() < 1e-5);
+        assert!(
+            (20.0 * (stats.peak()[chan] / stats.rms()[chan]).log10() - crest[chan]).abs() < 1e-4
+        );
+        assert_eq!(stats.non_finite()[chan], 0);
+    }
+
+    let peak = f32::max(stats.peak()[0], stats.peak()[1]);
+    assert!((20.0 * peak.log10() - buf::dbfs_peak(&buffer)).abs() < 1e-5);
+
+    assert!((stats.dc_offset()[0] - 0.25).abs() < 1e-5);
+    assert!((stats.dc_offset()[1] - 0.25).abs() < 1e-5);
+
+    let empty = crate::Dynamic::<f32>::with_topology(2, 0);
+    let stats = buf::stats(&empty);
+    assert_eq!(stats.peak(), &[0.0, 0.0]);
+    assert_eq!(stats.rms(), &[0.0, 0.0]);
+    assert_eq!(stats.dc_offset(), &[0.0, 0.0]);
+    assert_eq!(stats.non_finite(), &[0, 0]);
+}
+
+#[cfg(feature = "debug-checks")]
+#[test]
+#[should_panic(expected = "channel 1 has 3 frames, but the buffer reports 4")]
+fn test_debug_checks_ragged_buffer() {
+    use crate::{Buf, Channel, Channels, ExactSizeBuf};
+
+    // A buffer which claims to have an exact size, but has a short channel.
+    struct Inconsistent(Vec<Vec<f32>>);
+
+    impl Buf for Inconsistent {
+        fn frames_hint(&self) -> Option<usize> {
+            Some(4)
+        }
+
+        fn channels(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl ExactSizeBuf for Inconsistent {
+        fn frames(&self) -> usize {
+            4
+        }
+    }
+
+    impl Channels<f32> for Inconsistent {
+        fn channel(&self, channel: usize) -> Channel<'_, f32> {
+            Channel::linear(&self.0[channel])
+        }
+    }
+
+    let buf = Inconsistent(vec![vec![0.0; 4], vec![0.0; 3]]);
+    crate::buf::to_owned(&buf);
+}
+
+#[cfg(feature = "debug-checks")]
+#[test]
+#[should_panic(expected = "channel 0 has 3 frames, but the buffer reports at most 2")]
+fn test_debug_checks_copy() {
+    use crate::{Buf, Channel, Channels};
+
+    // A buffer which hints at fewer frames than its channels have.
+    struct Inconsistent(Vec<Vec<i16>>);
+
+    impl Buf for Inconsistent {
+        fn frames_hint(&self) -> Option<usize> {
+            Some(2)
+        }
+
+        fn channels(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl Channels<i16> for Inconsistent {
+        fn channel(&self, channel: usize) -> Channel<'_, i16> {
+            Channel::linear(&self.0[channel])
+        }
+    }
+
+    let buf = Inconsistent(vec![vec![0; 3], vec![0; 3]]);
+
+    let mut to = crate::interleaved![[0i16; 3]; 2];
+    crate::buf::copy(&buf, &mut to);
+}
+
+#[cfg(feature = "debug-checks")]
+#[test]
+#[should_panic(expected = "channel 1 has 4 frames, but the buffer reports at most 3")]
+fn test_debug_checks_translate() {
+    use crate::{Buf, Channel, Channels};
+
+    // A buffer with a long channel.
+    struct Inconsistent(Vec<Vec<i16>>);
+
+    impl Buf for Inconsistent {
+        fn frames_hint(&self) -> Option<usize> {
+            Some(3)
+        }
+
+        fn channels(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl Channels<i16> for Inconsistent {
+        fn channel(&self, channel: usize) -> Channel<'_, i16> {
+            Channel::linear(&self.0[channel])
+        }
+    }
+
+    let buf = Inconsistent(vec![vec![0; 3], vec![0; 4]]);
+
+    let mut to = crate::dynamic![[0.0f32; 3]; 2];
+    crate::buf::translate(&buf, &mut to);
+}
+
+#[cfg(feature = "debug-checks")]
+#[test]
+#[should_panic(expected = "isn't a whole number of frames")]
+fn test_debug_checks_interleaved_stride() {
+    crate::Channel::interleaved(&[0.0f32; 5], 2, 1);
+}
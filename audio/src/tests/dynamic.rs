@@ -143,7 +143,7 @@ fn test_enabled_mut() {
 }
 
 #[test]
-fn test_stale_allocation() {
+fn test_resize_zeroes_stale_allocation() {
     let mut buffer = crate::Dynamic::<f32>::with_topology(4, 256);
     assert_eq!(buffer[1][128], 0.0);
     buffer[1][128] = 42.0;
@@ -152,7 +152,23 @@ fn test_stale_allocation() {
     assert!(buffer[1].get(128).is_none());
 
     buffer.resize(256);
-    assert_eq!(buffer[1][128], 42.0);
+    assert_eq!(buffer[1][128], 0.0);
+}
+
+#[test]
+fn test_resize_with() {
+    let mut buffer = crate::Dynamic::<f32>::with_topology(2, 2);
+    buffer[0].copy_from_slice(&[1.0, 2.0]);
+    buffer[1].copy_from_slice(&[3.0, 4.0]);
+
+    buffer.resize_with(4, 0.5);
+    assert_eq!(&buffer[0], &[1.0, 2.0, 0.5, 0.5]);
+    assert_eq!(&buffer[1], &[3.0, 4.0, 0.5, 0.5]);
+
+    buffer.resize(1);
+    buffer.resize(3);
+    assert_eq!(&buffer[0], &[1.0, 0.0, 0.0]);
+    assert_eq!(&buffer[1], &[3.0, 0.0, 0.0]);
 }
 
 #[test]
@@ -201,3 +217,177 @@ fn test_resize_topology() {
     buffer.resize(20480);
     buffer.resize_channels(1);
 }
+
+#[test]
+fn test_reserve_then_resize_does_not_reallocate() {
+    let mut buffer = crate::Dynamic::<f32>::with_capacity(2, 4);
+    buffer.reserve(1000);
+
+    let cap = buffer.capacity();
+    assert!(cap >= 1000);
+
+    let ptr = buffer[0].as_ptr();
+
+    for frames in (0..=cap).step_by(10) {
+        buffer.resize(frames);
+        assert_eq!(buffer.capacity(), cap);
+        assert_eq!(buffer[0].as_ptr(), ptr);
+    }
+
+    buffer.reserve(cap);
+    assert!(buffer.capacity() >= buffer.frames() + cap);
+}
+
+#[test]
+fn test_push_frame() {
+    let mut buffer = crate::Dynamic::<i16>::with_capacity(2, 0);
+
+    for n in 0..100 {
+        buffer.push_frame(&[n, -n]);
+    }
+
+    let mut expected = crate::Dynamic::<i16>::with_topology(2, 100);
+
+    for n in 0..100 {
+        expected[0][n] = n as i16;
+        expected[1][n] = -(n as i16);
+    }
+
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+#[should_panic]
+fn test_push_frame_wrong_channels() {
+    let mut buffer = crate::Dynamic::<i16>::with_capacity(2, 4);
+    buffer.push_frame(&[1, 2, 3]);
+}
+
+#[test]
+fn test_from_interleaved_round_trip() {
+    let data = [1i16, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12];
+    let buffer = crate::Dynamic::from_interleaved(&data, 3);
+
+    assert_eq!(buffer.channels(), 3);
+    assert_eq!(buffer.frames(), 4);
+    assert_eq!(buffer[0], [1, 2, 3, 4]);
+    assert_eq!(buffer[1], [5, 6, 7, 8]);
+    assert_eq!(buffer[2], [9, 10, 11, 12]);
+
+    let mut out = crate::Interleaved::with_topology(3, 4);
+    crate::buf::copy(&buffer, &mut out);
+    assert_eq!(out.as_slice(), &data[..]);
+
+    let buffer = crate::Dynamic::<i16>::from_interleaved(&[], 2);
+    assert_eq!(buffer.channels(), 2);
+    assert_eq!(buffer.frames(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_from_interleaved_uneven() {
+    let _ = crate::Dynamic::from_interleaved(&[1i16, 2, 3, 4, 5], 2);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_cast() {
+    use crate::{Channels as _, ExactSizeBuf as _};
+
+    let mut buffer = crate::dynamic![[0.0f32; 4]; 2];
+    buffer[0].copy_from_slice(&[1.0, -0.5, f32::MAX, 0.25]);
+    buffer[1].copy_from_slice(&[-1.0, 0.5, f32::MIN, -0.25]);
+
+    let expected = buffer
+        .iter()
+        .map(|chan| chan.iter().map(|s| s.to_bits()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut cast = buffer.cast::<u32>().unwrap();
+    assert_eq!(cast.channels(), 2);
+    assert_eq!(cast.frames(), 4);
+    assert_eq!(&cast[0], &expected[0][..]);
+    assert_eq!(&cast[1], &expected[1][..]);
+
+    // The cast buffer is fully usable.
+    cast.resize(8);
+    assert_eq!(&cast[1][4..], &[0; 4]);
+
+    let back = cast.cast::<f32>().unwrap();
+    assert_eq!(back[0][..4], [1.0, -0.5, f32::MAX, 0.25]);
+
+    // Differently sized types can't be cast.
+    let buffer = crate::dynamic![[1i16; 4]; 2];
+    let buffer = buffer.cast::<u32>().unwrap_err();
+    assert_eq!(buffer, crate::dynamic![[1i16; 4]; 2]);
+}
+
+#[test]
+fn test_into_interleaved_and_sequential() {
+    let ramp = |c: usize, f: usize| (c * 100 + f) as i16;
+    let buffer: crate::Dynamic<i16> = crate::generate!(ramp; [5]; 3);
+
+    let interleaved = crate::buf::to_owned(&buffer).into_interleaved();
+    assert_eq!(interleaved.channels(), 3);
+    assert_eq!(interleaved.frames(), 5);
+    assert_eq!(&interleaved.as_slice()[..6], &[0, 100, 200, 1, 101, 201]);
+
+    let sequential = crate::buf::to_owned(&buffer).into_sequential();
+    assert_eq!(sequential.channels(), 3);
+    assert_eq!(sequential.frames(), 5);
+    assert_eq!(&sequential.as_slice()[..6], &[0, 1, 2, 3, 4, 100]);
+
+    // Round trip back into a dynamic buffer.
+    let from_interleaved = crate::Dynamic::from_interleaved(interleaved.as_slice(), 3);
+    assert_eq!(from_interleaved, buffer);
+
+    let from_sequential = crate::buf::to_owned(&sequential);
+    assert_eq!(from_sequential, buffer);
+
+    // Mono buffers reuse their allocation.
+    let mut mono = crate::dynamic![[1.0f32, 2.0, 3.0]; 1];
+    let ptr = mono[0].as_ptr();
+    mono.resize(2);
+
+    let interleaved = mono.into_interleaved();
+    assert_eq!(interleaved.as_slice().as_ptr(), ptr);
+    assert_eq!(interleaved.as_slice(), &[1.0, 2.0]);
+
+    let empty = crate::Dynamic::<f32>::with_topology(0, 4).into_sequential();
+    assert_eq!(empty.channels(), 0);
+    assert_eq!(empty.as_slice(), &[]);
+}
+
+#[test]
+fn test_with_alignment() {
+    use crate::Channels as _;
+
+    let is_aligned =
+        |buffer: &crate::Dynamic<f32>| buffer.iter().all(|c| c.as_ptr() as usize % 32 == 0);
+
+    let mut buffer = crate::Dynamic::<f32>::with_alignment(4, 100, 32);
+    assert_eq!(buffer.alignment(), 32);
+    assert!(is_aligned(&buffer));
+
+    buffer[1][99] = 1.0;
+
+    // Growing frames and channels reallocates with the same alignment.
+    buffer.resize(1000);
+    buffer.resize_channels(9);
+    assert!(is_aligned(&buffer));
+    assert_eq!(buffer[1][99], 1.0);
+    assert!(buffer[1][100..].iter().all(|s| *s == 0.0));
+    assert!(buffer[8].iter().all(|s| *s == 0.0));
+
+    let vectors = buffer.into_vectors_if(|n| n != 0);
+    assert_eq!(vectors.len(), 9);
+    assert!(vectors[0].is_empty());
+    assert_eq!(vectors[1][99], 1.0);
+    assert_eq!(vectors[1].len(), 1000);
+
+    let empty = crate::Dynamic::<f32>::with_alignment(2, 0, 64);
+    assert!(empty.iter().all(|c| c.as_ptr() as usize % 64 == 0));
+
+    let natural = crate::Dynamic::<f32>::with_topology(2, 4);
+    assert_eq!(natural.alignment(), std::mem::align_of::<f32>());
+}
@@ -0,0 +1,67 @@
+#[test]
+fn test_delay_impulse_across_blocks() {
+    use crate::effect::Delay;
+
+    let mut delay = Delay::new(64);
+    let mut output = Vec::new();
+
+    let mut block = crate::interleaved![[0.0f32; 16]; 2];
+    *block.frame_mut(0, 0).unwrap() = 1.0;
+    *block.frame_mut(1, 0).unwrap() = -1.0;
+
+    for _ in 0..8 {
+        delay.process(&mut block, 20, 0.5, 0.5);
+        output.extend_from_slice(block.as_slice());
+        block.as_slice_mut().iter_mut().for_each(|s| *s = 0.0);
+    }
+
+    let frame = |n: usize| &output[n * 2..n * 2 + 2];
+
+    assert_eq!(frame(0), &[0.5, -0.5]);
+    assert_eq!(frame(20), &[0.5, -0.5]);
+    assert_eq!(frame(40), &[0.25, -0.25]);
+    assert_eq!(frame(60), &[0.125, -0.125]);
+
+    let silent = (1..output.len() / 2).filter(|n| n % 20 != 0);
+
+    for n in silent {
+        assert_eq!(frame(n), &[0.0, 0.0]);
+    }
+}
+
+#[test]
+fn test_compressor_ratio() {
+    use crate::effect::Compressor;
+
+    let mut compressor = Compressor::new(1000.0);
+    compressor.set_threshold_db(-20.0);
+    compressor.set_ratio(4.0);
+    compressor.set_attack(0.005);
+    compressor.set_release(0.05);
+
+    // A quiet signal below the threshold is left untouched.
+    let mut block = crate::interleaved![[0.05f32; 32]; 2];
+    compressor.process(&mut block);
+    assert!(block.as_slice().iter().all(|s| (s - 0.05).abs() < 1e-6));
+
+    // A signal at 0 dBFS is 20 dB above the threshold, so once the attack has
+    // settled it comes out 5 dB above it. The quieter channel is reduced by
+    // the same amount since the compressor is linked.
+    let expected = 10.0f32.powf(-15.0 / 20.0);
+    let mut last = [0.0f32; 2];
+
+    for _ in 0..8 {
+        let mut block = crate::sequential![[1.0f32; 32], [-0.5; 32]];
+        compressor.process(&mut block);
+        last = [block[0][31], block[1][31]];
+    }
+
+    assert!((last[0] - expected).abs() < 1e-3, "{:?}", last);
+    assert!((last[1] + expected * 0.5).abs() < 1e-3, "{:?}", last);
+
+    // Makeup gain is applied after compression.
+    compressor.set_makeup(crate::Level::from_db(15.0));
+    let mut block = crate::sequential![[1.0f32; 32]; 1];
+    compressor.process(&mut block);
+    assert!(block[0].iter().all(|s| (s - 1.0).abs() < 1e-3));
+}
@@ -0,0 +1,137 @@
+#[test]
+fn test_biquad_highpass_removes_dc() {
+    use crate::filter::Biquad;
+
+    let mut filter = Biquad::highpass(50.0, 0.707, 48000.0);
+    let mut buffer = crate::sequential![[0.5f32; 48000]; 2];
+
+    filter.process(&mut buffer);
+
+    for chan in 0..2 {
+        assert!(buffer[chan][48000 - 1].abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_biquad_state_persists_across_blocks() {
+    use crate::filter::Biquad;
+    use crate::Buf as _;
+
+    let input = (0..1024)
+        .map(|n| ((n as f32) * 0.05).sin())
+        .collect::<Vec<_>>();
+
+    let mut whole = crate::Dynamic::<f32>::with_topology(2, input.len());
+    let mut blocks = crate::Dynamic::<f32>::with_topology(2, input.len());
+
+    for chan in 0..2 {
+        whole[chan].copy_from_slice(&input);
+        blocks[chan].copy_from_slice(&input);
+    }
+
+    Biquad::lowpass(2000.0, 0.707, 44100.0).process(&mut whole);
+
+    let mut filter = Biquad::lowpass(2000.0, 0.707, 44100.0);
+
+    for n in 0..(input.len() / 128) {
+        filter.process((&mut blocks).skip(n * 128).limit(128));
+    }
+
+    assert_eq!(whole, blocks);
+}
+
+#[test]
+fn test_fir_identity() {
+    use crate::filter::Fir;
+
+    let mut filter = Fir::new(vec![1.0]);
+    let mut buffer = crate::interleaved![[0.5f32, -1.0, 0.25, 0.0, 1.0]; 3];
+
+    filter.process(&mut buffer);
+    filter.process(&mut buffer);
+
+    assert_eq!(
+        buffer,
+        crate::interleaved![[0.5f32, -1.0, 0.25, 0.0, 1.0]; 3]
+    );
+}
+
+#[test]
+fn test_fir_moving_average() {
+    use crate::filter::Fir;
+    use crate::Buf as _;
+
+    let input = [
+        0.0f32, 4.0, 0.0, 4.0, 0.0, 4.0, 0.0, 4.0, 8.0, 8.0, 8.0, 8.0,
+    ];
+
+    let mut whole = crate::Dynamic::<f32>::with_topology(2, input.len());
+    let mut blocks = crate::Dynamic::<f32>::with_topology(2, input.len());
+
+    for chan in 0..2 {
+        whole[chan].copy_from_slice(&input);
+        blocks[chan].copy_from_slice(&input);
+    }
+
+    Fir::new(vec![0.25; 4]).process(&mut whole);
+
+    // Once the history is primed, the alternating signal is smoothed into its
+    // mean, and the step up is spread out over four samples.
+    assert_eq!(
+        &whole[1],
+        &[0.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 2.0, 4.0, 5.0, 7.0, 8.0]
+    );
+
+    // History is carried across blocks.
+    let mut filter = Fir::new(vec![0.25; 4]);
+
+    for n in 0..(input.len() / 3) {
+        filter.process((&mut blocks).skip(n * 3).limit(3));
+    }
+
+    assert_eq!(whole, blocks);
+}
+
+#[test]
+fn test_filter_bank_independent_channels() {
+    use crate::filter::{Biquad, FilterBank};
+    use crate::Buf as _;
+
+    // Alternating samples at the Nyquist frequency, which a lowpass removes.
+    let mut buffer = crate::Dynamic::<f32>::with_topology(2, 4096);
+
+    for chan in 0..2 {
+        for (n, s) in buffer[chan].iter_mut().enumerate() {
+            *s = if n % 2 == 0 { 1.0 } else { -1.0 };
+        }
+    }
+
+    let input = crate::buf::to_owned(&buffer);
+
+    let mut bank = FilterBank::new(vec![
+        Biquad::lowpass(1000.0, 0.707, 48000.0),
+        Biquad::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+    ]);
+
+    // Process in blocks to make sure that every filter keeps its own state.
+    for n in 0..(4096 / 256) {
+        bank.process((&mut buffer).skip(n * 256).limit(256));
+    }
+
+    assert!(buffer[0][2048..].iter().all(|s| s.abs() < 1e-3));
+    assert_eq!(&buffer[1], &input[1]);
+
+    // The same as filtering the channel on its own.
+    let mut expected = crate::buf::channel_to_owned(&input, 0);
+    Biquad::lowpass(1000.0, 0.707, 48000.0).process(&mut expected);
+    assert_eq!(&buffer[0], &expected[0]);
+}
+
+#[test]
+#[should_panic]
+fn test_filter_bank_channel_mismatch() {
+    use crate::filter::{Biquad, FilterBank};
+
+    let mut bank = FilterBank::new(vec![Biquad::lowpass(1000.0, 0.707, 48000.0)]);
+    bank.process(&mut crate::dynamic![[0.0f32; 4]; 2]);
+}
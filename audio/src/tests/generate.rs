@@ -0,0 +1,73 @@
+#[test]
+fn test_sine_period() {
+    use crate::generate;
+    use crate::Buf as _;
+
+    // A period of exactly 48 samples.
+    let mut buffer = crate::dynamic![[0.0f32; 480]; 2];
+    generate::sine(1000.0, 0.8, 48000.0).fill(&mut buffer);
+
+    for chan in 0..2 {
+        let channel = &buffer[chan];
+
+        for n in 0..(480 - 48) {
+            assert!((channel[n] - channel[n + 48]).abs() < 1e-4);
+        }
+
+        assert!(channel[0].abs() < 1e-6);
+        assert!((channel[12] - 0.8).abs() < 1e-4);
+        assert!(channel[24].abs() < 1e-4);
+        assert!((channel[36] + 0.8).abs() < 1e-4);
+    }
+
+    // Filling block by block is continuous.
+    let mut blocks = crate::dynamic![[0.0f32; 480]; 2];
+    let mut sine = generate::sine(1000.0, 0.8, 48000.0);
+
+    for n in 0..(480 / 100) {
+        sine.fill((&mut blocks).skip(n * 100).limit(100));
+    }
+
+    for chan in 0..2 {
+        for (a, b) in buffer[chan].iter().zip(blocks[chan].iter()).take(400) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_white_noise_bounded() {
+    use crate::generate;
+
+    let mut buffer = crate::interleaved![[0.0f32; 4096]; 2];
+    generate::white_noise(0.25, 1).fill(&mut buffer);
+
+    let samples = buffer.as_slice();
+    assert!(samples.iter().all(|s| s.abs() <= 0.25));
+    assert!(samples.iter().any(|s| *s > 0.2));
+    assert!(samples.iter().any(|s| *s < -0.2));
+
+    // Roughly zero mean.
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    assert!(mean.abs() < 0.01);
+
+    // Seeds are reproducible, and different seeds produce different signals.
+    let mut same = crate::interleaved![[0.0f32; 4096]; 2];
+    generate::white_noise(0.25, 1).fill(&mut same);
+    assert_eq!(buffer, same);
+
+    let mut other = crate::interleaved![[0.0f32; 4096]; 2];
+    generate::white_noise(0.25, 2).fill(&mut other);
+    assert_ne!(buffer, other);
+}
+
+#[test]
+fn test_silence() {
+    use crate::generate;
+
+    let mut buffer = crate::sequential![[0.0f32; 64]; 3];
+    generate::white_noise(1.0, 7).fill(&mut buffer);
+    generate::silence().fill(&mut buffer);
+
+    assert!(crate::buf::is_silent(&buffer));
+}
@@ -177,3 +177,111 @@ fn test_as_interleaved_mut_ptr() {
         &[1, 1, 1, 1, 1, 1, 1, 1],
     };
 }
+
+#[test]
+fn test_iter_mut_counter() {
+    use crate::ChannelsMut as _;
+
+    let mut buffer = crate::Interleaved::<u32>::with_topology(4, 8);
+
+    let mut chan = buffer.get_mut(2).unwrap();
+    let mut it = chan.iter_mut();
+    assert_eq!(it.len(), 8);
+    it.next();
+    assert_eq!(it.len(), 7);
+
+    for (n, s) in buffer.get_mut(2).unwrap().iter_mut().enumerate() {
+        *s = n as u32 + 1;
+    }
+
+    for (n, s) in buffer.channel_mut(0).iter_mut().enumerate() {
+        *s = (n as u32 + 1) * 10;
+    }
+
+    for (n, frame) in buffer.as_slice().chunks(4).enumerate() {
+        let n = n as u32 + 1;
+        assert_eq!(frame, &[n * 10, 0, n, 0]);
+    }
+}
+
+#[test]
+fn test_deinterleave_into() {
+    let buffer = crate::interleaved![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+    let mut a = [0; 4];
+    let mut b = [0; 4];
+    let mut c = [0; 6];
+
+    let frames = buffer.deinterleave_into(&mut [&mut a[..], &mut b[..], &mut c[..]]);
+
+    assert_eq!(frames, 4);
+    assert_eq!(a, [1, 2, 3, 4]);
+    assert_eq!(b, [5, 6, 7, 8]);
+    assert_eq!(c, [9, 10, 11, 12, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_deinterleave_into_short_slice() {
+    let buffer = crate::interleaved![[1, 2, 3, 4], [5, 6, 7, 8]];
+
+    let mut a = [0; 4];
+    let mut b = [0; 3];
+    buffer.deinterleave_into(&mut [&mut a[..], &mut b[..]]);
+}
+
+#[test]
+fn test_resize_with() {
+    let mut buffer = crate::interleaved![[1.0f32, 2.0], [3.0, 4.0]];
+
+    buffer.resize_with(4, 0.5);
+    assert_eq!(buffer.as_slice(), &[1.0, 3.0, 2.0, 4.0, 0.5, 0.5, 0.5, 0.5]);
+
+    buffer.resize(1);
+    buffer.resize(3);
+    assert_eq!(buffer.as_slice(), &[1.0, 3.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_frames_array() {
+    let buffer = crate::interleaved![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+    let frames = buffer.frames_array::<3>().collect::<Vec<_>>();
+    assert_eq!(frames, vec![[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]]);
+
+    let buffer = crate::interleaved![[0.0f32; 0]; 2];
+    assert_eq!(buffer.frames_array::<2>().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_frames_array_mismatched_channels() {
+    let buffer = crate::interleaved![[1, 2, 3, 4]; 3];
+    let _ = buffer.frames_array::<2>();
+}
+
+#[test]
+fn test_frame_slices_alignment() {
+    let mut buffer = crate::Interleaved::<u32>::with_topology(2, 10);
+
+    for (n, s) in buffer.as_slice_mut().iter_mut().enumerate() {
+        *s = n as u32;
+    }
+
+    let slices = buffer.frame_slices(4).collect::<Vec<_>>();
+    let lens = slices.iter().map(|s| s.len()).collect::<Vec<_>>();
+    assert_eq!(lens, vec![8, 8, 4]);
+
+    for (n, slice) in slices.iter().enumerate() {
+        // Every slice starts with the left channel of the frame at its
+        // boundary.
+        assert_eq!(slice[0], (n * 4 * 2) as u32);
+        assert_eq!(slice.len() % 2, 0);
+    }
+
+    assert_eq!(slices.concat(), buffer.as_slice());
+    assert_eq!(buffer.frame_slices(16).count(), 1);
+
+    let empty = crate::Interleaved::<u32>::new();
+    assert_eq!(empty.frame_slices(4).count(), 0);
+}
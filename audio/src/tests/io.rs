@@ -58,3 +58,76 @@ fn test_simple_io() {
 
     assert_eq!(buffer.channels(), 4);
 }
+
+#[test]
+fn test_deinterleaver() {
+    use crate::io;
+    use crate::{Buf as _, Channels as _, ExactSizeBuf as _, ReadBuf as _};
+
+    let data = [1i16, 10, 100, 2, 20, 200, 3, 30, 300];
+    let mut planar = io::Deinterleaver::new(io::Read::new(crate::wrap::interleaved(&data[..], 3)));
+
+    assert_eq!(planar.frames(), 0);
+    assert_eq!(planar.fill(), 3);
+
+    assert_eq!(planar.channels(), 3);
+    assert!(planar.channel(0).iter().eq([1, 2, 3]));
+    assert!(planar.channel(1).iter().eq([10, 20, 30]));
+    assert!(planar.channel(2).iter().eq([100, 200, 300]));
+
+    // Reading out only a part of the source.
+    planar.as_mut().set_read(1);
+    assert_eq!(planar.fill(), 2);
+    assert!(planar.channel(0).iter().eq([2, 3]));
+    assert!(planar.channel(2).iter().eq([200, 300]));
+    assert!(!planar.as_ref().has_remaining());
+
+    assert_eq!(planar.fill(), 0);
+    assert_eq!(planar.frames(), 0);
+}
+
+#[test]
+fn test_exact_truncates() {
+    use crate::io::{Read, Write};
+    use crate::{ReadBuf as _, WriteBuf as _};
+
+    let from = Read::new(crate::interleaved![[1, 2, 3, 4, 5, 6]; 2]);
+    let mut exact = from.exact(4);
+    assert_eq!(exact.remaining(), 4);
+
+    let mut to = Write::new(crate::interleaved![[0; 8]; 2]);
+    exact.copy_remaining(&mut to);
+
+    assert!(!exact.has_remaining());
+    assert_eq!(to.remaining_mut(), 4);
+    assert_eq!(
+        to.as_ref().as_slice(),
+        &[1, 1, 2, 2, 3, 3, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    // The rest of the source is still available to the underlying reader.
+    assert_eq!(exact.into_inner().remaining(), 2);
+}
+
+#[test]
+fn test_exact_pads_with_silence() {
+    use crate::io::{Read, Write};
+    use crate::{ReadBuf as _, WriteBuf as _};
+
+    let from = Read::new(crate::sequential![[1.0f32, 2.0, 3.0]; 2]);
+    let mut exact = from.exact(8);
+
+    // Read in blocks that straddle the end of the source.
+    let mut to = Write::new(crate::sequential![[f32::NAN; 4]; 2]);
+    exact.copy_remaining(&mut to);
+    assert_eq!(exact.remaining(), 4);
+    assert!(!to.has_remaining_mut());
+    assert_eq!(&to.as_ref()[1], &[1.0, 2.0, 3.0, 0.0]);
+
+    let mut to = Write::new(crate::sequential![[f32::NAN; 6]; 2]);
+    exact.copy_remaining(&mut to);
+    assert!(!exact.has_remaining());
+    assert_eq!(to.remaining_mut(), 2);
+    assert_eq!(&to.as_ref()[0][..4], &[0.0; 4]);
+    assert!(to.as_ref()[0][4..].iter().all(|s| s.is_nan()));
+}
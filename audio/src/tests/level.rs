@@ -0,0 +1,35 @@
+#[test]
+fn test_level_db_round_trip() {
+    use crate::Level;
+
+    let gain = Level::from_db(-6.0);
+    assert!((gain.as_linear() - 0.501).abs() < 1e-3);
+    assert!((gain.as_db() + 6.0).abs() < 1e-5);
+
+    let gain = Level::from_linear(gain.as_linear());
+    assert!((gain.as_db() + 6.0).abs() < 1e-5);
+
+    for db in [-96.0f32, -12.5, 0.0, 3.0, 24.0].iter().copied() {
+        assert!((Level::from_db(db).as_db() - db).abs() < 1e-3);
+    }
+
+    assert_eq!(Level::default(), Level::UNITY);
+    assert_eq!(Level::UNITY.as_db(), 0.0);
+}
+
+#[test]
+fn test_level_mul_samples() {
+    use crate::Level;
+
+    let gain = Level::from_db(-20.0);
+    assert!((1.0f32 * gain - 0.1).abs() < 1e-6);
+    assert!((1.0f64 * gain - 0.1).abs() < 1e-6);
+
+    let mut s = 0.5f32;
+    s *= Level::from_linear(2.0);
+    assert_eq!(s, 1.0);
+
+    let mut s = 0.5f64;
+    s *= Level::from_linear(2.0);
+    assert_eq!(s, 1.0);
+}
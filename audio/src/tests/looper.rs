@@ -0,0 +1,55 @@
+use crate::LoopPlayer;
+
+#[test]
+fn test_loop_player_across_blocks() {
+    let buffer = crate::dynamic![
+        [0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0],
+        [0.0, -1.0, -2.0, -3.0, -4.0, -5.0]
+    ];
+    let mut player = LoopPlayer::new(buffer, 2, 5);
+
+    let mut out = crate::dynamic![[0.0f32; 4]; 2];
+    let mut played = Vec::new();
+
+    for _ in 0..4 {
+        player.read_into(&mut out);
+        played.extend_from_slice(&out[0]);
+
+        for (a, b) in out[0].iter().zip(out[1].iter()) {
+            assert_eq!(*a, -*b);
+        }
+    }
+
+    assert_eq!(
+        played,
+        vec![0.0, 1.0, 2.0, 3.0, 4.0, 2.0, 3.0, 4.0, 2.0, 3.0, 4.0, 2.0, 3.0, 4.0, 2.0, 3.0]
+    );
+    assert_eq!(player.position(), 4);
+}
+
+#[test]
+fn test_loop_player_short_loop() {
+    // A loop region which is shorter than the output block wraps several
+    // times in a single call.
+    let buffer = crate::dynamic![[1.0f32, 2.0, 3.0]];
+    let mut player = LoopPlayer::new(buffer, 1, 2);
+
+    let mut out = crate::interleaved![[0.0f32; 5]; 2];
+    player.read_into(&mut out);
+
+    assert_eq!(
+        out.get(0).unwrap().iter().copied().collect::<Vec<_>>(),
+        vec![1.0, 2.0, 2.0, 2.0, 2.0]
+    );
+    assert_eq!(
+        out.get(1).unwrap().iter().copied().collect::<Vec<_>>(),
+        vec![0.0; 5]
+    );
+    assert_eq!(player.position(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_loop_player_empty_region() {
+    let _ = LoopPlayer::new(crate::dynamic![[0.0f32; 4]], 2, 2);
+}
@@ -1,5 +1,20 @@
+mod analysis;
+mod buf;
 mod copy_channels;
 mod dynamic;
+mod effect;
+mod filter;
+mod generate;
 mod interleaved;
 mod io;
+mod level;
+mod looper;
+mod ms;
+mod pcm;
+mod pool;
+mod processor;
+mod resample;
 mod sequential;
+mod silence;
+mod timed;
+mod wav;
@@ -0,0 +1,43 @@
+#[test]
+fn test_ms_round_trip() {
+    use crate::ms;
+
+    let original = crate::generate!(|c, f| ((f * (c + 1)) as f32 * 0.1).sin(); [128]; 2);
+    let mut buffer = crate::generate!(|c, f| ((f * (c + 1)) as f32 * 0.1).sin(); [128]; 2);
+
+    ms::encode(&mut buffer);
+    assert_ne!(buffer, original);
+    ms::decode(&mut buffer);
+
+    for chan in 0..2 {
+        for (a, b) in buffer[chan].iter().zip(&original[chan]) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_ms_width() {
+    use crate::ms;
+    use crate::ChannelsMut as _;
+
+    // Mono content has no side, so widening it has no effect.
+    let mut buffer = crate::interleaved![[0.5f32, -0.25]; 2];
+    ms::encode(&mut buffer);
+    buffer.channel_mut(1).iter_mut().for_each(|s| *s *= 3.0);
+    ms::decode(&mut buffer);
+    assert_eq!(buffer, crate::interleaved![[0.5f32, -0.25]; 2]);
+
+    // Removing the side entirely collapses the signal to mono.
+    let mut buffer = crate::interleaved![[1.0f32, 0.0], [0.0, 1.0]];
+    ms::encode(&mut buffer);
+    buffer.channel_mut(1).iter_mut().for_each(|s| *s = 0.0);
+    ms::decode(&mut buffer);
+    assert_eq!(buffer, crate::interleaved![[0.5f32, 0.5]; 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_ms_requires_stereo() {
+    crate::ms::encode(crate::dynamic![[0.0f32; 4]; 3]);
+}
@@ -0,0 +1,86 @@
+use crate::pcm::{self, Encoding, Endianness, Layout, PcmFormat};
+
+#[test]
+fn test_to_pcm_bytes_i16_le_interleaved() {
+    let buffer = crate::sequential![[0.0f32, 0.5, 1.0], [-0.5, -1.0, 2.0]];
+    let bytes = pcm::to_pcm_bytes(&buffer, PcmFormat::new(Encoding::I16));
+
+    // 0.5 * 32767 = 16383 = 0x3fff, -0.5 * 32768 = -16384 = 0xc000.
+    assert_eq!(
+        bytes,
+        vec![
+            0x00, 0x00, 0x00, 0xc0, //
+            0xff, 0x3f, 0x00, 0x80, //
+            0xff, 0x7f, 0xff, 0x7f,
+        ]
+    );
+}
+
+#[test]
+fn test_to_pcm_bytes_formats() {
+    let buffer = crate::dynamic![[1.0f32, -1.0], [0.25, 0.0]];
+
+    let format = PcmFormat::new(Encoding::I24).with_layout(Layout::Sequential);
+    assert_eq!(
+        pcm::to_pcm_bytes(&buffer, format),
+        vec![0xff, 0xff, 0x7f, 0x00, 0x00, 0x80, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00]
+    );
+
+    let format = PcmFormat::new(Encoding::I32).with_endianness(Endianness::Big);
+    let bytes = pcm::to_pcm_bytes(&buffer, format);
+    assert_eq!(&bytes[..4], &[0x7f, 0xff, 0xff, 0xff]);
+    assert_eq!(&bytes[8..12], &[0x80, 0x00, 0x00, 0x00]);
+
+    let bytes = pcm::to_pcm_bytes(&buffer, PcmFormat::new(Encoding::F32));
+    assert_eq!(&bytes[4..8], &0.25f32.to_le_bytes());
+    assert_eq!(bytes.len(), 16);
+}
+
+#[test]
+fn test_pcm_round_trip() {
+    let mut buffer = crate::Dynamic::<f32>::with_topology(3, 64);
+
+    for chan in 0..3 {
+        for (n, s) in buffer[chan].iter_mut().enumerate() {
+            *s = ((n as f32 * 0.3 + chan as f32).sin() * 0.9).clamp(-1.0, 1.0);
+        }
+    }
+
+    let encodings = [
+        (Encoding::I16, 1e-4),
+        (Encoding::I24, 1e-6),
+        (Encoding::I32, 1e-6),
+        (Encoding::F32, 0.0),
+    ];
+
+    for (encoding, epsilon) in encodings.iter().copied() {
+        for endianness in [Endianness::Little, Endianness::Big].iter().copied() {
+            for layout in [Layout::Interleaved, Layout::Sequential].iter().copied() {
+                let format = PcmFormat::new(encoding)
+                    .with_endianness(endianness)
+                    .with_layout(layout);
+
+                let bytes = pcm::to_pcm_bytes(&buffer, format);
+                assert_eq!(bytes.len(), 3 * 64 * encoding.bytes());
+
+                let decoded = pcm::from_pcm_bytes(&bytes, format, 3).unwrap();
+                assert!(
+                    crate::buf::approx_eq(&buffer, &decoded, epsilon),
+                    "{:?}",
+                    format
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_pcm_bytes_ragged() {
+    let format = PcmFormat::new(Encoding::I24);
+
+    let error = pcm::from_pcm_bytes(&[0; 10], format, 2).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    let buffer = pcm::from_pcm_bytes(&[0; 12], format, 2).unwrap();
+    assert_eq!(buffer, crate::dynamic![[0.0f32; 2]; 2]);
+}
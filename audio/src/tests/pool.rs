@@ -0,0 +1,80 @@
+#[test]
+fn test_pool_reuses_allocation() {
+    let pool = crate::BufferPool::<f32>::new();
+
+    let ptr = {
+        let mut buffer = pool.acquire(2, 512);
+        buffer[1][100] = 1.0;
+        buffer[1].as_ptr()
+    };
+
+    for frames in [512, 128, 256, 1].iter().copied() {
+        let buffer = pool.acquire(2, frames);
+        assert_eq!(buffer.channels(), 2);
+        assert_eq!(buffer.frames(), frames);
+        assert_eq!(buffer[1].as_ptr(), ptr);
+        assert!(buffer[1].iter().all(|s| *s == 0.0));
+        assert_eq!(pool.len(), 0);
+    }
+
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn test_pool_outstanding_buffers() {
+    let pool = crate::BufferPool::<i16>::new();
+
+    let a = pool.acquire(2, 64);
+    let b = pool.acquire(2, 64);
+    assert_ne!(a[0].as_ptr(), b[0].as_ptr());
+
+    drop(a);
+    drop(b);
+    assert_eq!(pool.len(), 2);
+
+    // A request which is larger than any free buffer allocates a new one.
+    let c = pool.acquire(2, 1024);
+    assert_eq!(pool.len(), 2);
+    drop(c);
+    assert_eq!(pool.len(), 3);
+}
+
+#[test]
+fn test_pool_mixed_channels() {
+    let pool = crate::BufferPool::<f32>::new();
+
+    let (mono, quad) = {
+        let mono = pool.acquire(1, 64);
+        let quad = pool.acquire(4, 64);
+        (mono[0].as_ptr(), quad[3].as_ptr())
+    };
+
+    assert_eq!(pool.len(), 2);
+
+    // A buffer with too few channels isn't reused, since it would have to
+    // reallocate.
+    {
+        let stereo = pool.acquire(2, 32);
+        assert_eq!(stereo.channels(), 2);
+        assert_eq!(pool.len(), 1);
+
+        let mono = pool.acquire(1, 64);
+        assert_eq!(pool.len(), 0);
+
+        let stereo = pool.acquire(2, 64);
+        assert_ne!(stereo[0].as_ptr(), mono[0].as_ptr());
+        assert_eq!(pool.len(), 0);
+    }
+
+    assert_eq!(pool.len(), 3);
+
+    // Channels which are no longer in use are retained by the buffer.
+    let quad_again = pool.acquire(4, 64);
+    assert_eq!(quad_again[3].as_ptr(), quad);
+
+    // Only the mono buffer has too few channels.
+    let stereo = pool.acquire(2, 64);
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.acquire(1, 64)[0].as_ptr(), mono);
+    drop(stereo);
+}
@@ -0,0 +1,70 @@
+#[test]
+fn test_decimate_halves_frames() {
+    use crate::resample;
+
+    let mut src = crate::sequential![[0.0f32; 1024]; 2];
+
+    for chan in 0..2 {
+        for (n, s) in src[chan].iter_mut().enumerate() {
+            *s = (n + chan) as f32;
+        }
+    }
+
+    let out = resample::decimate(&src, 2, false);
+    assert_eq!(out.channels(), 2);
+    assert_eq!(out.frames(), 512);
+
+    for chan in 0..2 {
+        for (n, s) in out[chan].iter().enumerate() {
+            assert_eq!(*s, (n * 2 + chan) as f32);
+        }
+    }
+
+    assert_eq!(resample::decimate(&src, 3, false).frames(), 342);
+}
+
+#[test]
+fn test_decimate_prefilter_removes_aliasing() {
+    use crate::resample;
+    use std::f32::consts::PI;
+
+    // A tone at 0.4 of the sample rate, which would alias down to 0.2 of the
+    // new sample rate without filtering.
+    let mut src = crate::sequential![[0.0f32; 4096]; 1];
+
+    for (n, s) in src[0].iter_mut().enumerate() {
+        *s = 0.5 + 0.5 * (2.0 * PI * 0.4 * n as f32).sin();
+    }
+
+    let peak = |buf: &crate::Dynamic<f32>| {
+        buf[0][1024..]
+            .iter()
+            .fold(0.0f32, |peak, s| f32::max(peak, (s - 0.5).abs()))
+    };
+
+    let aliased = resample::decimate(&src, 2, false);
+    let filtered = resample::decimate(&src, 2, true);
+
+    assert!(peak(&aliased) > 0.4);
+    assert!(peak(&filtered) < 0.01);
+    // DC passes through the filter.
+    assert!((filtered[0][2000] - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn test_interpolate_doubles_frames() {
+    use crate::resample;
+
+    let src = crate::interleaved![[1.0f32, -1.0, 0.5, 0.25]; 2];
+
+    let out = resample::interpolate(&src, 2, false);
+    assert_eq!(out.channels(), 2);
+    assert_eq!(out.frames(), 8);
+    assert_eq!(&out[1], &[1.0, 1.0, -1.0, -1.0, 0.5, 0.5, 0.25, 0.25]);
+
+    // A constant signal stays constant once the filter has settled.
+    let src = crate::interleaved![[1.0f32; 512]; 2];
+    let out = resample::interpolate(&src, 2, true);
+    assert_eq!(out.frames(), 1024);
+    assert!(out[0][512..].iter().all(|s| (s - 1.0).abs() < 0.01));
+}
@@ -84,8 +84,8 @@ fn test_unaligned_resize() {
 
     buffer.resize(4);
 
-    assert_eq!(&buffer[0], &[1.0, 2.0, 3.0, 2.0]); // <- 2.0 is stale data.
-    assert_eq!(&buffer[1], &[2.0, 3.0, 4.0, 5.0]); // <- 5.0 is stale data.
+    assert_eq!(&buffer[0], &[1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(&buffer[1], &[2.0, 3.0, 4.0, 0.0]);
 }
 
 #[test]
@@ -122,19 +122,17 @@ fn test_multiple_channel_resizes() {
     assert_eq!(buffer.get(1), Some(&expected[..64]));
     assert_eq!(buffer.get(2), None);
 
-    // increase - this causes some weirdness.
+    // increase - the frames which are sized back in are zeroed.
     buffer.resize(128);
 
-    let first_overlapping = expected[..64]
+    let zero_extended = expected[..64]
         .iter()
-        .chain(expected[..64].iter())
         .copied()
+        .chain(std::iter::repeat_n(0.0, 64))
         .collect::<Vec<_>>();
 
-    assert_eq!(buffer.get(0), Some(&first_overlapping[..]));
-    // Note: second channel matches perfectly up with an old channel that was
-    // masked out.
-    assert_eq!(buffer.get(1), Some(&expected[..]));
+    assert_eq!(buffer.get(0), Some(&zero_extended[..]));
+    assert_eq!(buffer.get(1), Some(&zero_extended[..]));
     assert_eq!(buffer.get(2), None);
 }
 
@@ -148,7 +146,7 @@ fn test_drop_empty() {
 }
 
 #[test]
-fn test_stale_allocation() {
+fn test_resize_zeroes_stale_allocation() {
     let mut buffer = crate::Sequential::<f32>::with_topology(4, 256);
     assert_eq!(buffer[1][128], 0.0);
     buffer[1][128] = 42.0;
@@ -164,3 +162,19 @@ fn test_stale_allocation() {
 fn test_from_array() {
     let _ = crate::dynamic![[0.0; 128]; 2];
 }
+
+#[test]
+fn test_resize_with() {
+    let mut buffer = crate::Sequential::<f32>::with_topology(2, 2);
+    buffer[0].copy_from_slice(&[1.0, 2.0]);
+    buffer[1].copy_from_slice(&[3.0, 4.0]);
+
+    buffer.resize_with(4, 0.5);
+    assert_eq!(&buffer[0], &[1.0, 2.0, 0.5, 0.5]);
+    assert_eq!(&buffer[1], &[3.0, 4.0, 0.5, 0.5]);
+
+    buffer.resize(1);
+    buffer.resize(3);
+    assert_eq!(&buffer[0], &[1.0, 0.0, 0.0]);
+    assert_eq!(&buffer[1], &[3.0, 0.0, 0.0]);
+}
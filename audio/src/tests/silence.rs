@@ -0,0 +1,138 @@
+#[test]
+fn test_is_silent_zeroed() {
+    use crate::buf;
+
+    assert!(buf::is_silent(crate::dynamic![[0.0f32; 16]; 2]));
+    assert!(buf::is_silent(crate::interleaved![[0i16; 16]; 2]));
+    assert!(buf::is_silent(crate::sequential![[0u8; 16]; 2]));
+    assert!(buf::is_silent(crate::Dynamic::<f32>::new()));
+}
+
+#[test]
+fn test_is_silent_single_sample() {
+    use crate::buf;
+
+    let mut buffer = crate::interleaved![[0i16; 16]; 2];
+    *buffer.frame_mut(1, 15).unwrap() = -1;
+    assert!(!buf::is_silent(&buffer));
+
+    let mut buffer = crate::sequential![[0.0f32; 16]; 2];
+    buffer[0][0] = f32::EPSILON;
+    assert!(!buf::is_silent(&buffer));
+}
+
+#[test]
+fn test_is_silent_below() {
+    use crate::buf;
+
+    let mut buffer = crate::dynamic![[0.0f32; 16]; 2];
+    buffer[1][8] = 0.01;
+    buffer[0][4] = -0.01;
+
+    assert!(buf::is_silent_below(&buffer, 0.01));
+    assert!(buf::is_silent_below(&buffer, 0.1));
+    assert!(!buf::is_silent_below(&buffer, 0.001));
+    assert!(!buf::is_silent_below(&buffer, 0.0));
+
+    let buffer = crate::interleaved![[0i16, 2, -2, 0]; 2];
+    assert!(buf::is_silent_below(&buffer, 2));
+    assert!(!buf::is_silent_below(&buffer, 1));
+}
+
+#[test]
+fn test_trim_silence_padded() {
+    use crate::{buf, Channels as _, ExactSizeBuf as _};
+
+    let from = crate::sequential![
+        [0.0f32, 0.0, 0.0, 1.0, 0.5, 0.0, 0.0, 0.0],
+        [0.0f32, 0.0, 0.0, 0.0, 0.0, -0.5, 0.0, 0.0]
+    ];
+
+    let trimmed = buf::trim_silence(&from, 0.0);
+    assert_eq!(trimmed.frames(), 3);
+    assert!(trimmed.channel(0).iter().eq([1.0, 0.5, 0.0]));
+    assert!(trimmed.channel(1).iter().eq([0.0, 0.0, -0.5]));
+
+    let from = crate::interleaved![[0i16, 1, 0, 0, 2, 0, 0]; 2];
+    let trimmed = buf::trim_silence(&from, 1);
+    assert_eq!(trimmed.frames(), 1);
+    assert!(trimmed.channel(0).iter().eq([2]));
+    assert!(trimmed.channel(1).iter().eq([2]));
+}
+
+#[test]
+fn test_trim_silence_fully_silent() {
+    use crate::{buf, Buf as _, Channels as _, ExactSizeBuf as _};
+
+    let from = crate::dynamic![[0.0f32; 16]; 2];
+    let trimmed = buf::trim_silence(&from, 0.0);
+
+    assert_eq!(trimmed.channels(), 2);
+    assert_eq!(trimmed.frames(), 0);
+    assert_eq!(trimmed.channel(0).iter().count(), 0);
+
+    let from = crate::Dynamic::<f32>::new();
+    assert_eq!(buf::trim_silence(&from, 0.0).frames(), 0);
+}
+
+#[test]
+fn test_silence_range_middle_of_ramp() {
+    use crate::buf;
+
+    let ramp = |c: usize, f: usize| (c * 100 + f + 1) as i16;
+
+    let mut buffer = crate::generate!(ramp; [10]; 2);
+    buf::silence_range(&mut buffer, 3, 7);
+
+    for chan in 0..2 {
+        for f in 0..10 {
+            let expected = if (3..7).contains(&f) {
+                0
+            } else {
+                ramp(chan, f)
+            };
+            assert_eq!(buffer[chan][f], expected);
+        }
+    }
+
+    let mut buffer = crate::interleaved![[1.0f32, 2.0, 3.0, 4.0]; 2];
+    buf::silence_range(&mut buffer, 2, 100);
+    assert_eq!(buffer, crate::interleaved![[1.0f32, 2.0, 0.0, 0.0]; 2]);
+}
+
+#[test]
+fn test_silence_range_empty() {
+    use crate::buf;
+
+    let mut buffer = crate::sequential![[1i16, 2, 3, 4]; 2];
+
+    buf::silence_range(&mut buffer, 3, 3);
+    buf::silence_range(&mut buffer, 3, 1);
+    buf::silence_range(&mut buffer, 4, 10);
+    buf::silence_range(&mut buffer, 100, 200);
+
+    assert_eq!(buffer, crate::sequential![[1i16, 2, 3, 4]; 2]);
+}
+
+#[test]
+fn test_active_bounds() {
+    use crate::buf;
+
+    let mut buffer = crate::interleaved![[0.0f32; 32]; 4];
+
+    for f in 10..20 {
+        *buffer.frame_mut(1, f).unwrap() = 0.5;
+    }
+
+    // Numerical fuzz elsewhere, which is below the threshold.
+    *buffer.frame_mut(3, 30).unwrap() = 0.0001;
+
+    assert_eq!(buf::active_bounds(&buffer, 0.001), (1..2, 10..20));
+    assert_eq!(buf::active_bounds(&buffer, 0.0), (1..4, 10..31));
+
+    let silent = crate::dynamic![[0.0f32; 16]; 2];
+    assert_eq!(buf::active_bounds(&silent, 0.0), (0..0, 0..0));
+
+    let empty = crate::Dynamic::<f32>::new();
+    assert_eq!(buf::active_bounds(&empty, 0.0), (0..0, 0..0));
+}
@@ -0,0 +1,34 @@
+use crate::timed::MixError;
+use crate::Timed;
+
+#[test]
+fn test_timed_add_from_mismatched_rates() {
+    let mut bus = Timed::new(crate::dynamic![[0.5f32; 8]; 2], 48000);
+    let clip = Timed::new(crate::interleaved![[0.25f32; 8]; 2], 44100);
+
+    assert_eq!(
+        bus.add_from(&clip),
+        Err(MixError::SampleRateMismatch {
+            expected: 48000,
+            actual: 44100,
+        })
+    );
+
+    // The bus is left untouched.
+    assert_eq!(bus.inner(), &crate::dynamic![[0.5f32; 8]; 2]);
+}
+
+#[test]
+fn test_timed_add_from_matching_rates() {
+    let mut bus = Timed::new(crate::sequential![[1i16; 4]; 3], 44100);
+    let clip = Timed::new(crate::interleaved![[2i16, 3, 4, 5, 6, 7]; 2], 44100);
+
+    bus.add_from(&clip).unwrap();
+    bus.add_from(&clip).unwrap();
+
+    assert_eq!(bus.sample_rate(), 44100);
+    assert_eq!(
+        bus.into_inner(),
+        crate::sequential![[5i16, 7, 9, 11], [5, 7, 9, 11], [1, 1, 1, 1]]
+    );
+}
@@ -0,0 +1,144 @@
+/// Encode a WAV file with the given format tag and raw sample data.
+fn encode(tag: u16, channels: u16, bits: u16, data: &[u8]) -> Vec<u8> {
+    let block_align = channels * bits / 8;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(4 + 24 + 10 + 8 + data.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&tag.to_le_bytes());
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&48000u32.to_le_bytes());
+    out.extend_from_slice(&(48000 * block_align as u32).to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&bits.to_le_bytes());
+
+    // An unknown chunk with an odd length, which should be skipped.
+    out.extend_from_slice(b"LIST");
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&[0, 0]);
+
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+/// Read a whole file in blocks of `block` frames.
+fn read_in_blocks(file: &[u8], block: usize) -> std::io::Result<crate::Dynamic<f32>> {
+    let mut reader = crate::wav::Reader::new(file)?;
+    let channels = reader.format().channels();
+
+    let mut out = crate::Dynamic::<f32>::with_topology(channels, 0);
+    let mut buffer = crate::Dynamic::<f32>::with_topology(channels, block);
+
+    loop {
+        let n = reader.read_block(block, &mut buffer)?;
+
+        if n == 0 {
+            break;
+        }
+
+        let frames = out.frames();
+        out.resize(frames + n);
+
+        for chan in 0..channels {
+            out[chan][frames..].copy_from_slice(&buffer[chan][..n]);
+        }
+    }
+
+    Ok(out)
+}
+
+#[test]
+fn test_wav_read_blocks() -> std::io::Result<()> {
+    let frames = 5000;
+
+    let mut data = Vec::new();
+
+    for n in 0..frames {
+        data.extend_from_slice(&((n * 13) as i16).to_le_bytes());
+        data.extend_from_slice(&(-((n * 7) as i16)).to_le_bytes());
+    }
+
+    let file = encode(1, 2, 16, &data);
+
+    let mut reader = crate::wav::Reader::new(&file[..])?;
+    assert_eq!(reader.format().channels(), 2);
+    assert_eq!(reader.format().sample_rate(), 48000);
+    assert_eq!(reader.format().bits_per_sample(), 16);
+    assert!(!reader.format().is_float());
+    assert_eq!(reader.remaining(), frames);
+
+    let mut whole = crate::Dynamic::<f32>::with_topology(2, frames);
+    assert_eq!(reader.read_block(frames, &mut whole)?, frames);
+    assert_eq!(reader.read_block(frames, &mut whole)?, 0);
+
+    // NB: positive and negative samples are scaled by `MAX` and `-MIN`
+    // respectively, like when translating samples.
+    assert_eq!(whole[0][1], 13.0 / 32767.0);
+    assert_eq!(whole[1][1], -7.0 / 32768.0);
+
+    let blocks = read_in_blocks(&file, 1024)?;
+    assert_eq!(blocks, whole);
+    Ok(())
+}
+
+#[test]
+fn test_wav_formats() -> std::io::Result<()> {
+    let expected = crate::dynamic![[0.5f32, -0.25]; 1];
+
+    let data = [0.5f32, -0.25]
+        .iter()
+        .flat_map(|s| s.to_le_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(read_in_blocks(&encode(3, 1, 32, &data), 1)?, expected);
+
+    let data = [0x00, 0x00, 0x40, 0x00, 0x00, 0xe0];
+    assert_eq!(read_in_blocks(&encode(1, 1, 24, &data), 4)?, expected);
+
+    // NB: 8-bit samples are unsigned and can't represent 0.5 exactly.
+    let data = [255u8, 96];
+    assert_eq!(
+        read_in_blocks(&encode(1, 1, 8, &data), 4)?,
+        crate::dynamic![[1.0f32, -0.25]; 1]
+    );
+
+    // Unsupported formats are rejected.
+    let error = crate::wav::Reader::new(&encode(2, 1, 4, &[])[..]).err();
+    assert_eq!(
+        error.map(|e| e.kind()),
+        Some(std::io::ErrorKind::InvalidData)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_wav_truncated() -> std::io::Result<()> {
+    let mut file = encode(1, 2, 16, &[0; 40]);
+    // Cut off the last frame and a half.
+    file.truncate(file.len() - 6);
+
+    let blocks = read_in_blocks(&file, 4)?;
+    assert_eq!(blocks.frames(), 8);
+    Ok(())
+}
+
+#[test]
+fn test_wav_decodes_like_pcm() -> std::io::Result<()> {
+    use crate::pcm::{self, Encoding, PcmFormat};
+
+    let data = [0x01, 0x00, 0xff, 0x7f, 0x00, 0x80, 0x34, 0x12];
+    let wav = read_in_blocks(&encode(1, 2, 16, &data), 3)?;
+    let raw = pcm::from_pcm_bytes(&data, PcmFormat::new(Encoding::I16), 2)?;
+    assert_eq!(wav, raw);
+
+    let data = [0x01, 0x00, 0x80, 0xff, 0xff, 0x7f];
+    let wav = read_in_blocks(&encode(1, 1, 24, &data), 3)?;
+    let raw = pcm::from_pcm_bytes(&data, PcmFormat::new(Encoding::I24), 1)?;
+    assert_eq!(wav, raw);
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Buffers which know the sample rate of their content.
+
+use audio_core::{Channels, ChannelsMut, Sample};
+use std::error;
+use std::fmt;
+use std::ops;
+
+/// An error raised when mixing [Timed] buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MixError {
+    /// The buffers have different sample rates, so mixing them would change
+    /// the pitch and speed of one of them.
+    SampleRateMismatch {
+        /// The sample rate of the buffer being mixed into.
+        expected: u32,
+        /// The sample rate of the buffer being mixed from.
+        actual: u32,
+    },
+}
+
+impl fmt::Display for MixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SampleRateMismatch { expected, actual } => write!(
+                f,
+                "cannot mix a buffer with a sample rate of {} Hz into one of {} Hz",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl error::Error for MixError {}
+
+/// A buffer associated with the sample rate of its content.
+///
+/// Keeping track of the sample rate allows for catching mistakes like mixing
+/// a 44.1 kHz clip into a 48 kHz bus, which would otherwise silently play the
+/// clip back at the wrong pitch and speed.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::Timed;
+///
+/// let mut bus = Timed::new(audio::dynamic![[0.5f32; 4]; 2], 48000);
+/// let clip = Timed::new(audio::dynamic![[0.25f32; 4]; 2], 44100);
+///
+/// assert!(bus.add_from(&clip).is_err());
+/// ```
+pub struct Timed<B> {
+    buf: B,
+    sample_rate: u32,
+}
+
+impl<B> Timed<B> {
+    /// Associate `buf` with the given `sample_rate`.
+    pub fn new(buf: B, sample_rate: u32) -> Self {
+        Self { buf, sample_rate }
+    }
+
+    /// The sample rate of the buffer.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Access the underlying buffer.
+    pub fn inner(&self) -> &B {
+        &self.buf
+    }
+
+    /// Access the underlying buffer mutably.
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Convert into the underlying buffer.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Mix `other` into this buffer by adding its samples to the samples of
+    /// this buffer.
+    ///
+    /// Only the channels and frames which are common to both buffers are
+    /// mixed.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [MixError::SampleRateMismatch] if the buffers have
+    /// different sample rates, in which case this buffer is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use audio::Timed;
+    ///
+    /// # fn main() -> Result<(), audio::timed::MixError> {
+    /// let mut bus = Timed::new(audio::dynamic![[0.5f32; 4]; 2], 48000);
+    /// let clip = Timed::new(audio::sequential![[0.25f32; 2]; 2], 48000);
+    ///
+    /// bus.add_from(&clip)?;
+    ///
+    /// assert_eq!(bus.into_inner(), audio::dynamic![[0.75f32, 0.75, 0.5, 0.5]; 2]);
+    /// # Ok(()) }
+    /// ```
+    pub fn add_from<U, T>(&mut self, other: &Timed<U>) -> Result<(), MixError>
+    where
+        B: ChannelsMut<T>,
+        U: Channels<T>,
+        T: Sample + ops::AddAssign,
+    {
+        if self.sample_rate != other.sample_rate {
+            return Err(MixError::SampleRateMismatch {
+                expected: self.sample_rate,
+                actual: other.sample_rate,
+            });
+        }
+
+        let channels = usize::min(self.buf.channels(), other.buf.channels());
+
+        for chan in 0..channels {
+            let from = other.buf.channel(chan);
+
+            for (o, s) in self.buf.channel_mut(chan).iter_mut().zip(from.iter()) {
+                *o += s;
+            }
+        }
+
+        Ok(())
+    }
+}
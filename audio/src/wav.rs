@@ -0,0 +1,295 @@
+//! Streaming decoding of WAV files.
+//!
+//! The [Reader] decodes a file block by block, so large files don't need to be
+//! loaded into memory in their entirety.
+
+use audio_core::{ChannelsMut, Translate};
+use std::io;
+
+/// The format tag for integer PCM data.
+const FORMAT_PCM: u16 = 0x0001;
+/// The format tag for IEEE floating point data.
+const FORMAT_IEEE_FLOAT: u16 = 0x0003;
+/// The format tag indicating that the actual format tag is stored in the
+/// extension of the format chunk.
+const FORMAT_EXTENSIBLE: u16 = 0xfffe;
+
+/// The format of the samples stored in a WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Format {
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    float: bool,
+}
+
+impl Format {
+    /// The number of channels stored in the file.
+    pub fn channels(&self) -> usize {
+        self.channels as usize
+    }
+
+    /// The sample rate of the file.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// The number of bits used to store every sample.
+    pub fn bits_per_sample(&self) -> u16 {
+        self.bits_per_sample
+    }
+
+    /// Test if the samples are stored as floating point numbers, as opposed to
+    /// integers.
+    pub fn is_float(&self) -> bool {
+        self.float
+    }
+
+    /// The number of bytes used to store a single frame.
+    fn block_align(&self) -> usize {
+        self.channels() * (self.bits_per_sample as usize / 8)
+    }
+
+    /// Decode a single sample from its little-endian bytes.
+    ///
+    /// Integer samples are translated the same way as everywhere else in the
+    /// crate, see [Translate].
+    fn decode(&self, b: &[u8]) -> f32 {
+        match (self.float, self.bits_per_sample) {
+            (false, 8) => f32::translate(b[0]),
+            (false, 16) => f32::translate(i16::from_le_bytes([b[0], b[1]])),
+            (false, 24) => crate::pcm::i24_to_f32([b[2], b[1], b[0]]),
+            (false, _) => f32::translate(i32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+            (true, 32) => f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            (true, _) => {
+                f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32
+            }
+        }
+    }
+}
+
+/// A streaming decoder for WAV files.
+///
+/// The header of the file is parsed when the reader is constructed, after
+/// which the samples are decoded block by block with [Reader::read_block].
+///
+/// Integer PCM data with 8, 16, 24, or 32 bits per sample, and floating point
+/// data with 32 or 64 bits per sample is supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use audio::wav;
+/// # fn wav_file() -> Vec<u8> {
+/// #     let data: &[i16] = &[0, 32767, -16384, -32768, 32767, -8192];
+/// #     let mut out = Vec::new();
+/// #     out.extend_from_slice(b"RIFF");
+/// #     out.extend_from_slice(&(36 + data.len() as u32 * 2).to_le_bytes());
+/// #     out.extend_from_slice(b"WAVEfmt ");
+/// #     out.extend_from_slice(&16u32.to_le_bytes());
+/// #     out.extend_from_slice(&1u16.to_le_bytes());
+/// #     out.extend_from_slice(&2u16.to_le_bytes());
+/// #     out.extend_from_slice(&44100u32.to_le_bytes());
+/// #     out.extend_from_slice(&(44100u32 * 4).to_le_bytes());
+/// #     out.extend_from_slice(&4u16.to_le_bytes());
+/// #     out.extend_from_slice(&16u16.to_le_bytes());
+/// #     out.extend_from_slice(b"data");
+/// #     out.extend_from_slice(&(data.len() as u32 * 2).to_le_bytes());
+/// #     for s in data { out.extend_from_slice(&s.to_le_bytes()); }
+/// #     out
+/// # }
+///
+/// # fn main() -> std::io::Result<()> {
+/// let file = wav_file();
+/// let mut reader = wav::Reader::new(&file[..])?;
+///
+/// assert_eq!(reader.format().channels(), 2);
+/// assert_eq!(reader.format().sample_rate(), 44100);
+/// assert_eq!(reader.remaining(), 3);
+///
+/// let mut buffer = audio::Dynamic::<f32>::with_topology(2, 2);
+///
+/// assert_eq!(reader.read_block(2, &mut buffer)?, 2);
+/// assert_eq!(buffer, audio::dynamic![[0.0, -0.5], [1.0, -1.0]]);
+///
+/// assert_eq!(reader.read_block(2, &mut buffer)?, 1);
+/// assert_eq!(buffer[0][0], 1.0);
+/// assert_eq!(buffer[1][0], -0.25);
+///
+/// assert_eq!(reader.read_block(2, &mut buffer)?, 0);
+/// # Ok(()) }
+/// ```
+pub struct Reader<R> {
+    reader: R,
+    format: Format,
+    remaining: usize,
+    scratch: Vec<u8>,
+}
+
+impl<R> Reader<R>
+where
+    R: io::Read,
+{
+    /// Construct a new reader, parsing the header of the file up until the
+    /// start of the sample data.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [io::ErrorKind::InvalidData] if the file isn't a WAV file
+    /// or if it uses an unsupported format.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0u8; 12];
+        reader.read_exact(&mut header)?;
+
+        if &header[..4] != b"RIFF" || &header[8..] != b"WAVE" {
+            return Err(invalid_data("not a RIFF WAVE file"));
+        }
+
+        let mut format = None;
+
+        loop {
+            let mut chunk = [0u8; 8];
+            reader.read_exact(&mut chunk)?;
+            let len = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as usize;
+
+            match &chunk[..4] {
+                b"fmt " => {
+                    let mut fmt = vec![0u8; len];
+                    reader.read_exact(&mut fmt)?;
+                    format = Some(parse_format(&fmt)?);
+                }
+                b"data" => {
+                    let format = format.ok_or_else(|| invalid_data("data before format"))?;
+
+                    return Ok(Self {
+                        reader,
+                        format,
+                        remaining: len / format.block_align(),
+                        scratch: Vec::new(),
+                    });
+                }
+                _ => {
+                    // NB: chunks are padded to an even number of bytes.
+                    let len = (len + (len & 1)) as u64;
+                    let skipped = io::copy(&mut io::Read::take(&mut reader, len), &mut io::sink())?;
+
+                    if skipped != len {
+                        return Err(io::ErrorKind::UnexpectedEof.into());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Access the format of the file.
+    pub fn format(&self) -> &Format {
+        &self.format
+    }
+
+    /// Get the number of frames remaining to be read, according to the
+    /// header of the file.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Decode up to `frames` frames into `into`, returning the number of
+    /// frames read. Zero is returned once the end of the data is reached.
+    ///
+    /// Fewer frames are read if the channels of `into` are shorter than
+    /// `frames`. Only the channels which are common to the file and `into` are
+    /// written to.
+    ///
+    /// If the file is truncated, this reads as many whole frames as are
+    /// available and treats that as the end of the data.
+    pub fn read_block<B>(&mut self, frames: usize, mut into: B) -> io::Result<usize>
+    where
+        B: ChannelsMut<f32>,
+    {
+        let channels = usize::min(self.format.channels(), into.channels());
+
+        let mut frames = usize::min(frames, self.remaining);
+
+        for chan in 0..channels {
+            frames = usize::min(frames, into.channel_mut(chan).frames());
+        }
+
+        let block_align = self.format.block_align();
+        self.scratch.resize(frames * block_align, 0);
+
+        let mut filled = 0;
+
+        while filled < self.scratch.len() {
+            match self.reader.read(&mut self.scratch[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        if filled < self.scratch.len() {
+            frames = filled / block_align;
+            self.remaining = 0;
+        } else {
+            self.remaining -= frames;
+        }
+
+        let width = self.format.bits_per_sample as usize / 8;
+
+        for chan in 0..channels {
+            let to = into.channel_mut(chan);
+
+            for (frame, s) in to.iter_mut().take(frames).enumerate() {
+                let at = frame * block_align + chan * width;
+                *s = self.format.decode(&self.scratch[at..at + width]);
+            }
+        }
+
+        Ok(frames)
+    }
+}
+
+/// Parse the content of a format chunk.
+fn parse_format(fmt: &[u8]) -> io::Result<Format> {
+    if fmt.len() < 16 {
+        return Err(invalid_data("format chunk too short"));
+    }
+
+    let u16_at = |at: usize| u16::from_le_bytes([fmt[at], fmt[at + 1]]);
+
+    let mut tag = u16_at(0);
+    let channels = u16_at(2);
+    let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+    let bits_per_sample = u16_at(14);
+
+    if tag == FORMAT_EXTENSIBLE {
+        // NB: the actual format tag is stored in the first two bytes of the
+        // sub format GUID.
+        if fmt.len() < 26 {
+            return Err(invalid_data("extensible format chunk too short"));
+        }
+
+        tag = u16_at(24);
+    }
+
+    let float = match (tag, bits_per_sample) {
+        (FORMAT_PCM, 8) | (FORMAT_PCM, 16) | (FORMAT_PCM, 24) | (FORMAT_PCM, 32) => false,
+        (FORMAT_IEEE_FLOAT, 32) | (FORMAT_IEEE_FLOAT, 64) => true,
+        _ => return Err(invalid_data("unsupported sample format")),
+    };
+
+    if channels == 0 {
+        return Err(invalid_data("file has no channels"));
+    }
+
+    Ok(Format {
+        channels,
+        sample_rate,
+        bits_per_sample,
+        float,
+    })
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -66,3 +66,53 @@ fn count_to_1000_mpsc(b: &mut Bencher) {
         total
     });
 }
+
+fn wakeup_latency(b: &mut Bencher, park: ste::ParkStrategy) {
+    use std::time::Duration;
+
+    let thread = ste::Builder::new().park_strategy(park).build().unwrap();
+
+    b.iter(|| {
+        // Let the background thread go idle so that each submission has to
+        // wake it up.
+        std::thread::sleep(Duration::from_micros(100));
+        thread.submit(|| 42)
+    });
+
+    thread.join();
+}
+
+#[bench]
+fn wakeup_latency_block(b: &mut Bencher) {
+    wakeup_latency(b, ste::ParkStrategy::Block);
+}
+
+#[bench]
+fn wakeup_latency_spin_then_park(b: &mut Bencher) {
+    wakeup_latency(b, ste::ParkStrategy::SpinThenPark);
+}
+
+#[bench]
+fn wakeup_latency_spin(b: &mut Bencher) {
+    wakeup_latency(b, ste::ParkStrategy::Spin);
+}
+
+fn submit_latency(b: &mut Bencher, submit_spin: usize) {
+    let thread = ste::Builder::new()
+        .submit_spin(submit_spin)
+        .build()
+        .unwrap();
+
+    b.iter(|| thread.submit(|| ()));
+    thread.join();
+}
+
+#[bench]
+fn submit_latency_no_spin(b: &mut Bencher) {
+    submit_latency(b, 0);
+}
+
+#[bench]
+fn submit_latency_spin(b: &mut Bencher) {
+    submit_latency(b, 1000);
+}
@@ -0,0 +1,43 @@
+use crate::loom::sync::atomic::{AtomicBool, Ordering};
+use crate::loom::sync::Arc;
+
+/// A token used to cooperatively cancel a task submitted through
+/// [Thread::submit_with_cancel][crate::Thread::submit_with_cancel].
+///
+/// Cloning the token produces a handle to the same cancellation flag, so one
+/// clone can be handed to another thread which requests cancellation while
+/// the task polls [CancelToken::is_cancelled].
+///
+/// # Examples
+///
+/// ```rust
+/// let token = ste::CancelToken::new();
+/// let handle = token.clone();
+///
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Construct a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Request cancellation of any task observing this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Test if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
@@ -0,0 +1,59 @@
+use std::error;
+use std::fmt;
+
+/// Error raised when a task submitted to a [Thread][crate::Thread] panicked.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+///
+/// let result = thread.submit_timed(|| panic!("woops"));
+/// assert!(result.is_err());
+///
+/// thread.join();
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Panicked;
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task panicked")
+    }
+}
+
+impl error::Error for Panicked {}
+
+/// Error raised when a task submitted through
+/// [Thread::submit_with_cancel][crate::Thread::submit_with_cancel] was
+/// cancelled.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+///
+/// let token = ste::CancelToken::new();
+/// token.cancel();
+///
+/// let result = thread.submit_with_cancel(&token, |_| 42);
+/// assert!(result.is_err());
+///
+/// thread.join();
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task cancelled")
+    }
+}
+
+impl error::Error for Cancelled {}
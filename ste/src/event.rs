@@ -0,0 +1,18 @@
+/// An event in the lifecycle of a task, as reported to the hook installed
+/// with [Builder::on_task][crate::Builder::on_task].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TaskEvent {
+    /// A task has been added to the queue of the background thread.
+    ///
+    /// Unlike other events, this is emitted on the thread which submitted the
+    /// task. It's not emitted for tasks which are rejected because the thread
+    /// has shut down.
+    Enqueued,
+    /// A task is about to start executing.
+    Started,
+    /// A task finished executing.
+    Finished,
+    /// A task panicked while executing.
+    Panicked,
+}
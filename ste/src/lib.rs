@@ -126,14 +126,22 @@
 //! ID per thread instead which can for example abort a program in case it can't
 //! guarantee uniqueness.
 //!
+//! [Tagged] containers guard against this by also keeping track of whether the
+//! thread that created them is still running, and panic if they're accessed
+//! after it has shut down. Plain [Tag]s don't have this protection.
+//!
 //! [audio]: https://github.com/udoprog/audio
 //! [submit]: https://docs.rs/ste/*/ste/struct.Thread.html#method.submit
 //! [Tag]: https://docs.rs/ste/*/ste/struct.Tag.html
+//! [Tagged]: https://docs.rs/ste/*/ste/struct.Tagged.html
 //! [Thread]: https://docs.rs/ste/*/ste/struct.Thread.html
 
 use std::future::Future;
 use std::io;
+use std::mem::MaybeUninit;
 use std::ptr;
+use std::sync::mpsc;
+use std::time::Instant;
 
 pub(crate) mod loom;
 use self::loom::thread;
@@ -145,12 +153,18 @@ mod parker;
 use crate::parker::Parker;
 
 mod worker;
-use self::worker::{Entry, Prelude, Shared};
+use self::worker::{Entry, Prelude, Shared, TaskHook, TryPrelude};
 
 mod tag;
 use self::tag::with_tag;
 pub use self::tag::Tag;
 
+mod tagged;
+pub use self::tagged::Tagged;
+
+mod thread_registry;
+pub use self::thread_registry::ThreadRegistry;
+
 #[doc(hidden)]
 pub mod linked_list;
 
@@ -160,6 +174,29 @@ use self::wait_future::WaitFuture;
 mod misc;
 use self::misc::RawSend;
 
+mod error;
+pub use self::error::{Cancelled, Panicked};
+
+mod cancel;
+pub use self::cancel::CancelToken;
+
+mod event;
+pub use self::event::TaskEvent;
+
+mod park;
+pub use self::park::ParkStrategy;
+
+mod priority;
+pub use self::priority::ThreadPriority;
+
+mod tasks;
+pub use self::tasks::Tasks;
+
+mod timing;
+pub use self::timing::SubmitTiming;
+
+mod sys;
+
 /// Construct a default background thread executor.
 ///
 /// These both do the same thing, except the builder allows you to catch an OS error:
@@ -174,6 +211,45 @@ pub fn spawn() -> Thread {
     Builder::new().build().expect("failed to spawn thread")
 }
 
+/// Assert that we're currently running on the thread associated with `tag`.
+///
+/// This performs the same check that [Tagged] uses to guard access to its
+/// value, which makes it possible to build custom thread-affine types using a
+/// [Tag] acquired through [Tag::current_thread] or [Thread::tag].
+///
+/// # Panics
+///
+/// Panics if not running on a tagged thread, or if the current thread is not
+/// the one associated with `tag`.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+/// let tag = thread.tag();
+///
+/// thread.submit(move || ste::assert_on_thread(tag));
+///
+/// thread.join();
+/// # Ok(()) }
+/// ```
+///
+/// Asserting from another thread panics:
+///
+/// ```rust,should_panic
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+/// let tag = thread.tag();
+///
+/// let other = ste::spawn();
+/// other.submit(move || ste::assert_on_thread(tag)); // <- oops, this panics!
+/// # Ok(()) }
+/// ```
+pub fn assert_on_thread(tag: Tag) {
+    tag.ensure_on_thread();
+}
+
 /// The handle for a background thread.
 ///
 /// The background thread can be interacted with in a couple of ways:
@@ -249,6 +325,49 @@ unsafe impl Send for Thread {}
 unsafe impl Sync for Thread {}
 
 impl Thread {
+    /// Spawn a background thread and construct a resource on it with
+    /// `construct`, returning the thread together with a [Tagged] handle to
+    /// the resource.
+    ///
+    /// This is the usual way to set up a thread which owns a resource that
+    /// isn't [Send], like an audio device. The resource can only be accessed
+    /// through tasks submitted to the returned thread.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the thread couldn't be spawned, like [Builder::build].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `construct` panics, like [submit][Thread::submit].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use std::cell::Cell;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let (thread, device) = ste::Thread::with_resource(|| Rc::new(Cell::new(0)))?;
+    /// assert_eq!(device.tag(), thread.tag());
+    ///
+    /// thread.submit(|| device.set(device.get() + 42));
+    /// assert_eq!(thread.submit(|| device.get()), 42);
+    ///
+    /// thread.drop(device);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn with_resource<R, C>(construct: C) -> io::Result<(Thread, Tagged<R>)>
+    where
+        C: Send + FnOnce() -> R,
+        R: 'static,
+    {
+        let thread = Builder::new().build()?;
+        let resource = thread.submit(move || Tagged::new(construct()));
+        Ok((thread, resource))
+    }
+
     /// Submit a task to run on the background thread.
     ///
     /// The call will block until it has been executed on the thread (or the
@@ -295,6 +414,277 @@ impl Thread {
     /// # Ok(()) }
     /// ```
     pub fn submit<F, T>(&self, task: F) -> T
+    where
+        F: Send + FnOnce() -> T,
+        T: Send,
+    {
+        match self.try_submit(task) {
+            Ok(output) => output,
+            Err(Panicked) => panic!("background thread panicked"),
+        }
+    }
+
+    /// Submit a task which can be cooperatively cancelled to run on the
+    /// background thread.
+    ///
+    /// The task is passed the `token`, and is expected to poll
+    /// [CancelToken::is_cancelled] and return early once cancellation has been
+    /// requested. A clone of the token can be used to request cancellation
+    /// from another thread while this call is blocked.
+    ///
+    /// If the token is cancelled by the time the task would start, the task is
+    /// not executed. If it is cancelled by the time the task completes, its
+    /// output is discarded. In both cases [Cancelled] is returned.
+    ///
+    /// Panics in the task are propagated like with [submit][Thread::submit].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let token = ste::CancelToken::new();
+    /// let handle = token.clone();
+    ///
+    /// let canceller = std::thread::spawn(move || {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    ///     handle.cancel();
+    /// });
+    ///
+    /// let result = thread.submit_with_cancel(&token, |token| {
+    ///     while !token.is_cancelled() {
+    ///         std::thread::yield_now();
+    ///     }
+    /// });
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// canceller.join().unwrap();
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_with_cancel<F, T>(&self, token: &CancelToken, task: F) -> Result<T, Cancelled>
+    where
+        F: Send + FnOnce(&CancelToken) -> T,
+        T: Send,
+    {
+        let output = self.submit(move || {
+            if token.is_cancelled() {
+                return None;
+            }
+
+            Some(task(token))
+        });
+
+        match output {
+            Some(output) if !token.is_cancelled() => Ok(output),
+            _ => Err(Cancelled),
+        }
+    }
+
+    /// Submit a task to run on the background thread, measuring how long it
+    /// took for it to start executing and how long it executed for.
+    ///
+    /// This behaves like [submit][Thread::submit], except that a panicking
+    /// task is reported as a [Panicked] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let (output, timing) = thread.submit_timed(|| {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    ///     42
+    /// })?;
+    ///
+    /// assert_eq!(output, 42);
+    /// assert!(timing.execution() >= Duration::from_millis(10));
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_timed<F, T>(&self, task: F) -> Result<(T, SubmitTiming), Panicked>
+    where
+        F: Send + FnOnce() -> T,
+        T: Send,
+    {
+        let submitted = Instant::now();
+
+        let (output, started, finished) = self.try_submit(move || {
+            let started = Instant::now();
+            let output = task();
+            (output, started, Instant::now())
+        })?;
+
+        let timing = SubmitTiming {
+            delay: started.saturating_duration_since(submitted),
+            execution: finished.saturating_duration_since(started),
+        };
+
+        Ok((output, timing))
+    }
+
+    /// Submit several tasks with different output types to run together on the
+    /// background thread, collecting their outputs.
+    ///
+    /// The tasks are passed as a tuple of up to 8 closures, see [Tasks]. They
+    /// run one after another in a single round-trip to the background thread,
+    /// which avoids the latency of submitting them one at a time.
+    ///
+    /// If any task panics, the remaining tasks aren't run and [Panicked] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let (a, b, c) = thread.submit_all((|| 1u32, || "x".to_string(), || vec![1.0f32; 4]))?;
+    ///
+    /// assert_eq!(a, 1);
+    /// assert_eq!(b, "x");
+    /// assert_eq!(c.len(), 4);
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_all<T>(&self, tasks: T) -> Result<T::Output, Panicked>
+    where
+        T: Send + Tasks,
+        T::Output: Send,
+    {
+        self.try_submit(move || tasks.run())
+    }
+
+    /// Submit a task to run on the background thread, writing its output
+    /// directly into the caller-provided slot `out`.
+    ///
+    /// This avoids moving the output of the task through intermediate storage,
+    /// which can matter for large outputs like audio buffers stored in
+    /// arrays.
+    ///
+    /// If this returns `Ok(())`, `out` has been initialized exactly once. If
+    /// the task panicked, [Panicked] is returned and `out` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::mem::MaybeUninit;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let mut out = MaybeUninit::<[f32; 4096]>::uninit();
+    /// thread.submit_into(&mut out, || [1.0; 4096])?;
+    ///
+    /// // Safety: submit_into returned successfully.
+    /// let out = unsafe { out.assume_init() };
+    /// assert!(out.iter().all(|s| *s == 1.0));
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_into<F, T>(&self, out: &mut MaybeUninit<T>, task: F) -> Result<(), Panicked>
+    where
+        F: Send + FnOnce() -> T,
+        T: Send,
+    {
+        let out = RawSend(ptr::NonNull::from(out));
+
+        self.try_submit(move || {
+            let RawSend(out) = out;
+
+            // Safety: the slot is exclusively borrowed for the duration of the
+            // submission, which is blocked on until this task has completed.
+            // Nothing is written if the task panics.
+            unsafe {
+                (*out.as_ptr()).as_mut_ptr().write(task());
+            }
+        })
+    }
+
+    /// Submit a task to run on the background thread without waiting for it to
+    /// complete. Once it has completed, its output is sent through `tx`.
+    ///
+    /// This bridges the background thread into channel-based architectures,
+    /// like an event loop which can't block on [submit][Thread::submit].
+    /// Since the caller doesn't wait for it, the task must be `'static` and
+    /// can't borrow from the caller's environment.
+    ///
+    /// If the receiving end of the channel has been disconnected by the time
+    /// the task completes, the output is dropped. If the task panics, or is
+    /// cancelled through [cancel_pending][Thread::cancel_pending], nothing is
+    /// sent and `tx` is dropped. Tasks which are still queued up when the
+    /// thread is joined are executed before it shuts down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the background thread has ended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::mpsc;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    /// let (tx, rx) = mpsc::channel();
+    ///
+    /// for n in 0..4u32 {
+    ///     thread.submit_to_sender(move || n * 2, tx.clone());
+    /// }
+    ///
+    /// drop(tx);
+    ///
+    /// let mut results = rx.iter().collect::<Vec<_>>();
+    /// results.sort();
+    /// assert_eq!(results, vec![0, 2, 4, 6]);
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_to_sender<F, T>(&self, task: F, tx: mpsc::Sender<T>)
+    where
+        F: Send + FnOnce() -> T + 'static,
+        T: Send + 'static,
+    {
+        use std::panic;
+
+        let mut task = Some(task);
+
+        let entry = Entry::detached(move |tag| {
+            if let Some(task) = task.take() {
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(|| with_tag(tag, task)));
+
+                match result {
+                    Ok(output) => {
+                        // NB: a disconnected receiver isn't interested in the
+                        // output, so it's simply dropped.
+                        let _ = tx.send(output);
+                    }
+                    Err(..) => return false,
+                }
+            }
+
+            true
+        });
+
+        // Safety: the entry was just allocated as a detached entry.
+        unsafe {
+            self.shared.as_ref().schedule_detached(entry);
+        }
+    }
+
+    /// Submit a task, reporting a panic in it as an error.
+    fn try_submit<F, T>(&self, task: F) -> Result<T, Panicked>
     where
         F: Send + FnOnce() -> T,
         T: Send,
@@ -315,13 +705,13 @@ impl Thread {
                 .as_ref()
                 .schedule_in_place(ptr::NonNull::from(&parker), entry);
 
-            return match storage {
-                Some(result) => result,
-                None => panic!("background thread panicked"),
-            };
+            return storage.ok_or(Panicked);
         }
 
-        fn into_task<T, O>(task: T, mut storage: RawSend<Option<O>>) -> impl FnMut(Tag) + Send
+        fn into_task<T, O>(
+            task: T,
+            mut storage: RawSend<Option<O>>,
+        ) -> impl FnMut(Tag) -> bool + Send
         where
             T: FnOnce() -> O + Send,
             O: Send,
@@ -332,7 +722,7 @@ impl Thread {
 
             move |tag| {
                 if let Some(task) = task.take() {
-                    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
                         let output = with_tag(tag, task);
 
                         // Safety: we're the only one with access to this pointer,
@@ -341,7 +731,11 @@ impl Thread {
                             *storage.0.as_mut() = Some(output);
                         }
                     }));
+
+                    return result.is_ok();
                 }
+
+                true
             }
         }
     }
@@ -444,6 +838,93 @@ impl Thread {
         self.submit(move || drop(value));
     }
 
+    /// Construct a resource on the background thread with `construct`, run
+    /// `run` with it, and drop it on the background thread, all in a single
+    /// round-trip.
+    ///
+    /// Since the resource never leaves the background thread it doesn't have
+    /// to be [Send], which avoids having to juggle [Tagged] containers and
+    /// separate calls to [drop][Thread::drop] for resources that are only
+    /// needed for a single operation. Like opening a device handle, using it,
+    /// and closing it.
+    ///
+    /// The resource is dropped on the background thread even if `run` panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `construct` or `run` panics, like [submit][Thread::submit].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// struct Foo {
+    ///     tag: ste::Tag,
+    ///     data: Rc<u32>,
+    /// }
+    ///
+    /// impl Drop for Foo {
+    ///     fn drop(&mut self) {
+    ///         self.tag.ensure_on_thread();
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let value = thread.construct_and_run(
+    ///     || Foo { tag: ste::Tag::current_thread(), data: Rc::new(21) },
+    ///     |foo| *foo.data * 2,
+    /// );
+    ///
+    /// assert_eq!(value, 42);
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn construct_and_run<C, T, F, R>(&self, construct: C, run: F) -> R
+    where
+        C: Send + FnOnce() -> T,
+        F: Send + FnOnce(&mut T) -> R,
+        R: Send,
+    {
+        self.submit(move || {
+            let mut resource = construct();
+            run(&mut resource)
+        })
+    }
+
+    /// Cancel all tasks which have been submitted but have not yet started
+    /// executing on the background thread, returning how many were cancelled.
+    ///
+    /// The callers of cancelled tasks are unblocked as if their task had
+    /// panicked. That is, [submit][Thread::submit] will panic,
+    /// [submit_timed][Thread::submit_timed] will return [Panicked], and the
+    /// future returned by [submit_async][Thread::submit_async] will panic when
+    /// polled. Tasks submitted with
+    /// [submit_to_sender][Thread::submit_to_sender] are dropped without
+    /// sending anything. The task which is currently executing, if any, is not
+    /// affected.
+    ///
+    /// This is useful when work which has been queued up has become obsolete.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// // Nothing is queued up.
+    /// assert_eq!(thread.cancel_pending(), 0);
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn cancel_pending(&self) -> usize {
+        unsafe { self.shared.as_ref().cancel_pending() }
+    }
+
     /// Join the background thread.
     ///
     /// Will block until the background thread is joined.
@@ -475,6 +956,53 @@ impl Thread {
         }
     }
 
+    /// Try to join the background thread if it's idle, or give the thread
+    /// back if it's still busy so that joining can be retried later.
+    ///
+    /// Since this takes the thread by value no new tasks can be submitted to
+    /// it concurrently, so the background thread is only busy while it's
+    /// running its [prelude][Builder::prelude].
+    ///
+    /// If the thread is joined, the inner result reports whether the
+    /// background thread panicked, instead of panicking like
+    /// [join][Thread::join] does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut thread = ste::spawn();
+    ///
+    /// let mut n = 10;
+    /// thread.submit(|| n += 10);
+    /// assert_eq!(20, n);
+    ///
+    /// loop {
+    ///     match thread.try_join() {
+    ///         Ok(result) => break result?,
+    ///         Err(t) => thread = t,
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn try_join(mut self) -> Result<Result<(), Panicked>, Thread> {
+        if let Some(handle) = self.handle.take() {
+            // Safety: the shared state is valid for as long as the thread is.
+            if !unsafe { self.shared.as_ref().is_idle() } {
+                self.handle = Some(handle);
+                return Err(self);
+            }
+
+            unsafe { self.shared.as_ref().outer_join() };
+
+            if handle.join().is_err() {
+                return Ok(Err(Panicked));
+            }
+        }
+
+        Ok(Ok(()))
+    }
+
     /// Construct the tag that is associated with the current thread externally
     /// from the thread.
     ///
@@ -505,6 +1033,50 @@ impl Thread {
     pub fn tag(&self) -> Tag {
         Tag(self.shared.as_ptr() as usize)
     }
+
+    /// Rename the background thread, as reported by the operating system to
+    /// tools like debuggers and profilers.
+    ///
+    /// This is useful for long-lived threads which are repurposed, so that
+    /// their name can track their current role. Names which are too long for
+    /// the platform are truncated, like to 15 bytes on Linux.
+    ///
+    /// Note that this doesn't change the name reported by
+    /// [std::thread::Thread::name], which is fixed when the thread is spawned.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the name contains a nul byte, if renaming fails, or if it's
+    /// not supported on the current platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// if thread.set_worker_name("mixer").is_ok() {
+    ///     assert_eq!(thread.worker_name()?, "mixer");
+    /// }
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn set_worker_name(&self, name: &str) -> io::Result<()> {
+        self.submit(|| sys::set_name(name))
+    }
+
+    /// Get the name of the background thread, as reported by the operating
+    /// system.
+    ///
+    /// See [Thread::set_worker_name].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the name can't be queried on the current platform.
+    pub fn worker_name(&self) -> io::Result<String> {
+        self.submit(sys::name)
+    }
 }
 
 impl Drop for Thread {
@@ -534,6 +1106,13 @@ impl Drop for Thread {
 /// The builder for a [Thread] which can be configured a bit more.
 pub struct Builder {
     prelude: Option<Box<Prelude>>,
+    try_prelude: Option<Box<TryPrelude>>,
+    core: Option<usize>,
+    priority: Option<ThreadPriority>,
+    fair: bool,
+    park: ParkStrategy,
+    submit_spin: usize,
+    on_task: Option<Box<TaskHook>>,
     #[cfg(feature = "tokio")]
     tokio: Option<tokio::runtime::Handle>,
 }
@@ -543,6 +1122,13 @@ impl Builder {
     pub fn new() -> Self {
         Self {
             prelude: None,
+            try_prelude: None,
+            core: None,
+            priority: None,
+            fair: false,
+            park: ParkStrategy::default(),
+            submit_spin: 0,
+            on_task: None,
             #[cfg(feature = "tokio")]
             tokio: None,
         }
@@ -601,6 +1187,229 @@ impl Builder {
         }
     }
 
+    /// Configure a fallible prelude to the [Thread]. This is code that will
+    /// run just as the thread is spinning up, before the
+    /// [prelude][Builder::prelude] if one is configured.
+    ///
+    /// If the prelude returns an error, the background thread exits without
+    /// processing any tasks and [Builder::build] returns the error. This is
+    /// useful for initialization which might legitimately fail, like opening
+    /// an audio device.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::Builder::new().try_prelude(|| Ok(())).build()?;
+    /// thread.join();
+    ///
+    /// let result = ste::Builder::new()
+    ///     .try_prelude(|| Err(io::Error::new(io::ErrorKind::NotFound, "no device")))
+    ///     .build();
+    ///
+    /// assert!(result.is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn try_prelude<P>(self, prelude: P) -> Self
+    where
+        P: FnOnce() -> io::Result<()> + Send + 'static,
+    {
+        Self {
+            try_prelude: Some(Box::new(prelude)),
+            ..self
+        }
+    }
+
+    /// Pin the background thread to the CPU core with the given id.
+    ///
+    /// Running the background thread on a dedicated core reduces scheduling
+    /// jitter. This is currently supported on Linux and Windows, on other
+    /// platforms [Builder::build] will return an error.
+    ///
+    /// The affinity is set before the [prelude][Builder::prelude] is run, and
+    /// if the core id is invalid [Builder::build] will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = match ste::Builder::new().pin_to_core(0).build() {
+    ///     Ok(thread) => thread,
+    ///     Err(e) => {
+    ///         println!("running without pinning: {}", e);
+    ///         ste::Builder::new().build()?
+    ///     }
+    /// };
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn pin_to_core(self, core: usize) -> Self {
+        Self {
+            core: Some(core),
+            ..self
+        }
+    }
+
+    /// Set the scheduling priority of the background thread.
+    ///
+    /// Low-latency audio processing typically needs an elevated priority to
+    /// avoid dropouts. See [ThreadPriority] for the capabilities required on
+    /// each platform. If the priority can't be set, for example due to
+    /// insufficient permissions, [Builder::build] will return the error.
+    ///
+    /// The priority is set before the [prelude][Builder::prelude] is run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ste::ThreadPriority;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = match ste::Builder::new().priority(ThreadPriority::RealTime).build() {
+    ///     Ok(thread) => thread,
+    ///     Err(e) => {
+    ///         println!("falling back to normal priority: {}", e);
+    ///         ste::Builder::new().build()?
+    ///     }
+    /// };
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn priority(self, priority: ThreadPriority) -> Self {
+        Self {
+            priority: Some(priority),
+            ..self
+        }
+    }
+
+    /// Enable or disable fair scheduling of tasks.
+    ///
+    /// By default the background thread takes every task which is queued up
+    /// and runs all of them before it looks for new tasks. This has the best
+    /// throughput, but a flood of tasks can delay one that was submitted just
+    /// after it.
+    ///
+    /// In fair mode, the background thread looks for newly submitted tasks
+    /// after each task, and runs them before the remaining tasks in the batch
+    /// it's currently working on. This improves the latency of newly
+    /// submitted tasks at the cost of more synchronization for each task.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::Builder::new().fair(true).build()?;
+    /// assert_eq!(thread.submit(|| 42), 42);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn fair(self, fair: bool) -> Self {
+        Self { fair, ..self }
+    }
+
+    /// Configure how the background thread waits for new tasks when it has
+    /// nothing to do.
+    ///
+    /// Blocking as soon as there's nothing to do uses the least CPU, but
+    /// waking a blocked thread goes through the operating system which adds
+    /// latency to the next submitted task. Spinning avoids that at the cost of
+    /// burning CPU while idle. See [ParkStrategy] for the available
+    /// strategies. The default is [ParkStrategy::SpinThenPark].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ste::ParkStrategy;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::Builder::new()
+    ///     .park_strategy(ParkStrategy::Block)
+    ///     .build()?;
+    ///
+    /// assert_eq!(thread.submit(|| 42), 42);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn park_strategy(self, park: ParkStrategy) -> Self {
+        Self { park, ..self }
+    }
+
+    /// Configure how many times a submitting thread busy-waits for its task to
+    /// complete before it parks.
+    ///
+    /// Parking and being woken up again goes through the operating system,
+    /// which for very short tasks like parameter updates can take longer than
+    /// the task itself. Spinning for a while first lets such tasks complete
+    /// without the submitter ever parking, at the cost of burning CPU on the
+    /// submitting thread while it waits for longer tasks. The default is `0`,
+    /// which parks immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::Builder::new().submit_spin(1000).build()?;
+    /// assert_eq!(thread.submit(|| 42), 42);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn submit_spin(self, submit_spin: usize) -> Self {
+        Self {
+            submit_spin,
+            ..self
+        }
+    }
+
+    /// Install a hook which is called with [TaskEvent]s as tasks move through
+    /// the background thread.
+    ///
+    /// This can be used for tracing or collecting metrics. With the exception
+    /// of [TaskEvent::Enqueued], the hook is called on the background thread
+    /// while it's processing tasks. [TaskEvent::Enqueued] is called on the
+    /// submitting thread once the task is in the queue, while the queue is
+    /// locked. So the hook must be fast, must not block, and must not submit
+    /// tasks to the thread. If the hook panics the panic is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use ste::TaskEvent;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let finished = Arc::new(AtomicUsize::new(0));
+    /// let finished2 = finished.clone();
+    ///
+    /// let thread = ste::Builder::new()
+    ///     .on_task(move |event| {
+    ///         if let TaskEvent::Finished = event {
+    ///             finished2.fetch_add(1, Ordering::SeqCst);
+    ///         }
+    ///     })
+    ///     .build()?;
+    ///
+    /// thread.submit(|| ());
+    /// thread.submit(|| ());
+    /// assert_eq!(finished.load(Ordering::SeqCst), 2);
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn on_task<F>(self, f: F) -> Self
+    where
+        F: Fn(TaskEvent) + Send + Sync + 'static,
+    {
+        Self {
+            on_task: Some(Box::new(f)),
+            ..self
+        }
+    }
+
     /// Construct the background thread.
     ///
     /// # Examples
@@ -612,14 +1421,26 @@ impl Builder {
     /// # Ok(()) }
     /// ```
     pub fn build(self) -> io::Result<Thread> {
-        let shared = ptr::NonNull::from(Box::leak(Box::new(Shared::new())));
+        let shared = ptr::NonNull::from(Box::leak(Box::new(Shared::new(
+            self.on_task,
+            self.submit_spin,
+        ))));
 
         let prelude = self.prelude;
+        let try_prelude = self.try_prelude;
+        let core = self.core;
+        let priority = self.priority;
+        let fair = self.fair;
+        let park = self.park;
         #[cfg(feature = "tokio")]
         let tokio = self.tokio;
 
         let shared2 = RawSend(shared);
 
+        // The outcome of configuring the background thread is sent back here,
+        // so that any errors can be reported before we return.
+        let (setup_tx, setup_rx) = mpsc::sync_channel(1);
+
         let handle = thread::Builder::new()
             .name(String::from("ste-thread"))
             .spawn(move || {
@@ -628,12 +1449,53 @@ impl Builder {
                 #[cfg(feature = "tokio")]
                 let _guard = tokio.as_ref().map(|h| h.enter());
 
-                worker::run(prelude, shared)
+                let result = setup(core, priority).and_then(|()| match try_prelude {
+                    Some(try_prelude) => try_prelude(),
+                    None => Ok(()),
+                });
+
+                let failed = result.is_err();
+                let _ = setup_tx.send(result);
+
+                if failed {
+                    return;
+                }
+
+                worker::run(prelude, shared, fair, park)
             })?;
 
+        let result = setup_rx
+            .recv()
+            .unwrap_or_else(|_| Err(io::Error::other("background thread failed to start")));
+
+        if let Err(e) = result {
+            let _ = handle.join();
+
+            // Safety: the background thread has been joined without ever
+            // touching the shared state.
+            unsafe {
+                let _ = Box::from_raw(shared.as_ptr());
+            }
+
+            return Err(e);
+        }
+
         Ok(Thread {
             shared,
             handle: Some(handle),
         })
     }
 }
+
+/// Configure the current thread before it starts processing tasks.
+fn setup(core: Option<usize>, priority: Option<ThreadPriority>) -> io::Result<()> {
+    if let Some(core) = core {
+        sys::pin_to_core(core)?;
+    }
+
+    if let Some(priority) = priority {
+        sys::set_priority(priority)?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 //! An intrusive linked list.
 
+use std::mem;
 use std::ptr;
 
 /// A node in the intrusive [LinkedList].
@@ -27,6 +28,7 @@ impl<T> Node<T> {
 pub struct LinkedList<T> {
     first: Option<ptr::NonNull<Node<T>>>,
     last: Option<ptr::NonNull<Node<T>>>,
+    len: usize,
 }
 
 impl<T> LinkedList<T> {
@@ -35,6 +37,7 @@ impl<T> LinkedList<T> {
         Self {
             first: None,
             last: None,
+            len: 0,
         }
     }
 
@@ -51,12 +54,41 @@ impl<T> LinkedList<T> {
         self.first.is_none()
     }
 
+    /// Get the number of elements in the linked list.
+    ///
+    /// This is kept track of as elements are pushed and popped, so it doesn't
+    /// have to walk the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ptr;
+    /// use ste::linked_list::{Node, LinkedList};
+    ///
+    /// let mut list = LinkedList::new();
+    /// assert_eq!(list.len(), 0);
+    ///
+    /// let mut a = Node::new(0);
+    ///
+    /// unsafe {
+    ///     list.push_front(ptr::NonNull::from(&mut a));
+    ///     assert_eq!(list.len(), 1);
+    ///
+    ///     list.pop_back();
+    ///     assert_eq!(list.len(), 0);
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
     /// Steal the entire contents of the linked list, removing it from the list
     /// that we stole it from.
     pub fn steal(&mut self) -> Self {
         Self {
             first: self.first.take(),
             last: self.last.take(),
+            len: mem::take(&mut self.len),
         }
     }
 
@@ -102,6 +134,8 @@ impl<T> LinkedList<T> {
     /// assert_eq!(b.value, 2);
     /// ```
     pub unsafe fn push_front(&mut self, mut node: ptr::NonNull<Node<T>>) -> bool {
+        self.len += 1;
+
         if let Some(mut first) = self.first.take() {
             node.as_mut().next = Some(first);
             first.as_mut().prev = Some(node);
@@ -156,6 +190,8 @@ impl<T> LinkedList<T> {
     /// assert_eq!(b.value, 1);
     /// ```
     pub unsafe fn push_back(&mut self, mut node: ptr::NonNull<Node<T>>) -> bool {
+        self.len += 1;
+
         if let Some(mut last) = self.last.take() {
             node.as_mut().prev = Some(last);
             last.as_mut().next = Some(node);
@@ -198,6 +234,7 @@ impl<T> LinkedList<T> {
     /// ```
     pub unsafe fn pop_front(&mut self) -> Option<ptr::NonNull<Node<T>>> {
         let mut first = self.first?;
+        self.len -= 1;
 
         if let Some(mut next) = first.as_mut().next.take() {
             next.as_mut().prev = None;
@@ -242,6 +279,7 @@ impl<T> LinkedList<T> {
     /// ```
     pub unsafe fn pop_back(&mut self) -> Option<ptr::NonNull<Node<T>>> {
         let mut last = self.last?;
+        self.len -= 1;
 
         if let Some(mut prev) = last.as_mut().prev.take() {
             prev.as_mut().next = None;
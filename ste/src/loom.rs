@@ -1,8 +1,12 @@
 #[cfg(loom)]
+pub use loom::hint;
+#[cfg(loom)]
 pub use loom::sync;
 #[cfg(loom)]
 pub use loom::thread;
 
+#[cfg(not(loom))]
+pub use ::std::hint;
 #[cfg(not(loom))]
 pub use ::std::sync;
 #[cfg(not(loom))]
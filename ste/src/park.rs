@@ -0,0 +1,28 @@
+/// How the background [Thread][crate::Thread] waits for new tasks when its
+/// queue is empty.
+///
+/// This trades wakeup latency against CPU usage while the thread is idle. See
+/// [Builder::park_strategy][crate::Builder::park_strategy].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParkStrategy {
+    /// Block on a condition variable as soon as the queue is empty.
+    ///
+    /// This uses the least CPU while idle, but every task submitted to an idle
+    /// thread has to wake it up through the operating system.
+    Block,
+    /// Yield a few times to catch tasks which are submitted in quick
+    /// succession without having to block, and then block like
+    /// [ParkStrategy::Block].
+    ///
+    /// This is the default.
+    #[default]
+    SpinThenPark,
+    /// Never block, and instead busy-wait until a task is submitted.
+    ///
+    /// This has the lowest wakeup latency, but keeps a CPU core fully occupied
+    /// for as long as the thread is running, even when it's idle. It's only
+    /// suitable when the thread has a core to itself, like when it's been
+    /// pinned with [Builder::pin_to_core][crate::Builder::pin_to_core].
+    Spin,
+}
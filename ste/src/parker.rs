@@ -4,9 +4,11 @@
 //
 // See: https://github.com/tokio-rs/tokio/blob/master/LICENSE
 
+use crate::loom::hint;
 use crate::loom::sync::atomic::{AtomicUsize, Ordering};
 use crate::loom::sync::{Condvar, Mutex};
 use crate::loom::thread;
+use crate::park::ParkStrategy;
 
 const EMPTY: usize = 0;
 const PARKED_CONDVAR: usize = 1;
@@ -53,10 +55,29 @@ impl Parker {
         }
     }
 
-    pub(crate) fn park(&self) {
+    /// Busy-wait for up to `spins` iterations to be notified, before parking
+    /// the current thread.
+    pub(crate) fn park_after_spin(&self, spins: usize) {
+        for _ in 0..spins {
+            if self.inner.state.compare_exchange(NOTIFIED, EMPTY).is_ok() {
+                return;
+            }
+
+            hint::spin_loop();
+        }
+
         self.inner.park()
     }
 
+    /// Park the current thread using the given strategy.
+    pub(crate) fn park_with(&self, strategy: ParkStrategy) {
+        match strategy {
+            ParkStrategy::Block => self.inner.park_condvar(),
+            ParkStrategy::SpinThenPark => self.inner.park(),
+            ParkStrategy::Spin => self.inner.park_spin(),
+        }
+    }
+
     pub(crate) fn unpark(&self) {
         self.inner.unpark()
     }
@@ -78,6 +99,13 @@ impl Inner {
         self.park_condvar();
     }
 
+    /// Busy-wait until we're notified.
+    fn park_spin(&self) {
+        while self.state.compare_exchange(NOTIFIED, EMPTY).is_err() {
+            hint::spin_loop();
+        }
+    }
+
     fn park_condvar(&self) {
         // Otherwise we need to coordinate going to sleep
         let mut m = self.mutex.lock().unwrap();
@@ -0,0 +1,23 @@
+/// The scheduling priority of a background [Thread][crate::Thread].
+///
+/// See [Builder::priority][crate::Builder::priority].
+///
+/// # Capabilities
+///
+/// Raising the priority typically requires additional privileges:
+/// * On Linux, [ThreadPriority::RealTime] uses the `SCHED_FIFO` scheduling
+///   class which requires `CAP_SYS_NICE` or a sufficient `RLIMIT_RTPRIO`
+///   (e.g. through `/etc/security/limits.conf`).
+/// * On Windows, [ThreadPriority::RealTime] maps to
+///   `THREAD_PRIORITY_TIME_CRITICAL` which doesn't require any additional
+///   privileges.
+///
+/// On other platforms only [ThreadPriority::Normal] is supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreadPriority {
+    /// The default priority of the operating system.
+    Normal,
+    /// Real-time priority, the highest priority available for the thread.
+    RealTime,
+}
@@ -0,0 +1,334 @@
+//! Platform-specific configuration of the background thread.
+//!
+//! We don't depend on `libc` or `winapi`, so the handful of functions needed
+//! are declared here directly.
+
+use crate::priority::ThreadPriority;
+use std::io;
+
+/// Pin the current thread to the core with the given id.
+pub(crate) fn pin_to_core(core: usize) -> io::Result<()> {
+    imp::pin_to_core(core).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("failed to pin thread to core {}: {}", core, e),
+        )
+    })
+}
+
+/// Set the scheduling priority of the current thread.
+pub(crate) fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+    imp::set_priority(priority).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("failed to set thread priority to {:?}: {}", priority, e),
+        )
+    })
+}
+
+/// Set the name of the current thread, as reported by the operating system.
+///
+/// Names which are too long for the platform are truncated.
+pub(crate) fn set_name(name: &str) -> io::Result<()> {
+    if name.contains('\0') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "thread name must not contain nul bytes",
+        ));
+    }
+
+    imp::set_name(name)
+        .map_err(|e| io::Error::new(e.kind(), format!("failed to set thread name: {}", e)))
+}
+
+/// Get the name of the current thread, as reported by the operating system.
+pub(crate) fn name() -> io::Result<String> {
+    imp::name().map_err(|e| io::Error::new(e.kind(), format!("failed to get thread name: {}", e)))
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use crate::priority::ThreadPriority;
+    use std::io;
+    use std::mem;
+    use std::os::raw::c_char;
+
+    /// The maximum length of a thread name, excluding the nul terminator.
+    const NAME_MAX: usize = 15;
+
+    /// The number of cpus representable in a `cpu_set_t`.
+    const CPU_SETSIZE: usize = 1024;
+    const SCHED_OTHER: i32 = 0;
+    const SCHED_FIFO: i32 = 1;
+
+    #[repr(C)]
+    struct SchedParam {
+        sched_priority: i32,
+    }
+
+    extern "C" {
+        fn sched_setaffinity(pid: i32, cpusetsize: usize, mask: *const u64) -> i32;
+        fn sched_get_priority_max(policy: i32) -> i32;
+        fn pthread_self() -> usize;
+        fn pthread_setschedparam(thread: usize, policy: i32, param: *const SchedParam) -> i32;
+        fn pthread_setname_np(thread: usize, name: *const c_char) -> i32;
+        fn pthread_getname_np(thread: usize, name: *mut c_char, len: usize) -> i32;
+    }
+
+    pub(super) fn pin_to_core(core: usize) -> io::Result<()> {
+        if core >= CPU_SETSIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "core id out of range",
+            ));
+        }
+
+        let mut set = [0u64; CPU_SETSIZE / 64];
+        set[core / 64] |= 1 << (core % 64);
+
+        // Safety: the set is correctly sized, and pid 0 refers to the calling
+        // thread.
+        if unsafe { sched_setaffinity(0, mem::size_of_val(&set), set.as_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        let (policy, sched_priority) = match priority {
+            ThreadPriority::Normal => (SCHED_OTHER, 0),
+            ThreadPriority::RealTime => {
+                // Safety: this is a simple query without side effects.
+                let max = unsafe { sched_get_priority_max(SCHED_FIFO) };
+
+                if max < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                (SCHED_FIFO, max)
+            }
+        };
+
+        let param = SchedParam { sched_priority };
+
+        // Safety: we're passing a valid parameter for the calling thread.
+        match unsafe { pthread_setschedparam(pthread_self(), policy, &param) } {
+            0 => Ok(()),
+            // NB: this returns the error directly instead of through errno.
+            errno => Err(io::Error::from_raw_os_error(errno)),
+        }
+    }
+
+    pub(super) fn set_name(name: &str) -> io::Result<()> {
+        let mut end = usize::min(name.len(), NAME_MAX);
+
+        // NB: don't split a multi-byte character.
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let mut buf = [0u8; NAME_MAX + 1];
+        buf[..end].copy_from_slice(&name.as_bytes()[..end]);
+
+        // Safety: the name is nul terminated and at most as long as the
+        // platform allows.
+        match unsafe { pthread_setname_np(pthread_self(), buf.as_ptr() as *const c_char) } {
+            0 => Ok(()),
+            errno => Err(io::Error::from_raw_os_error(errno)),
+        }
+    }
+
+    pub(super) fn name() -> io::Result<String> {
+        let mut buf = [0u8; NAME_MAX + 1];
+
+        // Safety: the buffer is as large as the longest possible name.
+        match unsafe {
+            pthread_getname_np(pthread_self(), buf.as_mut_ptr() as *mut c_char, buf.len())
+        } {
+            0 => {
+                let len = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+                Ok(String::from_utf8_lossy(&buf[..len]).into_owned())
+            }
+            errno => Err(io::Error::from_raw_os_error(errno)),
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use crate::priority::ThreadPriority;
+    use std::ffi::c_void;
+    use std::io;
+    use std::mem;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetCurrentThread() -> *mut c_void;
+        fn SetThreadAffinityMask(thread: *mut c_void, mask: usize) -> usize;
+        fn SetThreadPriority(thread: *mut c_void, priority: i32) -> i32;
+        fn GetModuleHandleA(module: *const u8) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, name: *const u8) -> *mut c_void;
+        fn LocalFree(mem: *mut c_void) -> *mut c_void;
+    }
+
+    type SetThreadDescription =
+        unsafe extern "system" fn(thread: *mut c_void, description: *const u16) -> i32;
+    type GetThreadDescription =
+        unsafe extern "system" fn(thread: *mut c_void, description: *mut *mut u16) -> i32;
+
+    const THREAD_PRIORITY_NORMAL: i32 = 0;
+    const THREAD_PRIORITY_TIME_CRITICAL: i32 = 15;
+    const FACILITY_WIN32: i32 = 7;
+
+    /// Look up a function in kernel32 by its nul terminated name.
+    ///
+    /// The thread description functions are only available since Windows 10
+    /// 1607, so like std we resolve them at runtime instead of linking to
+    /// them.
+    fn kernel32(name: &[u8]) -> io::Result<*mut c_void> {
+        debug_assert_eq!(name.last(), Some(&0));
+
+        // Safety: both names are nul terminated, and kernel32 is always
+        // loaded into the process.
+        let function = unsafe {
+            let module = GetModuleHandleA(b"kernel32.dll\0".as_ptr());
+
+            if module.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+
+            GetProcAddress(module, name.as_ptr())
+        };
+
+        if function.is_null() {
+            return Err(io::Error::other("not supported on this version of windows"));
+        }
+
+        Ok(function)
+    }
+
+    /// Convert a failed HRESULT into an I/O error.
+    fn hresult_error(hresult: i32) -> io::Error {
+        // HRESULT_FACILITY and HRESULT_CODE.
+        if (hresult >> 16) & 0x1fff == FACILITY_WIN32 {
+            io::Error::from_raw_os_error(hresult & 0xffff)
+        } else {
+            io::Error::other(format!("HRESULT {:#010x}", hresult))
+        }
+    }
+
+    pub(super) fn pin_to_core(core: usize) -> io::Result<()> {
+        if core >= mem::size_of::<usize>() * 8 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "core id out of range",
+            ));
+        }
+
+        // Safety: GetCurrentThread returns a pseudo handle which is always
+        // valid for the calling thread.
+        if unsafe { SetThreadAffinityMask(GetCurrentThread(), 1 << core) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        let priority = match priority {
+            ThreadPriority::Normal => THREAD_PRIORITY_NORMAL,
+            ThreadPriority::RealTime => THREAD_PRIORITY_TIME_CRITICAL,
+        };
+
+        // Safety: GetCurrentThread returns a pseudo handle which is always
+        // valid for the calling thread.
+        if unsafe { SetThreadPriority(GetCurrentThread(), priority) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn set_name(name: &str) -> io::Result<()> {
+        // Safety: the function resolved matches the signature of
+        // SetThreadDescription.
+        let set_thread_description = unsafe {
+            mem::transmute::<*mut c_void, SetThreadDescription>(kernel32(
+                b"SetThreadDescription\0",
+            )?)
+        };
+
+        let name = name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+
+        // Safety: the name is nul terminated, and GetCurrentThread returns a
+        // pseudo handle which is always valid for the calling thread.
+        match unsafe { set_thread_description(GetCurrentThread(), name.as_ptr()) } {
+            hresult if hresult < 0 => Err(hresult_error(hresult)),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn name() -> io::Result<String> {
+        // Safety: the function resolved matches the signature of
+        // GetThreadDescription.
+        let get_thread_description = unsafe {
+            mem::transmute::<*mut c_void, GetThreadDescription>(kernel32(
+                b"GetThreadDescription\0",
+            )?)
+        };
+
+        let mut description = std::ptr::null_mut();
+
+        // Safety: GetCurrentThread returns a pseudo handle which is always
+        // valid for the calling thread.
+        let hresult = unsafe { get_thread_description(GetCurrentThread(), &mut description) };
+
+        if hresult < 0 {
+            return Err(hresult_error(hresult));
+        }
+
+        // Safety: on success the description is a valid nul terminated
+        // string, which we're responsible for freeing.
+        unsafe {
+            let mut len = 0;
+
+            while *description.add(len) != 0 {
+                len += 1;
+            }
+
+            let name = String::from_utf16_lossy(std::slice::from_raw_parts(description, len));
+            LocalFree(description as *mut c_void);
+            Ok(name)
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+mod imp {
+    use crate::priority::ThreadPriority;
+    use std::io;
+
+    pub(super) fn pin_to_core(_: usize) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub(super) fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        match priority {
+            ThreadPriority::Normal => Ok(()),
+            _ => Err(unsupported()),
+        }
+    }
+
+    pub(super) fn set_name(_: &str) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub(super) fn name() -> io::Result<String> {
+        Err(unsupported())
+    }
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Other, "not supported on this platform")
+    }
+}
@@ -1,8 +1,33 @@
-use std::cell::Cell;
+use crate::loom::sync::Arc;
+use crate::tagged::Registry;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 
 thread_local! {
     static THREAD_TAG: Cell<Tag> = Cell::new(Tag(0));
+    static THREAD_REGISTRY: RefCell<Option<Arc<Registry>>> = RefCell::new(None);
+}
+
+/// Associate the registry of tagged values of a worker with the current
+/// thread.
+pub(super) fn set_registry(registry: Arc<Registry>) {
+    THREAD_REGISTRY.with(|w| {
+        *w.borrow_mut() = Some(registry);
+    });
+}
+
+/// Get the registry of tagged values associated with the current thread, if
+/// it's a worker.
+pub(super) fn current_registry() -> Option<Arc<Registry>> {
+    THREAD_REGISTRY.with(|w| w.borrow().clone())
+}
+
+/// Get the tag associated with the current thread, if it's tagged.
+pub(super) fn current_tag() -> Option<Tag> {
+    match THREAD_TAG.with(|tag| tag.get()) {
+        Tag(0) => None,
+        tag => Some(tag),
+    }
 }
 
 /// Run the given closure with the specified tag.
@@ -132,9 +157,9 @@ impl Tag {
     /// Panics if not running on a tagged thread. Tagged threads are the ones
     /// created with [Thread][super::Thread].
     pub fn current_thread() -> Self {
-        match THREAD_TAG.with(|tag| tag.get()) {
-            Tag(0) => panic!("not running on a tagged thread"),
-            tag => tag,
+        match current_tag() {
+            Some(tag) => tag,
+            None => panic!("not running on a tagged thread"),
         }
     }
 
@@ -166,10 +191,7 @@ impl Tag {
     ///
     /// See [Tag] documentation for how to use.
     pub fn is_on_thread(&self) -> bool {
-        THREAD_TAG.with(|tag| match tag.get() {
-            Tag(0) => false,
-            tag => *self == tag,
-        })
+        current_tag() == Some(*self)
     }
 }
 
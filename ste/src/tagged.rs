@@ -0,0 +1,439 @@
+use crate::loom::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use crate::loom::sync::{Arc, Mutex};
+use crate::tag::Tag;
+use std::collections::HashMap;
+use std::mem;
+use std::ops;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// A container for a value which is only accessible from the thread that
+/// created it.
+///
+/// This wraps the pattern described in the documentation for [Tag], so that
+/// `!Send` values can be safely moved across threads as long as they're only
+/// ever accessed or dropped on the thread that created them.
+///
+/// # Panics
+///
+/// Accessing or dropping the value on any other thread panics. Note that if
+/// the container is dropped on the wrong thread, the value will be leaked.
+///
+/// The container also keeps track of whether the thread that created it is
+/// still running. Once that thread has shut down, any access panics with a
+/// message saying that the owning thread is gone. This ensures that the
+/// container can't be accessed from a new thread which happens to be
+/// associated with the same [Tag] as one which has since been shut down.
+///
+/// # Drop on shutdown
+///
+/// Values which are still alive when the thread that created them shuts down
+/// cleanly, like when it's [joined][crate::Thread::join], are dropped on that
+/// thread before it exits. The order in which this happens is unspecified.
+/// Dropping the container afterwards does nothing, so forgetting to drop it
+/// with [Thread::drop][crate::Thread::drop] doesn't cause a panic.
+///
+/// If the thread shut down because it panicked, the values are leaked
+/// instead.
+///
+/// Since values might be dropped by the thread long after the scope which
+/// created them has ended, they have to be `'static`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+///
+/// let value = thread.submit(|| ste::Tagged::new(Rc::new(42)));
+///
+/// thread.submit(|| {
+///     assert_eq!(**value, 42);
+/// });
+///
+/// thread.drop(value);
+/// thread.join();
+/// # Ok(()) }
+/// ```
+///
+/// The value is dropped on the thread when it's joined, even if it's still
+/// alive:
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+///
+/// let value = thread.submit(|| ste::Tagged::new(Rc::new(42)));
+///
+/// thread.join();
+/// drop(value); // <- this is fine, the value has already been dropped.
+/// # Ok(()) }
+/// ```
+///
+/// Accessing the value outside of the thread that created it panics:
+///
+/// ```rust,should_panic
+/// use std::rc::Rc;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+///
+/// let value = thread.submit(|| ste::Tagged::new(Rc::new(42)));
+/// assert_eq!(**value, 42); // <- oops, this panics!
+///
+/// thread.join();
+/// # Ok(()) }
+/// ```
+pub struct Tagged<T> {
+    tag: Tag,
+    registry: Option<Arc<Registry>>,
+    /// The identifier of the value in the registry, if there is one.
+    id: usize,
+    value: ptr::NonNull<T>,
+}
+
+impl<T> Tagged<T> {
+    /// Construct a new tagged container associated with the current thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not running on a tagged thread, see [Tag::current_thread].
+    ///
+    /// # Examples
+    ///
+    /// Values which borrow from the stack can't be tagged, since they might be
+    /// dropped when the thread shuts down:
+    ///
+    /// ```rust,compile_fail
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let local = 42;
+    /// let value = thread.submit(|| ste::Tagged::new(&local));
+    ///
+    /// std::mem::forget(value);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn new(value: T) -> Self
+    where
+        T: 'static,
+    {
+        let tag = Tag::current_thread();
+        let value = ptr::NonNull::from(Box::leak(Box::new(value)));
+        let registry = crate::tag::current_registry();
+
+        let id = match &registry {
+            Some(registry) => registry.register(value),
+            None => 0,
+        };
+
+        Self {
+            tag,
+            registry,
+            id,
+            value,
+        }
+    }
+
+    /// Get the tag associated with the container.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Call `f` with a reference to the value.
+    ///
+    /// The thread is only checked once, which makes this cheaper and clearer
+    /// than dereferencing the container repeatedly when performing several
+    /// operations on the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called on the thread that created the container, or if
+    /// that thread is gone. See the [type level documentation][Tagged].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// let value = thread.submit(|| ste::Tagged::new(Rc::new(RefCell::new(vec![1, 2]))));
+    ///
+    /// let sum = thread.submit(|| {
+    ///     value.with(|v| {
+    ///         v.borrow_mut().push(3);
+    ///         assert_eq!(v.borrow().len(), 3);
+    ///         v.borrow().iter().sum::<u32>()
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(sum, 6);
+    ///
+    /// thread.drop(value);
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn with<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.ensure_on_thread();
+        // Safety: we're on the thread that created the value.
+        f(unsafe { self.value.as_ref() })
+    }
+
+    /// Convert the container into a raw pointer to the value and the tag it
+    /// is associated with.
+    ///
+    /// This is useful for passing a `!Send` value as an opaque pointer through
+    /// an FFI boundary, like a C callback. The container can be reconstructed
+    /// with [Tagged::from_raw].
+    ///
+    /// This doesn't access the value, so it can be called on any thread. The
+    /// value is no longer dropped when the thread that created it shuts down
+    /// until it has been reconstructed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let thread = ste::spawn();
+    ///
+    /// thread.submit(|| {
+    ///     let value = ste::Tagged::new(Rc::new(42));
+    ///     let (ptr, tag) = ste::Tagged::into_raw(value);
+    ///
+    ///     // Safety: we're reconstructing the container exactly once, from
+    ///     // the pointer and tag we just got.
+    ///     let value = unsafe { ste::Tagged::from_raw(ptr, tag) };
+    ///     assert_eq!(**value, 42);
+    /// });
+    ///
+    /// thread.join();
+    /// # Ok(()) }
+    /// ```
+    pub fn into_raw(this: Self) -> (*mut T, Tag) {
+        let this = mem::ManuallyDrop::new(this);
+
+        if let Some(registry) = &this.registry {
+            registry.deregister(this.id);
+        }
+
+        // Safety: the container is never used again, so we take the registry
+        // out of it to release it.
+        drop(unsafe { ptr::read(&this.registry) });
+        (this.value.as_ptr(), this.tag)
+    }
+
+    /// Reconstruct a container from a raw pointer and tag previously
+    /// returned by [Tagged::into_raw].
+    ///
+    /// The thread check is preserved, so accessing the reconstructed container
+    /// on a thread other than the one associated with `tag` still panics. The
+    /// check for whether the owning thread is still running, and dropping the
+    /// value when it shuts down, is only preserved if the container is
+    /// reconstructed on the thread associated with `tag`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// * `ptr` and `tag` were returned together from a single call to
+    ///   [Tagged::into_raw] for the same `T`.
+    /// * The container is reconstructed exactly once from them. Reconstructing
+    ///   it more than once results in a double free, and not at all leaks the
+    ///   value.
+    /// * The value hasn't been accessed through `ptr` on any thread other than
+    ///   the one associated with `tag` while it was in its raw form.
+    pub unsafe fn from_raw(ptr: *mut T, tag: Tag) -> Self
+    where
+        T: 'static,
+    {
+        let value = ptr::NonNull::new_unchecked(ptr);
+
+        let registry = if tag.is_on_thread() {
+            crate::tag::current_registry()
+        } else {
+            None
+        };
+
+        let id = match &registry {
+            Some(registry) => registry.register(value),
+            None => 0,
+        };
+
+        Self {
+            tag,
+            registry,
+            id,
+            value,
+        }
+    }
+
+    /// Ensure that the value can be accessed from the current thread.
+    fn ensure_on_thread(&self) {
+        if let Some(registry) = &self.registry {
+            if !registry.is_alive() {
+                panic!(
+                    "cannot operate on tagged element, the owning thread {:?} is gone",
+                    self.tag
+                );
+            }
+        }
+
+        self.tag.ensure_on_thread();
+    }
+}
+
+impl<T> ops::Deref for Tagged<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.ensure_on_thread();
+        // Safety: we're on the thread that created the value.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T> ops::DerefMut for Tagged<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ensure_on_thread();
+        // Safety: we're on the thread that created the value.
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T> Drop for Tagged<T> {
+    fn drop(&mut self) {
+        if let Some(registry) = &self.registry {
+            // The owning thread has shut down cleanly and dropped the value.
+            if !registry.is_alive() && !registry.contains(self.id) {
+                return;
+            }
+        }
+
+        self.ensure_on_thread();
+
+        if let Some(registry) = &self.registry {
+            registry.deregister(self.id);
+        }
+
+        // Safety: we're on the thread that created the value, and the box is
+        // only ever freed here or when the thread shuts down, which it
+        // hasn't.
+        unsafe {
+            let _ = Box::from_raw(self.value.as_ptr());
+        }
+    }
+}
+
+// Safety: the container is explicitly tagged with the thread that created it,
+// and every access to the value (including drop) ensures that it's on the
+// thread that created it.
+unsafe impl<T> Send for Tagged<T> {}
+unsafe impl<T> Sync for Tagged<T> {}
+
+/// A registered value, as its address and the function used to drop it.
+type Registered = (usize, unsafe fn(*mut ()));
+
+/// The values tagged with a worker thread which are still alive.
+pub(crate) struct Registry {
+    /// Cleared once the worker shuts down, so that values tagged with it can
+    /// tell that the thread they belong to is gone.
+    alive: AtomicBool,
+    /// The identifier handed out to the next registered value.
+    ///
+    /// NB: values can't be keyed by their address, since every zero-sized
+    /// value has the same dangling address.
+    next_id: AtomicUsize,
+    /// Registered values, keyed by their identifier.
+    values: Mutex<HashMap<usize, Registered>>,
+}
+
+impl Registry {
+    /// Construct a new registry for a running thread.
+    pub(crate) fn new() -> Self {
+        Self {
+            alive: AtomicBool::new(true),
+            next_id: AtomicUsize::new(0),
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Test if the thread is still running.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::Acquire)
+    }
+
+    /// Mark the thread as gone without dropping any values.
+    pub(crate) fn kill(&self) {
+        self.alive.store(false, Ordering::Release);
+    }
+
+    /// Register a value to be dropped when the thread shuts down, returning
+    /// the identifier it's registered with.
+    fn register<T>(&self, value: ptr::NonNull<T>) -> usize
+    where
+        T: 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        self.values
+            .lock()
+            .unwrap()
+            .insert(id, (value.as_ptr() as usize, drop_value::<T>));
+
+        id
+    }
+
+    /// Deregister the value with the given identifier.
+    fn deregister(&self, id: usize) {
+        self.values.lock().unwrap().remove(&id);
+    }
+
+    /// Test if the value with the given identifier is registered.
+    fn contains(&self, id: usize) -> bool {
+        self.values.lock().unwrap().contains_key(&id)
+    }
+
+    /// Mark the thread as gone and drop every value which is still
+    /// registered.
+    ///
+    /// This must only be called on the thread that the values belong to.
+    pub(crate) fn shutdown(&self) {
+        self.kill();
+
+        // NB: dropping a value might construct new tagged values, so we keep
+        // going until there are none left.
+        loop {
+            let values = mem::take(&mut *self.values.lock().unwrap());
+
+            if values.is_empty() {
+                break;
+            }
+
+            for (value, drop_value) in values.into_values() {
+                // Safety: we're on the thread that the value belongs to, and
+                // since it has been deregistered while the thread is marked as
+                // gone, the container will no longer attempt to drop it.
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+                    drop_value(value as *mut ());
+                }));
+            }
+        }
+    }
+}
+
+/// Drop a value which was leaked from a box of `T`.
+unsafe fn drop_value<T>(value: *mut ()) {
+    let _ = Box::from_raw(value as *mut T);
+}
@@ -0,0 +1,39 @@
+/// A collection of tasks which can be submitted together through
+/// [Thread::submit_all][crate::Thread::submit_all].
+///
+/// This is implemented for tuples of up to 8 closures, which can each return
+/// a different type. The output is a tuple of the same arity containing the
+/// output of every closure.
+pub trait Tasks {
+    /// The combined output of the tasks.
+    type Output;
+
+    /// Run every task in order, collecting their outputs.
+    fn run(self) -> Self::Output;
+}
+
+macro_rules! impl_tasks {
+    ($($f:ident $t:ident),*) => {
+        impl<$($f, $t,)*> Tasks for ($($f,)*)
+        where
+            $($f: FnOnce() -> $t,)*
+        {
+            type Output = ($($t,)*);
+
+            #[allow(non_snake_case)]
+            fn run(self) -> Self::Output {
+                let ($($f,)*) = self;
+                ($($f(),)*)
+            }
+        }
+    };
+}
+
+impl_tasks!(F1 T1);
+impl_tasks!(F1 T1, F2 T2);
+impl_tasks!(F1 T1, F2 T2, F3 T3);
+impl_tasks!(F1 T1, F2 T2, F3 T3, F4 T4);
+impl_tasks!(F1 T1, F2 T2, F3 T3, F4 T4, F5 T5);
+impl_tasks!(F1 T1, F2 T2, F3 T3, F4 T4, F5 T5, F6 T6);
+impl_tasks!(F1 T1, F2 T2, F3 T3, F4 T4, F5 T5, F6 T6, F7 T7);
+impl_tasks!(F1 T1, F2 T2, F3 T3, F4 T4, F5 T5, F6 T6, F7 T7, F8 T8);
@@ -65,3 +65,960 @@ fn test_threading() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(any(target_os = "linux", windows))]
+fn test_pin_to_core() -> anyhow::Result<()> {
+    let thread = crate::Builder::new().pin_to_core(0).build()?;
+    assert_eq!(thread.submit(|| 42), 42);
+    thread.join();
+
+    assert!(crate::Builder::new()
+        .pin_to_core(usize::MAX)
+        .build()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_normal_priority() -> anyhow::Result<()> {
+    let thread = crate::Builder::new()
+        .priority(crate::ThreadPriority::Normal)
+        .build()?;
+    assert_eq!(thread.submit(|| 42), 42);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_realtime_priority_does_not_panic() {
+    // NB: this might fail due to permissions, but it must never panic.
+    if let Ok(thread) = crate::Builder::new()
+        .priority(crate::ThreadPriority::RealTime)
+        .build()
+    {
+        thread.join();
+    }
+}
+
+#[test]
+fn test_submit_timed() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let thread = crate::spawn();
+
+    let (output, timing) = thread.submit_timed(|| {
+        thread::sleep(Duration::from_millis(20));
+        42
+    })?;
+
+    assert_eq!(output, 42);
+    assert!(timing.execution() >= Duration::from_millis(20));
+
+    assert_eq!(
+        thread.submit_timed(|| panic!("woops")).map(|_: ((), _)| ()),
+        Err(crate::Panicked)
+    );
+
+    // The thread keeps working after a panic.
+    assert_eq!(thread.submit_timed(|| 1)?.0, 1);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_submit_all() -> anyhow::Result<()> {
+    use std::rc::Rc;
+
+    let thread = crate::spawn();
+    let tag = thread.tag();
+
+    let (a, b, c): (u32, String, bool) =
+        thread.submit_all((|| 1, || "x".to_string(), move || tag.is_on_thread()))?;
+
+    assert_eq!(a, 1);
+    assert_eq!(b, "x");
+    assert!(c);
+
+    // All tasks run in order on the same thread, so they can share state
+    // which isn't Send through a tagged value.
+    let value = thread.submit(|| crate::Tagged::new(Rc::new(std::cell::Cell::new(0))));
+
+    let out = thread.submit_all((
+        || value.with(|v| v.replace(1)),
+        || value.with(|v| v.replace(2)),
+        || value.with(|v| v.replace(3)),
+        || value.with(|v| v.replace(4)),
+        || value.with(|v| v.replace(5)),
+        || value.with(|v| v.replace(6)),
+        || value.with(|v| v.replace(7)),
+        || value.with(|v| v.get() as f32),
+    ))?;
+
+    assert_eq!(out, (0, 1, 2, 3, 4, 5, 6, 7.0));
+
+    // A panic aborts the remaining tasks.
+    let result = thread.submit_all((
+        || value.with(|v| v.set(10)),
+        || -> u32 { panic!("woops") },
+        || value.with(|v| v.set(20)),
+    ));
+
+    assert_eq!(result.map(|_| ()), Err(crate::Panicked));
+    assert_eq!(thread.submit(|| value.with(|v| v.get())), 10);
+
+    thread.drop(value);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_set_worker_name() -> anyhow::Result<()> {
+    let thread = crate::spawn();
+
+    let result = thread.set_worker_name("audio-engine-worker");
+
+    if cfg!(any(target_os = "linux", windows)) {
+        result?;
+    }
+
+    if cfg!(target_os = "linux") {
+        // NB: truncated to the 15 bytes allowed by the platform.
+        assert_eq!(thread.worker_name()?, "audio-engine-wo");
+
+        // Multi-byte characters aren't split when truncating.
+        thread.set_worker_name("audio-engine-wö")?;
+        assert_eq!(thread.worker_name()?, "audio-engine-w");
+    }
+
+    if cfg!(windows) {
+        assert_eq!(thread.worker_name()?, "audio-engine-worker");
+    }
+
+    assert!(thread.set_worker_name("nul\0byte").is_err());
+
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_cancel_pending() -> anyhow::Result<()> {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let thread = Arc::new(crate::spawn());
+
+    let (started_tx, started_rx) = mpsc::channel();
+    let (resume_tx, resume_rx) = mpsc::channel::<()>();
+
+    let busy = {
+        let thread = thread.clone();
+
+        thread::spawn(move || {
+            thread.submit(move || {
+                started_tx.send(()).unwrap();
+                resume_rx.recv().unwrap();
+            })
+        })
+    };
+
+    started_rx.recv()?;
+
+    let mut waiters = Vec::new();
+
+    for n in 0..4 {
+        let thread = thread.clone();
+        waiters.push(thread::spawn(move || thread.submit_timed(move || n)));
+    }
+
+    // Give the waiters some time to enqueue their tasks.
+    thread::sleep(Duration::from_millis(100));
+
+    assert_eq!(thread.cancel_pending(), 4);
+
+    for waiter in waiters {
+        assert!(waiter.join().unwrap().is_err());
+    }
+
+    resume_tx.send(())?;
+    busy.join().unwrap();
+
+    let thread = Arc::try_unwrap(thread).map_err(|_| anyhow!("unwrap failed"))?;
+    assert_eq!(thread.submit(|| 42), 42);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_linked_list_len() {
+    use crate::linked_list::{LinkedList, Node};
+    use std::ptr;
+
+    let mut list = LinkedList::new();
+    let mut nodes = (0..4).map(Node::new).collect::<Vec<_>>();
+
+    unsafe {
+        for (n, node) in nodes.iter_mut().enumerate() {
+            if n % 2 == 0 {
+                list.push_front(ptr::NonNull::from(node));
+            } else {
+                list.push_back(ptr::NonNull::from(node));
+            }
+
+            assert_eq!(list.len(), n + 1);
+        }
+
+        assert!(list.pop_back().is_some());
+        assert!(list.pop_front().is_some());
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+
+        let mut stolen = list.steal();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert_eq!(stolen.len(), 2);
+
+        assert!(stolen.pop_front().is_some());
+        assert!(stolen.pop_front().is_some());
+        assert!(stolen.pop_front().is_none());
+        assert_eq!(stolen.len(), 0);
+        assert!(stolen.is_empty());
+    }
+}
+
+#[test]
+fn test_fair_scheduling() -> anyhow::Result<()> {
+    assert_eq!(run_batch_with_urgent(true)?[1], 100);
+    assert_eq!(run_batch_with_urgent(false)?[4], 100);
+    return Ok(());
+
+    /// Submit a batch of tasks while the thread is busy, then submit an
+    /// urgent task (identified by 100) after the first task in the batch has
+    /// started.
+    fn run_batch_with_urgent(fair: bool) -> anyhow::Result<Vec<u32>> {
+        use std::sync::{mpsc, Mutex};
+        use std::time::Duration;
+
+        let thread = Arc::new(crate::Builder::new().fair(fair).build()?);
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let (started_tx, started_rx) = mpsc::channel();
+        let (resume_tx, resume_rx) = mpsc::channel::<()>();
+        let resume_rx = Arc::new(Mutex::new(resume_rx));
+
+        let mut threads = Vec::new();
+
+        {
+            let thread = thread.clone();
+            let resume_rx = resume_rx.clone();
+            let started_tx = started_tx.clone();
+
+            threads.push(thread::spawn(move || {
+                thread.submit(move || {
+                    started_tx.send(()).unwrap();
+                    resume_rx.lock().unwrap().recv().unwrap();
+                })
+            }));
+        }
+
+        // Wait for the thread to be busy.
+        started_rx.recv()?;
+
+        for n in 0..4 {
+            let thread = thread.clone();
+            let order = order.clone();
+            let resume_rx = resume_rx.clone();
+            let started_tx = started_tx.clone();
+
+            threads.push(thread::spawn(move || {
+                thread.submit(move || {
+                    let mut order = order.lock().unwrap();
+                    order.push(n);
+
+                    if order.len() == 1 {
+                        drop(order);
+                        started_tx.send(()).unwrap();
+                        resume_rx.lock().unwrap().recv().unwrap();
+                    }
+                })
+            }));
+        }
+
+        // Give the batch time to be queued, then release the busy task.
+        thread::sleep(Duration::from_millis(100));
+        resume_tx.send(())?;
+
+        // The first task in the batch is running.
+        started_rx.recv()?;
+
+        {
+            let thread = thread.clone();
+            let order = order.clone();
+
+            threads.push(thread::spawn(move || {
+                thread.submit(move || order.lock().unwrap().push(100))
+            }));
+        }
+
+        thread::sleep(Duration::from_millis(100));
+        resume_tx.send(())?;
+
+        for t in threads {
+            t.join().unwrap();
+        }
+
+        let thread = Arc::try_unwrap(thread).map_err(|_| anyhow!("unwrap failed"))?;
+        thread.join();
+
+        let order = order.lock().unwrap().clone();
+        Ok(order)
+    }
+}
+
+#[test]
+fn test_submit_into() -> anyhow::Result<()> {
+    use std::mem::MaybeUninit;
+
+    let thread = crate::spawn();
+
+    let mut out = MaybeUninit::<[u32; 16384]>::uninit();
+
+    thread.submit_into(&mut out, || {
+        let mut out = [0; 16384];
+
+        for (n, o) in out.iter_mut().enumerate() {
+            *o = n as u32;
+        }
+
+        out
+    })?;
+
+    // Safety: initialized by a successful submit_into.
+    let out = unsafe { out.assume_init() };
+    assert!(out.iter().enumerate().all(|(n, o)| *o == n as u32));
+
+    // A panicking task leaves the slot uninitialized, so it can be reused.
+    let mut out = MaybeUninit::<Vec<u32>>::uninit();
+    assert_eq!(
+        thread.submit_into(&mut out, || -> Vec<u32> { panic!("woops") }),
+        Err(crate::Panicked)
+    );
+
+    thread.submit_into(&mut out, || vec![1, 2, 3])?;
+    // Safety: initialized by a successful submit_into.
+    assert_eq!(unsafe { out.assume_init() }, vec![1, 2, 3]);
+
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_tagged_raw_round_trip() -> anyhow::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let thread = crate::spawn();
+
+    let (ptr, tag) = thread.submit(|| {
+        let (ptr, tag) = crate::Tagged::into_raw(crate::Tagged::new(Rc::new(42)));
+        (
+            crate::misc::RawSend(std::ptr::NonNull::new(ptr).unwrap()),
+            tag,
+        )
+    });
+
+    // Safety: reconstructed exactly once from the result of `into_raw`.
+    let value = unsafe { crate::Tagged::from_raw(ptr.0.as_ptr(), tag) };
+    assert_eq!(value.tag(), tag);
+
+    // Accessing it on the wrong thread still panics.
+    assert!(catch_unwind(AssertUnwindSafe(|| **value)).is_err());
+
+    thread.submit(|| assert_eq!(**value, 42));
+    thread.drop(value);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_on_task_events() -> anyhow::Result<()> {
+    use crate::TaskEvent;
+    use std::sync::{mpsc, Mutex};
+
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+
+    let thread = crate::Builder::new()
+        .on_task(move |event| tx.lock().unwrap().send(event).unwrap())
+        .build()?;
+
+    thread.submit(|| ());
+    assert!(thread
+        .submit_timed(|| panic!("woops"))
+        .map(|_: ((), _)| ())
+        .is_err());
+    thread.join();
+
+    let events = rx.try_iter().collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        [
+            TaskEvent::Enqueued,
+            TaskEvent::Started,
+            TaskEvent::Finished,
+            TaskEvent::Enqueued,
+            TaskEvent::Started,
+            TaskEvent::Panicked,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_on_task_panicking_hook() -> anyhow::Result<()> {
+    let thread = crate::Builder::new()
+        .on_task(|event| panic!("hook panicked on {:?}", event))
+        .build()?;
+
+    // Neither the submitter nor the worker is affected by the hook panicking.
+    assert_eq!(thread.submit(|| 1 + 2), 3);
+    assert_eq!(thread.submit(|| 4 + 5), 9);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_tagged_owning_thread_gone() -> anyhow::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let thread = crate::spawn();
+    let value = thread.submit(|| crate::Tagged::new(Rc::new(42)));
+    thread.join();
+
+    let error = match catch_unwind(AssertUnwindSafe(|| **value)) {
+        Ok(_) => return Err(anyhow!("expected access to panic")),
+        Err(error) => error,
+    };
+
+    let message = error
+        .downcast_ref::<String>()
+        .ok_or_else(|| anyhow!("expected a formatted panic message"))?;
+    assert!(message.contains("owning thread"), "{}", message);
+    assert!(message.contains("is gone"), "{}", message);
+
+    // NB: dropping the value would panic as well, and since the owning thread
+    // is gone there's nowhere left to drop it.
+    std::mem::forget(value);
+    Ok(())
+}
+
+#[test]
+fn test_tagged_with_off_thread() -> anyhow::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let thread = crate::spawn();
+    let value = thread.submit(|| crate::Tagged::new(Rc::new(42)));
+
+    let doubled = thread.submit(|| value.with(|v| **v * 2));
+    assert_eq!(doubled, 84);
+
+    let error = match catch_unwind(AssertUnwindSafe(|| value.with(|v| **v))) {
+        Ok(_) => return Err(anyhow!("expected access to panic")),
+        Err(error) => error,
+    };
+
+    // NB: the test thread isn't tagged at all.
+    let message = error
+        .downcast_ref::<&str>()
+        .ok_or_else(|| anyhow!("expected a static panic message"))?;
+    assert!(
+        message.contains("not running on a tagged thread"),
+        "{}",
+        message
+    );
+
+    thread.drop(value);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_construct_and_run_drops_on_thread() -> anyhow::Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Foo {
+        tag: crate::Tag,
+        dropped: Arc<AtomicUsize>,
+        _not_send: Rc<()>,
+    }
+
+    impl Drop for Foo {
+        fn drop(&mut self) {
+            self.tag.ensure_on_thread();
+            self.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let thread = crate::spawn();
+    let dropped = Arc::new(AtomicUsize::new(0));
+
+    let construct = || Foo {
+        tag: crate::Tag::current_thread(),
+        dropped: dropped.clone(),
+        _not_send: Rc::new(()),
+    };
+
+    let tag = thread.construct_and_run(construct, |foo| foo.tag);
+    assert_eq!(tag, thread.tag());
+    assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+    // The resource is still dropped on the thread if running it panics.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        thread.construct_and_run(construct, |_| panic!("trigger"))
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(dropped.load(Ordering::SeqCst), 2);
+
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_try_join_busy() -> anyhow::Result<()> {
+    use std::sync::mpsc;
+
+    let (started_tx, started_rx) = mpsc::sync_channel(1);
+    let (release_tx, release_rx) = mpsc::sync_channel::<()>(1);
+
+    let mut thread = crate::Builder::new()
+        .prelude(move || {
+            let _ = started_tx.send(());
+            let _ = release_rx.recv();
+        })
+        .build()?;
+
+    started_rx.recv()?;
+
+    // The worker is still busy in its prelude, so the thread is given back.
+    for _ in 0..10 {
+        thread = match thread.try_join() {
+            Ok(_) => return Err(anyhow!("expected busy thread to be given back")),
+            Err(thread) => thread,
+        };
+    }
+
+    release_tx.send(())?;
+
+    let mut n = 0;
+    thread.submit(|| n += 1);
+    assert_eq!(n, 1);
+
+    // Once a task has completed the worker is idle.
+    match thread.try_join() {
+        Ok(result) => result?,
+        Err(_) => return Err(anyhow!("expected idle thread to be joined")),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_try_prelude_error() -> anyhow::Result<()> {
+    use std::io;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let ran_prelude = Arc::new(AtomicBool::new(false));
+    let ran = ran_prelude.clone();
+
+    let result = crate::Builder::new()
+        .try_prelude(|| Err(io::Error::new(io::ErrorKind::NotFound, "no device")))
+        .prelude(move || ran.store(true, Ordering::SeqCst))
+        .build();
+
+    let error = match result {
+        Ok(_) => return Err(anyhow!("expected build to fail")),
+        Err(error) => error,
+    };
+
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    assert_eq!(error.to_string(), "no device");
+    assert!(!ran_prelude.load(Ordering::SeqCst));
+
+    // A panicking prelude is reported as an error as well.
+    let result = crate::Builder::new()
+        .try_prelude(|| panic!("trigger"))
+        .build();
+    assert!(result.is_err());
+
+    let thread = crate::Builder::new().try_prelude(|| Ok(())).build()?;
+    assert_eq!(thread.submit(|| 42), 42);
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_assert_on_thread() -> anyhow::Result<()> {
+    let thread = crate::spawn();
+    let other = crate::spawn();
+    let tag = thread.tag();
+
+    thread.submit(move || crate::assert_on_thread(tag));
+
+    let result = other.try_submit(move || crate::assert_on_thread(tag));
+    assert!(result.is_err());
+
+    let result = thread::spawn(move || crate::assert_on_thread(tag)).join();
+    assert!(result.is_err());
+
+    other.join();
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_submit_with_cancel() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let thread = crate::spawn();
+
+    let token = crate::CancelToken::new();
+    assert_eq!(thread.submit_with_cancel(&token, |_| 42), Ok(42));
+
+    let handle = token.clone();
+
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        handle.cancel();
+    });
+
+    let mut iterations = 0u64;
+
+    let result = thread.submit_with_cancel(&token, |token| {
+        while !token.is_cancelled() {
+            iterations += 1;
+            thread::yield_now();
+        }
+
+        iterations
+    });
+
+    assert_eq!(result, Err(crate::Cancelled));
+    assert!(iterations > 0);
+
+    // The thread is still usable after a task has been cancelled.
+    assert_eq!(thread.submit(|| 42), 42);
+
+    canceller
+        .join()
+        .map_err(|_| anyhow!("canceller panicked"))?;
+    thread.join();
+    Ok(())
+}
+
+#[test]
+fn test_tagged_dropped_on_join() -> anyhow::Result<()> {
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Foo {
+        // NB: makes Foo !Send.
+        _rc: Rc<()>,
+        tag: crate::Tag,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Foo {
+        fn drop(&mut self) {
+            self.tag.ensure_on_thread();
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let thread = crate::spawn();
+
+    let (a, b) = thread.submit(|| {
+        let new = || {
+            crate::Tagged::new(Foo {
+                _rc: Rc::new(()),
+                tag: crate::Tag::current_thread(),
+                drops: drops.clone(),
+            })
+        };
+
+        (new(), new())
+    });
+
+    thread.drop(a);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    thread.join();
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+
+    // Dropping the container after the thread has been joined is a no-op.
+    drop(b);
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[test]
+fn test_park_strategies() -> anyhow::Result<()> {
+    use crate::ParkStrategy;
+    use std::time::Duration;
+
+    for park in [
+        ParkStrategy::Block,
+        ParkStrategy::SpinThenPark,
+        ParkStrategy::Spin,
+    ]
+    .iter()
+    .copied()
+    {
+        let thread = crate::Builder::new().park_strategy(park).build()?;
+
+        for n in 0..10 {
+            // Give the worker time to go idle and park.
+            thread::sleep(Duration::from_millis(5));
+            assert_eq!(thread.submit(move || n * 2), n * 2);
+        }
+
+        // Rapid successive submissions.
+        let total = (0..1000).map(|n| thread.submit(move || n)).sum::<u32>();
+        assert_eq!(total, 499500);
+
+        thread.join();
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_spin() -> anyhow::Result<()> {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let thread = Arc::new(crate::Builder::new().submit_spin(1000).build()?);
+
+    // A task which outlasts the spin, so the submitter has to park.
+    let value = thread.submit(|| {
+        thread::sleep(Duration::from_millis(10));
+        42
+    });
+    assert_eq!(value, 42);
+
+    let mut handles = Vec::new();
+
+    for _ in 0..4 {
+        let thread = thread.clone();
+
+        handles.push(thread::spawn(move || {
+            (0..1000).map(|n| thread.submit(move || n)).sum::<u32>()
+        }));
+    }
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 499500);
+    }
+
+    Arc::try_unwrap(thread)
+        .map_err(|_| anyhow!("thread is still shared"))?
+        .join();
+    Ok(())
+}
+
+#[test]
+fn test_thread_registry() -> anyhow::Result<()> {
+    let a = crate::spawn();
+    let b = crate::spawn();
+
+    let mut registry = crate::ThreadRegistry::new();
+
+    assert_eq!(a.submit(|| registry.insert_current("a")), None);
+    assert_eq!(b.submit(|| registry.insert_current("b")), None);
+    assert_eq!(registry.len(), 2);
+
+    assert_eq!(a.submit(|| registry.get_current().copied()), Some("a"));
+    assert_eq!(b.submit(|| registry.get_current().copied()), Some("b"));
+    assert_eq!(registry.get_current(), None);
+    assert_eq!(registry.remove_current(), None);
+
+    assert_eq!(a.submit(|| registry.remove_current()), Some("a"));
+    assert_eq!(a.submit(|| registry.get_current().copied()), None);
+    assert_eq!(registry.len(), 1);
+
+    a.join();
+    b.join();
+
+    // Values of threads which have shut down are absent.
+    assert!(registry.is_empty());
+
+    // NB: later threads are likely to reuse the tag of a thread which has shut
+    // down, but they never see its value.
+    for _ in 0..16 {
+        let thread = crate::spawn();
+        assert_eq!(thread.submit(|| registry.get_current().copied()), None);
+        assert_eq!(thread.submit(|| registry.insert_current("c")), None);
+        assert_eq!(registry.len(), 1);
+        thread.join();
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_to_sender() -> anyhow::Result<()> {
+    use std::sync::mpsc;
+
+    let thread = crate::spawn();
+    let tag = thread.tag();
+
+    let (tx, rx) = mpsc::channel();
+
+    for n in 0..100u32 {
+        thread.submit_to_sender(
+            move || {
+                tag.ensure_on_thread();
+                n
+            },
+            tx.clone(),
+        );
+    }
+
+    // Panicking tasks send nothing, and don't affect later tasks.
+    thread.submit_to_sender(|| -> u32 { panic!("woops") }, tx.clone());
+    thread.submit_to_sender(|| 100, tx);
+
+    let mut results = rx.iter().collect::<Vec<_>>();
+    results.sort_unstable();
+    assert_eq!(results, (0..=100).collect::<Vec<_>>());
+
+    // A disconnected receiver drops the output.
+    let (tx, rx) = mpsc::channel();
+    drop(rx);
+    thread.submit_to_sender(|| Box::new(42), tx);
+
+    // Tasks which are still pending are executed when joining.
+    let (tx, rx) = mpsc::channel();
+
+    for n in 0..10u32 {
+        thread.submit_to_sender(move || n, tx.clone());
+    }
+
+    thread.join();
+    drop(tx);
+    assert_eq!(rx.iter().sum::<u32>(), 45);
+    Ok(())
+}
+
+#[test]
+fn test_tagged_zero_sized() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Zst;
+
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let thread = crate::spawn();
+
+    // NB: every zero-sized value has the same address, so they must still be
+    // tracked individually.
+    let (a, b, c) = thread.submit(|| {
+        (
+            crate::Tagged::new(Zst),
+            crate::Tagged::new(Zst),
+            crate::Tagged::new(Zst),
+        )
+    });
+
+    thread.drop(a);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    thread.join();
+    assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+
+    drop((b, c));
+    assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[test]
+fn test_cancel_pending_async() -> anyhow::Result<()> {
+    use std::future::Future;
+    use std::sync::mpsc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::time::Duration;
+
+    struct Unpark(thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F>(future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    let thread = Arc::new(crate::spawn());
+
+    let (started_tx, started_rx) = mpsc::channel();
+    let (resume_tx, resume_rx) = mpsc::channel::<()>();
+
+    let busy = {
+        let thread = thread.clone();
+
+        thread::spawn(move || {
+            thread.submit(move || {
+                started_tx.send(()).unwrap();
+                resume_rx.recv().unwrap();
+            })
+        })
+    };
+
+    started_rx.recv()?;
+
+    let waiter = {
+        let thread = thread.clone();
+        thread::spawn(move || block_on(thread.submit_async(async { 42 })))
+    };
+
+    // Give the waiter some time to enqueue its task.
+    thread::sleep(Duration::from_millis(100));
+
+    assert_eq!(thread.cancel_pending(), 1);
+    assert!(waiter.join().is_err());
+
+    resume_tx.send(())?;
+    busy.join().unwrap();
+
+    let thread = Arc::try_unwrap(thread).map_err(|_| anyhow!("unwrap failed"))?;
+    assert_eq!(block_on(thread.submit_async(async { 42 })), 42);
+    thread.join();
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::loom::sync::Arc;
+use crate::tag::{current_registry, current_tag, Tag};
+use crate::tagged::Registry;
+use std::collections::HashMap;
+
+/// A collection of values keyed by the [Tag] of the thread they belong to.
+///
+/// This allows libraries to keep a value per background thread, like a
+/// scratch buffer for every audio thread, and to cheaply look up the one
+/// belonging to the thread they're currently running on.
+///
+/// A thread which is started later might be associated with the same [Tag]
+/// as one which has shut down. So values belonging to a thread which has shut
+/// down are treated as absent, and are dropped the next time a value is
+/// inserted.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> anyhow::Result<()> {
+/// let thread = ste::spawn();
+/// let mut registry = ste::ThreadRegistry::new();
+///
+/// thread.submit(|| registry.insert_current(vec![0.0f32; 64]));
+///
+/// let len = thread.submit(|| registry.get_current().map(|scratch| scratch.len()));
+/// assert_eq!(len, Some(64));
+/// assert!(registry.get_current().is_none());
+///
+/// thread.submit(|| registry.remove_current());
+/// thread.join();
+/// # Ok(()) }
+/// ```
+pub struct ThreadRegistry<T> {
+    values: HashMap<Tag, Entry<T>>,
+}
+
+/// A value in the registry.
+struct Entry<T> {
+    /// The registry of the thread the value belongs to, which keeps track of
+    /// whether it's still running.
+    registry: Option<Arc<Registry>>,
+    value: T,
+}
+
+impl<T> Entry<T> {
+    /// Test if the thread the value belongs to is still running.
+    fn is_alive(&self) -> bool {
+        self.registry
+            .as_ref()
+            .is_none_or(|registry| registry.is_alive())
+    }
+
+    /// Get the value if the thread it belongs to is still running.
+    fn into_live(self) -> Option<T> {
+        if self.is_alive() {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ThreadRegistry<T> {
+    /// Construct a new empty registry.
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+
+    /// Register `value` for the current thread, returning the value which
+    /// was previously registered for it, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not running on a tagged thread, see [Tag::current_thread].
+    pub fn insert_current(&mut self, value: T) -> Option<T> {
+        let tag = Tag::current_thread();
+
+        self.values.retain(|_, entry| entry.is_alive());
+
+        let entry = Entry {
+            registry: current_registry(),
+            value,
+        };
+
+        self.values.insert(tag, entry).and_then(Entry::into_live)
+    }
+
+    /// Get the value registered for the current thread.
+    ///
+    /// Returns `None` if nothing is registered for it, or if not running on a
+    /// tagged thread.
+    pub fn get_current(&self) -> Option<&T> {
+        let entry = self.values.get(&current_tag()?)?;
+
+        if entry.is_alive() {
+            Some(&entry.value)
+        } else {
+            None
+        }
+    }
+
+    /// Get the value registered for the current thread mutably.
+    ///
+    /// Returns `None` if nothing is registered for it, or if not running on a
+    /// tagged thread.
+    pub fn get_current_mut(&mut self) -> Option<&mut T> {
+        let entry = self.values.get_mut(&current_tag()?)?;
+
+        if entry.is_alive() {
+            Some(&mut entry.value)
+        } else {
+            None
+        }
+    }
+
+    /// Remove the value registered for the current thread, returning it.
+    ///
+    /// Returns `None` if nothing is registered for it, or if not running on a
+    /// tagged thread.
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.values.remove(&current_tag()?)?.into_live()
+    }
+
+    /// Get the number of running threads which have a value registered.
+    pub fn len(&self) -> usize {
+        self.values
+            .values()
+            .filter(|entry| entry.is_alive())
+            .count()
+    }
+
+    /// Test if no running thread has a value registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for ThreadRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
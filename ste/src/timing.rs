@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+/// Timing information for a task submitted through
+/// [Thread::submit_timed][crate::Thread::submit_timed].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmitTiming {
+    pub(crate) delay: Duration,
+    pub(crate) execution: Duration,
+}
+
+impl SubmitTiming {
+    /// The time it took from the task being submitted until it started
+    /// executing on the background thread.
+    ///
+    /// This is a measure of how much scheduling jitter the background thread
+    /// is subject to.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// The time it took to execute the task on the background thread.
+    pub fn execution(&self) -> Duration {
+        self.execution
+    }
+}
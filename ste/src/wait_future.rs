@@ -34,7 +34,11 @@ where
                 panic!("task already completed");
             }
 
+            // NB: cleared if the entry is cancelled instead of being executed.
+            let mut polled = false;
+
             let mut task = into_task(
+                RawSend((&mut polled).into()),
                 RawSend((&mut this.complete).into()),
                 RawSend(this.future),
                 RawSend(this.output),
@@ -44,6 +48,12 @@ where
 
             this.shared.schedule_in_place(this.parker, entry);
 
+            // NB: a cancelled entry is resolved as if the task had panicked,
+            // since nothing would ever wake up the waker.
+            if !polled {
+                this.complete = true;
+            }
+
             if this.complete {
                 panic!("background thread panicked");
             }
@@ -61,11 +71,12 @@ where
 unsafe impl<F> Send for WaitFuture<'_, F> where F: Future {}
 
 fn into_task<F>(
+    mut polled: RawSend<bool>,
     mut complete: RawSend<bool>,
     mut future: RawSend<F>,
     mut output: RawSend<Option<F::Output>>,
     waker: RawSend<Waker>,
-) -> impl FnMut(Tag) + Send
+) -> impl FnMut(Tag) -> bool + Send
 where
     F: Future,
 {
@@ -73,6 +84,8 @@ where
 
     move |tag| {
         unsafe {
+            *polled.0.as_mut() = true;
+
             // Safety: At this point, we know the waker has been
             // replaced by the polling task and can safely deref it into
             // the underlying waker.
@@ -89,7 +102,10 @@ where
 
             if result.is_err() {
                 *complete.0.as_mut() = true;
+                return false;
             }
+
+            true
         }
     }
 }
@@ -1,29 +1,72 @@
+use crate::event::TaskEvent;
 use crate::linked_list::{LinkedList, Node};
-use crate::loom::sync::atomic::{AtomicIsize, Ordering};
-use crate::loom::sync::Mutex;
+use crate::loom::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use crate::loom::sync::{Arc, Mutex};
 use crate::loom::thread;
+use crate::park::ParkStrategy;
 use crate::parker::Parker;
 use crate::tag::Tag;
+use crate::tagged::Registry;
 use std::mem;
+use std::panic;
 use std::ptr;
 
 /// The type of the prelude function.
 pub(super) type Prelude = dyn Fn() + Send + 'static;
 
+/// The type of the fallible prelude function.
+pub(super) type TryPrelude = dyn FnOnce() -> std::io::Result<()> + Send + 'static;
+
+/// The type of the task lifecycle hook.
+pub(super) type TaskHook = dyn Fn(TaskEvent) + Send + Sync + 'static;
+
 // Shared state between the worker thread and [Thread].
 pub(super) struct Shared {
     modifiers: AtomicIsize,
     queue: Mutex<LinkedList<Entry>>,
     parker: Parker,
+    on_task: Option<Box<TaskHook>>,
+    /// Values tagged with the worker, which keeps track of whether the thread
+    /// they belong to is gone.
+    registry: Arc<Registry>,
+    /// Set while the worker is running its prelude or has taken entries off
+    /// the queue which it hasn't finished executing yet.
+    busy: AtomicBool,
+    /// How many times a submitter spins waiting for its task to complete
+    /// before it parks.
+    submit_spin: usize,
 }
 
 impl Shared {
     /// Construct new shared state.
-    pub(super) fn new() -> Self {
+    pub(super) fn new(on_task: Option<Box<TaskHook>>, submit_spin: usize) -> Self {
         Self {
             modifiers: AtomicIsize::new(0),
             queue: Mutex::new(LinkedList::new()),
             parker: Parker::new(),
+            on_task,
+            registry: Arc::new(Registry::new()),
+            busy: AtomicBool::new(true),
+            submit_spin,
+        }
+    }
+
+    /// Test if the worker is idle, which is when it isn't executing anything
+    /// and there's nothing left in the queue for it to execute.
+    pub(super) fn is_idle(&self) -> bool {
+        let queue = self.queue.lock().unwrap();
+        // NB: the worker only marks itself as busy while holding the queue
+        // lock, so entries can't be in transit between the two.
+        queue.is_empty() && !self.busy.load(Ordering::Acquire)
+    }
+
+    /// Report a task lifecycle event to the hook, if one is installed.
+    ///
+    /// A panic in the hook is caught and ignored, since the hook is called
+    /// while the queue is locked or while the worker is executing tasks.
+    fn emit(&self, event: TaskEvent) {
+        if let Some(on_task) = &self.on_task {
+            let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| on_task(event)));
         }
     }
 
@@ -60,6 +103,9 @@ impl Shared {
     // zero, after which it will pop all elements from the queue and release
     // them.
     unsafe fn panic_join(&self) {
+        self.registry.kill();
+        self.busy.store(false, Ordering::Release);
+
         let modifiers = self.modifiers.fetch_add(isize::MIN, Ordering::SeqCst);
 
         // It's not possible for the state to be anything but empty
@@ -88,30 +134,85 @@ impl Shared {
     pub(super) unsafe fn schedule_in_place(&self, parker: ptr::NonNull<Parker>, entry: Entry) {
         let mut node = Node::new(entry);
 
+        if !self.push(ptr::NonNull::from(&mut node)) {
+            panic!("background thread ended");
+        }
+
+        // NB: We must park here until the remote task wakes us up to allow
+        // the task to access things from the environment in the other
+        // thread safely.
+        //
+        // We also know fully that the parker is balanced - i.e. there are
+        // no sporadic wakes that can happen because we contrl the state of
+        // the submitted task exactly above. The notification is consumed
+        // atomically, so it's observed exactly once whether it arrives while
+        // we're spinning or after we've parked.
+        parker.as_ref().park_after_spin(self.submit_spin);
+    }
+
+    /// Process the given detached entry on the remote thread without waiting
+    /// for it to complete.
+    ///
+    /// # Safety
+    ///
+    /// The entry must have been allocated with [Entry::detached].
+    pub(super) unsafe fn schedule_detached(&self, node: ptr::NonNull<Node<Entry>>) {
+        if !self.push(node) {
+            Entry::complete(node);
+            panic!("background thread ended");
+        }
+    }
+
+    /// Push the given entry onto the queue, waking up the worker if needed.
+    ///
+    /// Returns `false` if the worker has ended, in which case the entry was
+    /// not pushed.
+    unsafe fn push(&self, node: ptr::NonNull<Node<Entry>>) -> bool {
         let first = {
             let _guard = match self.lock_queue() {
                 Some(guard) => guard,
-                None => panic!("background thread ended"),
+                None => return false,
             };
 
-            self.queue
-                .lock()
-                .unwrap()
-                .push_front(ptr::NonNull::from(&mut node))
+            let mut queue = self.queue.lock().unwrap();
+            let first = queue.push_front(node);
+            // NB: emitted while the queue is still locked so that it's
+            // guaranteed to be observed before the task is started.
+            self.emit(TaskEvent::Enqueued);
+            first
         };
 
         if first {
             self.parker.unpark();
         }
 
-        // NB: We must park here until the remote task wakes us up to allow
-        // the task to access things from the environment in the other
-        // thread safely.
-        //
-        // We also know fully that the parker is balanced - i.e. there are
-        // no sporadic wakes that can happen because we contrl the state of
-        // the submitted task exactly above.
-        parker.as_ref().park();
+        true
+    }
+
+    /// Release all entries which have not yet been picked up by the worker,
+    /// returning the number of entries released.
+    pub(super) fn cancel_pending(&self) -> usize {
+        let mut local = {
+            let _guard = match self.lock_queue() {
+                Some(guard) => guard,
+                None => return 0,
+            };
+
+            self.queue.lock().unwrap().steal()
+        };
+
+        let mut count = 0;
+
+        // Safety: the entries have been stolen from the shared queue, so we
+        // are the only ones who have access to them.
+        unsafe {
+            while let Some(entry) = local.pop_back() {
+                Entry::complete(entry);
+                count += 1;
+            }
+        }
+
+        count
     }
 
     /// What should happen when the shared state is joined.
@@ -139,10 +240,20 @@ impl Drop for ModifierGuard<'_> {
 }
 
 /// Worker thread.
-pub(super) fn run(prelude: Option<Box<Prelude>>, shared: ptr::NonNull<Shared>) {
+///
+/// If `fair` is set, the shared queue is checked after every task and newly
+/// arrived entries are run before the rest of the current batch. `park`
+/// determines how the worker waits when the queue is empty.
+pub(super) fn run(
+    prelude: Option<Box<Prelude>>,
+    shared: ptr::NonNull<Shared>,
+    fair: bool,
+    park: ParkStrategy,
+) {
     unsafe {
         let shared = shared.as_ref();
         let tag = Tag(shared as *const _ as usize);
+        crate::tag::set_registry(shared.registry.clone());
 
         if let Some(prelude) = prelude {
             let guard = PoisonGuard { shared };
@@ -151,20 +262,67 @@ pub(super) fn run(prelude: Option<Box<Prelude>>, shared: ptr::NonNull<Shared>) {
         }
 
         while let Some(guard) = shared.lock_queue() {
-            let mut local = shared.queue.lock().unwrap().steal();
+            let mut local = {
+                let mut queue = shared.queue.lock().unwrap();
+                let local = queue.steal();
+                shared.busy.store(!local.is_empty(), Ordering::Release);
+                local
+            };
+
             drop(guard);
 
             if local.is_empty() {
-                shared.parker.park();
+                shared.parker.park_with(park);
                 continue;
             }
 
-            while let Some(mut entry) = local.pop_front() {
-                let entry = &mut entry.as_mut().value;
-                entry.task.as_mut()(tag);
-                entry.parker.as_ref().unpark();
+            while let Some(entry) = local.pop_front() {
+                execute(shared, tag, entry);
+
+                if fair {
+                    if let Some(_guard) = shared.lock_queue() {
+                        let mut incoming = shared.queue.lock().unwrap().steal();
+
+                        while let Some(node) = incoming.pop_back() {
+                            local.push_front(node);
+                        }
+                    }
+                }
+
+                // NB: marked as idle before the caller is woken up, so that the
+                // caller observes the worker as idle once its task completes.
+                if local.is_empty() {
+                    shared.busy.store(false, Ordering::Release);
+                }
+
+                Entry::complete(entry);
             }
         }
+
+        // NB: detached entries don't block their submitter, so they might
+        // still be queued up when the thread is joined. Nothing else can be
+        // pushed at this point, so they're executed before shutting down.
+        let mut local = shared.queue.lock().unwrap().steal();
+
+        while let Some(entry) = local.pop_front() {
+            execute(shared, tag, entry);
+            Entry::complete(entry);
+        }
+
+        // NB: tagged values which are still alive are dropped here, while
+        // we're still on the thread they belong to.
+        crate::tag::with_tag(tag, || shared.registry.shutdown());
+    }
+
+    /// Execute the task of the given entry.
+    unsafe fn execute(shared: &Shared, tag: Tag, mut entry: ptr::NonNull<Node<Entry>>) {
+        shared.emit(TaskEvent::Started);
+
+        if entry.as_mut().value.task.as_mut()(tag) {
+            shared.emit(TaskEvent::Finished);
+        } else {
+            shared.emit(TaskEvent::Panicked);
+        }
     }
 
     /// Guard used to mark the state of the executed as "panicked". This is
@@ -184,28 +342,85 @@ pub(super) fn run(prelude: Option<Box<Prelude>>, shared: ptr::NonNull<Shared>) {
 }
 
 /// A task submitted to the executor.
+///
+/// The task returns `false` if it panicked.
 #[derive(Debug)]
 pub(super) struct Entry {
-    task: ptr::NonNull<dyn FnMut(Tag) + Send + 'static>,
-    parker: ptr::NonNull<Parker>,
+    task: ptr::NonNull<dyn FnMut(Tag) -> bool + Send + 'static>,
+    completion: Completion,
+}
+
+/// What happens once an entry has been executed or released.
+#[derive(Debug, Clone, Copy)]
+enum Completion {
+    /// The submitter which owns the entry is parked, waiting to be woken up.
+    Parker(ptr::NonNull<Parker>),
+    /// The entry is owned by the queue, and is freed with the given function.
+    Detached(unsafe fn(ptr::NonNull<Node<Entry>>)),
+}
+
+/// The allocation backing a detached entry.
+///
+/// NB: `repr(C)` guarantees that the node is at the start of the allocation,
+/// so that a pointer to it can be cast back into the whole allocation.
+#[repr(C)]
+struct Detached<F> {
+    node: Node<Entry>,
+    task: F,
 }
 
 impl Entry {
     pub(super) unsafe fn new(
-        task: &mut (impl FnMut(Tag) + Send),
+        task: &mut (impl FnMut(Tag) -> bool + Send),
         parker: ptr::NonNull<Parker>,
     ) -> Self {
         Self {
-            task: ptr::NonNull::new_unchecked(mem::transmute::<&mut (dyn FnMut(Tag) + Send), _>(
-                task,
-            )),
-            parker,
+            task: ptr::NonNull::new_unchecked(mem::transmute::<
+                &mut (dyn FnMut(Tag) -> bool + Send),
+                _,
+            >(task)),
+            completion: Completion::Parker(parker),
+        }
+    }
+
+    /// Allocate an entry which owns its task, and which is freed once it has
+    /// been executed or released instead of waking up its submitter.
+    pub(super) fn detached<F>(task: F) -> ptr::NonNull<Node<Entry>>
+    where
+        F: FnMut(Tag) -> bool + Send + 'static,
+    {
+        let mut detached = Box::new(Detached {
+            node: Node::new(Entry {
+                task: ptr::NonNull::<F>::dangling(),
+                completion: Completion::Detached(free::<F>),
+            }),
+            task,
+        });
+
+        // NB: the task is boxed, so it won't move for as long as the entry
+        // is alive.
+        detached.node.value.task = ptr::NonNull::from(&mut detached.task);
+        return ptr::NonNull::from(Box::leak(detached)).cast();
+
+        unsafe fn free<F>(node: ptr::NonNull<Node<Entry>>) {
+            drop(Box::from_raw(node.cast::<Detached<F>>().as_ptr()));
         }
     }
 
-    /// Release all resources associated with the entry.
-    unsafe fn release(&self) {
-        self.parker.as_ref().unpark();
+    /// Complete the given entry, which either wakes up its submitter or frees
+    /// it.
+    ///
+    /// # Safety
+    ///
+    /// The entry must not be accessed after this, since it might have been
+    /// freed.
+    unsafe fn complete(node: ptr::NonNull<Node<Entry>>) {
+        let completion = node.as_ref().value.completion;
+
+        match completion {
+            Completion::Parker(parker) => parker.as_ref().unpark(),
+            Completion::Detached(free) => free(node),
+        }
     }
 }
 
@@ -215,6 +430,6 @@ impl Entry {
 /// part of the queue from the rest.
 unsafe fn release_local_queue(queue: &mut LinkedList<Entry>) {
     while let Some(entry) = queue.pop_back() {
-        entry.as_ref().value.release();
+        Entry::complete(entry);
     }
 }